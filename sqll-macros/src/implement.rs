@@ -7,6 +7,7 @@ use std::ffi::CString;
 
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::{
     Data, DataStruct, DeriveInput, Error, Ident, Index, Lifetime, LifetimeParam, LitCStr, LitInt,
@@ -131,6 +132,101 @@ struct Attrs {
     crate_path: Path,
     core_path: Path,
     named: bool,
+    rename_all: Option<RenameAll>,
+}
+
+/// A case conversion applied to field names through `#[sql(rename_all = ..)]`.
+#[derive(Clone, Copy)]
+enum RenameAll {
+    Snake,
+    Camel,
+    Pascal,
+    ScreamingSnake,
+}
+
+impl RenameAll {
+    const ALL: [(&'static str, RenameAll); 4] = [
+        ("snake_case", RenameAll::Snake),
+        ("camelCase", RenameAll::Camel),
+        ("PascalCase", RenameAll::Pascal),
+        ("SCREAMING_SNAKE_CASE", RenameAll::ScreamingSnake),
+    ];
+
+    fn parse(s: &LitStr) -> syn::Result<Self> {
+        let value = s.value();
+
+        for (name, rename) in Self::ALL {
+            if value == name {
+                return Ok(rename);
+            }
+        }
+
+        let expected = Self::ALL.map(|(name, _)| format!("{name:?}")).join(", ");
+
+        Err(Error::new_spanned(
+            s,
+            format_args!("unsupported `rename_all` value {value:?}, expected one of {expected}"),
+        ))
+    }
+
+    /// Apply the case conversion to the given name.
+    ///
+    /// The name is split into words at underscores and at transitions from
+    /// lowercase to uppercase characters.
+    fn apply(self, name: &str) -> String {
+        let mut words = Vec::<String>::new();
+        let mut current = String::new();
+        let mut prev_lower = false;
+
+        for c in name.chars() {
+            if c == '_' {
+                if !current.is_empty() {
+                    words.push(core::mem::take(&mut current));
+                }
+
+                prev_lower = false;
+                continue;
+            }
+
+            if c.is_uppercase() && prev_lower {
+                words.push(core::mem::take(&mut current));
+            }
+
+            prev_lower = c.is_lowercase() || c.is_numeric();
+            current.push(c);
+        }
+
+        if !current.is_empty() {
+            words.push(current);
+        }
+
+        let mut out = String::with_capacity(name.len());
+
+        for (n, word) in words.iter().enumerate() {
+            match self {
+                RenameAll::Snake | RenameAll::ScreamingSnake if n > 0 => {
+                    out.push('_');
+                }
+                _ => {}
+            }
+
+            match self {
+                RenameAll::Snake => out.extend(word.chars().flat_map(char::to_lowercase)),
+                RenameAll::ScreamingSnake => out.extend(word.chars().flat_map(char::to_uppercase)),
+                RenameAll::Camel if n == 0 => out.extend(word.chars().flat_map(char::to_lowercase)),
+                RenameAll::Camel | RenameAll::Pascal => {
+                    let mut chars = word.chars();
+
+                    if let Some(first) = chars.next() {
+                        out.extend(first.to_uppercase());
+                        out.extend(chars.flat_map(char::to_lowercase));
+                    }
+                }
+            }
+        }
+
+        out
+    }
 }
 
 fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
@@ -146,6 +242,7 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
         crate_path: syn::parse_quote!(::sqll),
         core_path: syn::parse_quote!(::core),
         named: false,
+        rename_all: None,
     };
 
     for attr in &input.attrs {
@@ -164,6 +261,11 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
                 return Ok(());
             }
 
+            if meta.path.is_ident("rename_all") {
+                attrs.rename_all = Some(RenameAll::parse(&meta.value()?.parse()?)?);
+                return Ok(());
+            }
+
             Err(Error::new_spanned(
                 meta.path,
                 "unknown attribute for `Row` derive",
@@ -202,8 +304,10 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
 
     let Struct {
         fields,
+        names,
         types,
         bindings,
+        withs,
     } = st;
 
     match what {
@@ -211,17 +315,25 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
             let bindings = fields
                 .iter()
                 .zip(bindings.iter())
-                .map(|(field, binding)| match binding {
-                    Binding::Index(n) => quote! {
-                        #bind_value_t::bind_value(&self.#field, stmt, #n)?;
-                    },
-                    Binding::Name(name) => quote! {{
-                        let Some(index) = stmt.bind_parameter_index(#name) else {
-                            return #result::Err(#error::new(#code::MISMATCH, format_args!("bad parameter name {:?}", #name)));
-                        };
-
-                        #bind_value_t::bind_value(&self.#field, stmt, index)?;
-                    }},
+                .zip(withs.iter())
+                .map(|((field, binding), with)| {
+                    let bind_value = match with {
+                        Some(with) => quote!(#with::bind_value),
+                        None => quote!(#bind_value_t::bind_value),
+                    };
+
+                    match binding {
+                        Binding::Index(n) => quote! {
+                            #bind_value(&self.#field, stmt, #n)?;
+                        },
+                        Binding::Name(name) => quote! {{
+                            let Some(index) = stmt.bind_parameter_index(#name) else {
+                                return #result::Err(#error::new(#code::MISMATCH, format_args!("bad parameter name {:?}", #name)));
+                            };
+
+                            #bind_value(&self.#field, stmt, index)?;
+                        }},
+                    }
                 });
 
             let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -278,41 +390,68 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
                 }
             }
 
+            let mut converted = Vec::new();
             let mut setup = Vec::new();
-            let mut checked = Vec::new();
+            let mut values = Vec::new();
 
-            for (i, (b, ty)) in bindings.iter().zip(types).enumerate() {
+            for (i, ((b, ty), with)) in bindings.iter().zip(types).zip(&withs).enumerate() {
                 let Binding::Index(index) = b else {
                     continue;
                 };
 
                 let c = quote::format_ident!("v{i}");
 
-                setup.push(quote! {
-                    let #c = <<#ty as #from_column_t::<#lt>>::Type as #column_type_t>::check(stmt, #index)?;
-                });
-
-                checked.push(c);
+                // Custom conversions require mutable access to the statement,
+                // so they are all performed before any regular columns are
+                // checked and read.
+                if let Some(with) = with {
+                    converted.push(quote! {
+                        let #c = #with::from_column(stmt, #index)?;
+                    });
+
+                    values.push(quote!(#c));
+                } else {
+                    setup.push(quote! {
+                        let #c = <<#ty as #from_column_t::<#lt>>::Type as #column_type_t>::check(stmt, #index)?;
+                    });
+
+                    values.push(quote!(#from_column_t::<#lt>::from_column(stmt, #c)?));
+                }
             }
 
-            let fields = fields.iter().zip(checked.iter()).map(|(m, c)| {
+            let fields = fields.iter().zip(values.iter()).map(|(m, value)| {
                 quote! {
-                    #m: #from_column_t::<#lt>::from_column(stmt, #c)?
+                    #m: #value
                 }
             });
 
             let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
-            let (_, ty_generics, _) = input.generics.split_for_impl();
+            let (type_impl_generics, ty_generics, type_where_clause) =
+                input.generics.split_for_impl();
+
+            let columns = columns(&bindings, &names).map(|columns| {
+                quote! {
+                    #[automatically_derived]
+                    impl #type_impl_generics #ident #ty_generics #type_where_clause {
+                        /// The names of the columns read by this type, in
+                        /// the order of their indexes.
+                        pub const COLUMNS: &'static [&'static str] = &[#(#columns),*];
+                    }
+                }
+            });
 
             let expanded = quote! {
                 #[automatically_derived]
                 unsafe impl #impl_generics #row_t<#lt> for #ident #ty_generics #where_clause {
                     #[inline]
                     fn from_row(stmt: &#lt mut #statement) -> #result<Self, #error> {
+                        #(#converted)*
                         #(#setup)*
                         #result::Ok(Self { #(#fields),* })
                     }
                 }
+
+                #columns
             };
 
             Ok(expanded)
@@ -320,6 +459,22 @@ fn inner(cx: &Ctxt, input: TokenStream, what: What) -> Result<TokenStream, ()> {
     }
 }
 
+/// Collect the names of all columns ordered by index, if every field has a name.
+fn columns(bindings: &[Binding], names: &[Option<String>]) -> Option<Vec<String>> {
+    let mut columns = Vec::with_capacity(names.len());
+
+    for (binding, name) in bindings.iter().zip(names) {
+        let Binding::Index(n) = binding else {
+            continue;
+        };
+
+        columns.push((*n, name.clone()?));
+    }
+
+    columns.sort_by_key(|&(n, _)| n);
+    Some(columns.into_iter().map(|(_, name)| name).collect())
+}
+
 enum Binding {
     Index(c_int),
     Name(LitCStr),
@@ -328,8 +483,10 @@ enum Binding {
 #[derive(Default)]
 struct Struct {
     fields: Vec<Member>,
+    names: Vec<Option<String>>,
     types: Vec<Type>,
     bindings: Vec<Binding>,
+    withs: Vec<Option<Path>>,
 }
 
 enum Name {
@@ -355,6 +512,7 @@ fn expand_struct(cx: &Ctxt, data: &DataStruct, attrs: &Attrs, what: What) -> Res
 
     for field in data.fields.iter() {
         let mut name = Name::None;
+        let mut with = None::<Path>;
 
         for attr in &field.attrs {
             if !attr.path().is_ident("sql") {
@@ -383,6 +541,18 @@ fn expand_struct(cx: &Ctxt, data: &DataStruct, attrs: &Attrs, what: What) -> Res
                     return Ok(());
                 }
 
+                if meta.path.is_ident("with") {
+                    if let Some(existing) = &with {
+                        return Err(Error::new_spanned(
+                            existing,
+                            "duplicate `with` attribute for field",
+                        ));
+                    }
+
+                    with = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
                 Err(Error::new_spanned(
                     meta.path,
                     "unknown attribute for `Row` derive",
@@ -395,6 +565,16 @@ fn expand_struct(cx: &Ctxt, data: &DataStruct, attrs: &Attrs, what: What) -> Res
             }
         }
 
+        // The name of the field after any container-level renaming.
+        let field_name = field.ident.as_ref().map(|ident| {
+            let name = ident.unraw().to_string();
+
+            match attrs.rename_all {
+                Some(rename_all) => rename_all.apply(&name),
+                None => name,
+            }
+        });
+
         let name = match (what, name, &field.ident) {
             (What::Bind, Name::LitCStr(name), _) => Some(name),
             (What::Bind, Name::LitStr(name), _) => {
@@ -412,7 +592,7 @@ fn expand_struct(cx: &Ctxt, data: &DataStruct, attrs: &Attrs, what: What) -> Res
                 Some(LitCStr::new(&c_str, name.span()))
             }
             (What::Bind, Name::None, Some(ident)) if attrs.named => {
-                let name = format!(":{ident}");
+                let name = format!(":{}", field_name.as_deref().unwrap_or_default());
 
                 let Ok(c_str) = CString::new(name.clone()) else {
                     cx.spanned(
@@ -458,8 +638,10 @@ fn expand_struct(cx: &Ctxt, data: &DataStruct, attrs: &Attrs, what: What) -> Res
         };
 
         st.fields.push(member);
+        st.names.push(field_name);
         st.types.push(field.ty.clone());
         st.bindings.push(access);
+        st.withs.push(with);
     }
 
     Ok(st)
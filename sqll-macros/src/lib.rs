@@ -13,6 +13,7 @@
 //! [`sqll` crate]: https://docs.rs/sqll
 
 mod implement;
+mod sql;
 
 #[proc_macro_derive(Row, attributes(sql))]
 pub fn row(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -23,3 +24,8 @@ pub fn row(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn bind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     implement::expand(input.into(), implement::What::Bind).into()
 }
+
+#[proc_macro]
+pub fn sql(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    sql::expand(input.into()).into()
+}
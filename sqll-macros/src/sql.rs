@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Path, Token};
+
+/// The largest parameter index supported by default in sqlite, which
+/// corresponds to `SQLITE_MAX_VARIABLE_NUMBER`.
+const MAX_VARIABLE_NUMBER: usize = 32766;
+
+struct Input {
+    crate_path: Path,
+    sql: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut crate_path = syn::parse_quote!(::sqll);
+
+        if input.peek(Token![crate]) {
+            input.parse::<Token![crate]>()?;
+            input.parse::<Token![=]>()?;
+            crate_path = input.parse()?;
+            input.parse::<Token![,]>()?;
+        }
+
+        let sql = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self { crate_path, sql })
+    }
+}
+
+pub(crate) fn expand(input: TokenStream) -> TokenStream {
+    let Input { crate_path, sql } = match syn::parse2::<Input>(input) {
+        Ok(input) => input,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let count = match count_parameters(&sql.value()) {
+        Ok(count) => count,
+        Err(message) => return syn::Error::new(sql.span(), message).to_compile_error(),
+    };
+
+    quote! {
+        #crate_path::Sql::<#count>::__new(#sql)
+    }
+}
+
+/// Count the number of parameters in a piece of SQL the same way as
+/// `sqlite3_bind_parameter_count`.
+///
+/// This means that the count is the largest parameter index, where:
+/// * `?` is assigned the largest index so far plus one.
+/// * `?NNN` is assigned the index `NNN`.
+/// * `:AAA`, `@AAA`, and `$AAA` are assigned the largest index so far plus one
+///   the first time they are seen, and reuse that index after that.
+///
+/// String literals, quoted identifiers, and comments are skipped.
+fn count_parameters(sql: &str) -> Result<usize, String> {
+    let mut chars = sql.char_indices().peekable();
+    let mut max = 0usize;
+    let mut names = HashSet::new();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                let kind = if c == '\'' {
+                    "string literal"
+                } else {
+                    "quoted identifier"
+                };
+
+                loop {
+                    match chars.next() {
+                        Some((_, end)) if end == c => {
+                            // A doubled quote is an escaped quote.
+                            if chars.next_if(|&(_, next)| next == c).is_none() {
+                                break;
+                            }
+                        }
+                        Some(..) => {}
+                        None => return Err(format!("unterminated {kind}")),
+                    }
+                }
+            }
+            '[' if !chars.by_ref().any(|(_, c)| c == ']') => {
+                return Err("unterminated quoted identifier".to_owned());
+            }
+            '-' if chars.next_if(|&(_, c)| c == '-').is_some() => {
+                for (_, c) in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.next_if(|&(_, c)| c == '*').is_some() => {
+                // NB: An unterminated block comment is permitted by sqlite and
+                // extends to the end of the input.
+                let mut last = '\0';
+
+                for (_, c) in chars.by_ref() {
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+
+                    last = c;
+                }
+            }
+            '?' => {
+                let mut end = start + 1;
+
+                while let Some((n, c)) = chars.next_if(|&(_, c)| c.is_ascii_digit()) {
+                    end = n + c.len_utf8();
+                }
+
+                let digits = &sql[start + 1..end];
+
+                if digits.is_empty() {
+                    max += 1;
+                } else {
+                    let index = match digits.parse::<usize>() {
+                        Ok(index @ 1..=MAX_VARIABLE_NUMBER) => index,
+                        _ => {
+                            return Err(format!(
+                                "parameter index `?{digits}` must be between 1 and {MAX_VARIABLE_NUMBER}"
+                            ));
+                        }
+                    };
+
+                    max = max.max(index);
+                }
+            }
+            ':' | '@' | '$' => {
+                let mut end = start + 1;
+
+                while let Some((n, c)) = chars.next_if(|&(_, c)| is_identifier(c)) {
+                    end = n + c.len_utf8();
+                }
+
+                let name = &sql[start..end];
+
+                if name.len() == 1 {
+                    return Err(format!("expected a parameter name after `{c}`"));
+                }
+
+                if names.insert(name) {
+                    max += 1;
+                }
+            }
+            c if is_identifier(c) => {
+                // NB: Identifiers may contain `$`, so it must not be treated as
+                // the start of a parameter.
+                while chars
+                    .next_if(|&(_, c)| is_identifier(c) || c == '$')
+                    .is_some()
+                {}
+            }
+            _ => {}
+        }
+
+        if max > MAX_VARIABLE_NUMBER {
+            return Err(format!(
+                "too many parameters, at most {MAX_VARIABLE_NUMBER} are supported"
+            ));
+        }
+    }
+
+    Ok(max)
+}
+
+fn is_identifier(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || !c.is_ascii()
+}
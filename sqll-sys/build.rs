@@ -11,6 +11,7 @@ use semver::{Version, VersionReq};
 const SQLITE_VERSION: &str = "3.37.0";
 const CLANG_ENV: &[&str] = &["SQLL_CLANG_PATH", "CLANG_PATH"];
 const TARGET_ENV: &[&str] = &["SQLL_TARGET", "TARGET"];
+const MIN_VERSION_ENV: &[&str] = &["SQLL_MIN_SQLITE_VERSION"];
 
 fn main() {
     if cfg!(feature = "bundled") {
@@ -33,10 +34,24 @@ fn env(names: &[&'static str]) -> Option<OsString> {
 }
 
 fn system() {
-    let Ok(version_req) = SQLITE_VERSION.parse::<VersionReq>() else {
+    let Ok(mut version_req) = SQLITE_VERSION.parse::<VersionReq>() else {
         panic!("invalid version: {SQLITE_VERSION}");
     };
 
+    if let Some(value) = env(MIN_VERSION_ENV) {
+        let value = value.to_string_lossy();
+
+        let Ok(req) = value.parse::<VersionReq>() else {
+            panic!("invalid version in {}: {value}", MIN_VERSION_ENV[0]);
+        };
+
+        println!(
+            "cargo:warning=overriding the minimum required system sqlite3 version {version_req} with {req}, functions missing from older versions might fail to link or misbehave at runtime"
+        );
+
+        version_req = req;
+    }
+
     let mut errors = Vec::new();
 
     match pkg_config::find_library("sqlite3") {
@@ -47,8 +62,12 @@ fn system() {
 
             if !version_req.matches(&version) {
                 panic!(
-                    "system sqlite3 library version {} does not match required version {}",
-                    library.version, version_req
+                    "system sqlite3 library version {} does not match required version {}\n\n\
+                    The minimum can be lowered by setting the {} environment variable, such as to {}.\n\
+                    This is done at your own risk, since functions missing from older versions\n\
+                    might fail to link or misbehave at runtime. Consider enabling the `bundled`\n\
+                    feature instead.",
+                    library.version, version_req, MIN_VERSION_ENV[0], library.version
                 );
             }
 
@@ -123,16 +142,44 @@ fn bundled() {
         build.target(&target);
     }
 
-    if !cfg!(feature = "threadsafe") || is_wasm {
+    // Serialized is the default threading mode when building sqlite, so it
+    // does not need to be defined.
+    if is_wasm
+        || !cfg!(any(
+            feature = "threadsafe-serialized",
+            feature = "threadsafe-multi"
+        ))
+    {
         build.define("SQLITE_THREADSAFE", "0");
+    } else if !cfg!(feature = "threadsafe-serialized") {
+        build.define("SQLITE_THREADSAFE", "2");
     }
 
     if is_wasm {
         build.define("SQLITE_OMIT_LOAD_EXTENSION", "1");
     }
 
+    if cfg!(feature = "math") {
+        build.define("SQLITE_ENABLE_MATH_FUNCTIONS", "1");
+    }
+
+    if cfg!(feature = "normalize") {
+        build.define("SQLITE_ENABLE_NORMALIZE", "1");
+    }
+
     if cfg!(feature = "strict") {
         build.flags(["-Wall", "-Wextra", "-Werror"]);
+
+        // The math functions in the amalgamation don't use all of their
+        // parameters.
+        if cfg!(feature = "math") {
+            build.flag("-Wno-unused-parameter");
+        }
+
+        // Normalization relies on implicit fallthrough in switch statements.
+        if cfg!(feature = "normalize") {
+            build.flag("-Wno-implicit-fallthrough");
+        }
     }
 
     if cfg!(not(debug_assertions)) {
@@ -12,6 +12,42 @@ pub const SQLITE_OPEN_SHAREDCACHE: ::core::ffi::c_int = 131072;
 pub const SQLITE_OPEN_PRIVATECACHE: ::core::ffi::c_int = 262144;
 pub const SQLITE_OPEN_NOFOLLOW: ::core::ffi::c_int = 16777216;
 pub const SQLITE_OPEN_EXRESCODE: ::core::ffi::c_int = 33554432;
+pub const SQLITE_CONFIG_LOG: ::core::ffi::c_int = 16;
+pub const SQLITE_DENY: ::core::ffi::c_int = 1;
+pub const SQLITE_IGNORE: ::core::ffi::c_int = 2;
+pub const SQLITE_CREATE_INDEX: ::core::ffi::c_int = 1;
+pub const SQLITE_CREATE_TABLE: ::core::ffi::c_int = 2;
+pub const SQLITE_CREATE_TEMP_INDEX: ::core::ffi::c_int = 3;
+pub const SQLITE_CREATE_TEMP_TABLE: ::core::ffi::c_int = 4;
+pub const SQLITE_CREATE_TEMP_TRIGGER: ::core::ffi::c_int = 5;
+pub const SQLITE_CREATE_TEMP_VIEW: ::core::ffi::c_int = 6;
+pub const SQLITE_CREATE_TRIGGER: ::core::ffi::c_int = 7;
+pub const SQLITE_CREATE_VIEW: ::core::ffi::c_int = 8;
+pub const SQLITE_DELETE: ::core::ffi::c_int = 9;
+pub const SQLITE_DROP_INDEX: ::core::ffi::c_int = 10;
+pub const SQLITE_DROP_TABLE: ::core::ffi::c_int = 11;
+pub const SQLITE_DROP_TEMP_INDEX: ::core::ffi::c_int = 12;
+pub const SQLITE_DROP_TEMP_TABLE: ::core::ffi::c_int = 13;
+pub const SQLITE_DROP_TEMP_TRIGGER: ::core::ffi::c_int = 14;
+pub const SQLITE_DROP_TEMP_VIEW: ::core::ffi::c_int = 15;
+pub const SQLITE_DROP_TRIGGER: ::core::ffi::c_int = 16;
+pub const SQLITE_DROP_VIEW: ::core::ffi::c_int = 17;
+pub const SQLITE_INSERT: ::core::ffi::c_int = 18;
+pub const SQLITE_PRAGMA: ::core::ffi::c_int = 19;
+pub const SQLITE_READ: ::core::ffi::c_int = 20;
+pub const SQLITE_SELECT: ::core::ffi::c_int = 21;
+pub const SQLITE_TRANSACTION: ::core::ffi::c_int = 22;
+pub const SQLITE_UPDATE: ::core::ffi::c_int = 23;
+pub const SQLITE_ATTACH: ::core::ffi::c_int = 24;
+pub const SQLITE_DETACH: ::core::ffi::c_int = 25;
+pub const SQLITE_ALTER_TABLE: ::core::ffi::c_int = 26;
+pub const SQLITE_REINDEX: ::core::ffi::c_int = 27;
+pub const SQLITE_ANALYZE: ::core::ffi::c_int = 28;
+pub const SQLITE_CREATE_VTABLE: ::core::ffi::c_int = 29;
+pub const SQLITE_DROP_VTABLE: ::core::ffi::c_int = 30;
+pub const SQLITE_FUNCTION: ::core::ffi::c_int = 31;
+pub const SQLITE_SAVEPOINT: ::core::ffi::c_int = 32;
+pub const SQLITE_RECURSIVE: ::core::ffi::c_int = 33;
 pub const SQLITE_PREPARE_PERSISTENT: ::core::ffi::c_int = 1;
 pub const SQLITE_PREPARE_NORMALIZE: ::core::ffi::c_int = 2;
 pub const SQLITE_PREPARE_NO_VTAB: ::core::ffi::c_int = 4;
@@ -20,9 +56,41 @@ pub const SQLITE_FLOAT: ::core::ffi::c_int = 2;
 pub const SQLITE_BLOB: ::core::ffi::c_int = 4;
 pub const SQLITE_NULL: ::core::ffi::c_int = 5;
 pub const SQLITE_TEXT: ::core::ffi::c_int = 3;
+pub const SQLITE_UTF8: ::core::ffi::c_int = 1;
+pub const SQLITE_DETERMINISTIC: ::core::ffi::c_int = 2048;
+pub const SQLITE_DIRECTONLY: ::core::ffi::c_int = 524288;
+pub const SQLITE_INNOCUOUS: ::core::ffi::c_int = 2097152;
+pub const SQLITE_TXN_NONE: ::core::ffi::c_int = 0;
+pub const SQLITE_TXN_READ: ::core::ffi::c_int = 1;
+pub const SQLITE_TXN_WRITE: ::core::ffi::c_int = 2;
+pub const SQLITE_DBSTATUS_CACHE_USED: ::core::ffi::c_int = 1;
+pub const SQLITE_DBSTATUS_CACHE_HIT: ::core::ffi::c_int = 7;
+pub const SQLITE_DBSTATUS_CACHE_MISS: ::core::ffi::c_int = 8;
+pub const SQLITE_DBSTATUS_CACHE_WRITE: ::core::ffi::c_int = 9;
+pub const SQLITE_DESERIALIZE_FREEONCLOSE: ::core::ffi::c_int = 1;
+pub const SQLITE_DESERIALIZE_RESIZEABLE: ::core::ffi::c_int = 2;
+pub const SQLITE_DESERIALIZE_READONLY: ::core::ffi::c_int = 4;
+pub const SQLITE_INDEX_CONSTRAINT_EQ: ::core::ffi::c_int = 2;
+pub const SQLITE_INDEX_CONSTRAINT_GT: ::core::ffi::c_int = 4;
+pub const SQLITE_INDEX_CONSTRAINT_LE: ::core::ffi::c_int = 8;
+pub const SQLITE_INDEX_CONSTRAINT_LT: ::core::ffi::c_int = 16;
+pub const SQLITE_INDEX_CONSTRAINT_GE: ::core::ffi::c_int = 32;
+pub const SQLITE_INDEX_CONSTRAINT_MATCH: ::core::ffi::c_int = 64;
+pub const SQLITE_INDEX_CONSTRAINT_LIKE: ::core::ffi::c_int = 65;
+pub const SQLITE_INDEX_CONSTRAINT_GLOB: ::core::ffi::c_int = 66;
+pub const SQLITE_INDEX_CONSTRAINT_REGEXP: ::core::ffi::c_int = 67;
+pub const SQLITE_INDEX_CONSTRAINT_NE: ::core::ffi::c_int = 68;
+pub const SQLITE_INDEX_CONSTRAINT_ISNOT: ::core::ffi::c_int = 69;
+pub const SQLITE_INDEX_CONSTRAINT_ISNOTNULL: ::core::ffi::c_int = 70;
+pub const SQLITE_INDEX_CONSTRAINT_ISNULL: ::core::ffi::c_int = 71;
+pub const SQLITE_INDEX_CONSTRAINT_IS: ::core::ffi::c_int = 72;
+pub const SQLITE_INDEX_CONSTRAINT_FUNCTION: ::core::ffi::c_int = 150;
 unsafe extern "C" {
     pub fn sqlite3_libversion() -> *const ::core::ffi::c_char;
 }
+unsafe extern "C" {
+    pub fn sqlite3_sourceid() -> *const ::core::ffi::c_char;
+}
 unsafe extern "C" {
     pub fn sqlite3_libversion_number() -> ::core::ffi::c_int;
 }
@@ -34,10 +102,15 @@ pub struct sqlite3 {
     _unused: [u8; 0],
 }
 pub type sqlite_int64 = ::core::ffi::c_longlong;
+pub type sqlite_uint64 = ::core::ffi::c_ulonglong;
 pub type sqlite3_int64 = sqlite_int64;
+pub type sqlite3_uint64 = sqlite_uint64;
 unsafe extern "C" {
     pub fn sqlite3_close_v2(arg1: *mut sqlite3) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_config(arg1: ::core::ffi::c_int, ...) -> ::core::ffi::c_int;
+}
 unsafe extern "C" {
     pub fn sqlite3_extended_result_codes(
         arg1: *mut sqlite3,
@@ -53,6 +126,12 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn sqlite3_total_changes(arg1: *mut sqlite3) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_total_changes64(arg1: *mut sqlite3) -> sqlite3_int64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_interrupt(arg1: *mut sqlite3);
+}
 unsafe extern "C" {
     pub fn sqlite3_busy_handler(
         arg1: *mut sqlite3,
@@ -71,9 +150,37 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn sqlite3_malloc(arg1: ::core::ffi::c_int) -> *mut ::core::ffi::c_void;
 }
+unsafe extern "C" {
+    pub fn sqlite3_malloc64(arg1: sqlite3_uint64) -> *mut ::core::ffi::c_void;
+}
 unsafe extern "C" {
     pub fn sqlite3_free(arg1: *mut ::core::ffi::c_void);
 }
+unsafe extern "C" {
+    pub fn sqlite3_msize(arg1: *mut ::core::ffi::c_void) -> sqlite3_uint64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_memory_used() -> sqlite3_int64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_memory_highwater(resetFlag: ::core::ffi::c_int) -> sqlite3_int64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_set_authorizer(
+        arg1: *mut sqlite3,
+        xAuth: ::core::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut ::core::ffi::c_void,
+                arg2: ::core::ffi::c_int,
+                arg3: *const ::core::ffi::c_char,
+                arg4: *const ::core::ffi::c_char,
+                arg5: *const ::core::ffi::c_char,
+                arg6: *const ::core::ffi::c_char,
+            ) -> ::core::ffi::c_int,
+        >,
+        pUserData: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+}
 unsafe extern "C" {
     pub fn sqlite3_open_v2(
         filename: *const ::core::ffi::c_char,
@@ -82,6 +189,12 @@ unsafe extern "C" {
         zVfs: *const ::core::ffi::c_char,
     ) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_errcode(db: *mut sqlite3) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_extended_errcode(db: *mut sqlite3) -> ::core::ffi::c_int;
+}
 unsafe extern "C" {
     pub fn sqlite3_errmsg(arg1: *mut sqlite3) -> *const ::core::ffi::c_char;
 }
@@ -102,6 +215,22 @@ unsafe extern "C" {
         pzTail: *mut *const ::core::ffi::c_char,
     ) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_prepare16_v3(
+        db: *mut sqlite3,
+        zSql: *const ::core::ffi::c_void,
+        nByte: ::core::ffi::c_int,
+        prepFlags: ::core::ffi::c_uint,
+        ppStmt: *mut *mut sqlite3_stmt,
+        pzTail: *mut *const ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_sql(pStmt: *mut sqlite3_stmt) -> *const ::core::ffi::c_char;
+}
+unsafe extern "C" {
+    pub fn sqlite3_normalized_sql(pStmt: *mut sqlite3_stmt) -> *const ::core::ffi::c_char;
+}
 unsafe extern "C" {
     pub fn sqlite3_bind_blob(
         arg1: *mut sqlite3_stmt,
@@ -140,6 +269,9 @@ unsafe extern "C" {
         arg5: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
     ) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_bind_parameter_count(arg1: *mut sqlite3_stmt) -> ::core::ffi::c_int;
+}
 unsafe extern "C" {
     pub fn sqlite3_bind_parameter_name(
         arg1: *mut sqlite3_stmt,
@@ -164,6 +296,12 @@ unsafe extern "C" {
         N: ::core::ffi::c_int,
     ) -> *const ::core::ffi::c_char;
 }
+unsafe extern "C" {
+    pub fn sqlite3_column_decltype(
+        arg1: *mut sqlite3_stmt,
+        arg2: ::core::ffi::c_int,
+    ) -> *const ::core::ffi::c_char;
+}
 unsafe extern "C" {
     pub fn sqlite3_step(arg1: *mut sqlite3_stmt) -> ::core::ffi::c_int;
 }
@@ -213,3 +351,365 @@ unsafe extern "C" {
         zDbName: *const ::core::ffi::c_char,
     ) -> ::core::ffi::c_int;
 }
+unsafe extern "C" {
+    pub fn sqlite3_txn_state(
+        arg1: *mut sqlite3,
+        zSchema: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_next_stmt(pDb: *mut sqlite3, pStmt: *mut sqlite3_stmt) -> *mut sqlite3_stmt;
+}
+unsafe extern "C" {
+    pub fn sqlite3_get_autocommit(arg1: *mut sqlite3) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_soft_heap_limit64(N: sqlite3_int64) -> sqlite3_int64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_hard_heap_limit64(N: sqlite3_int64) -> sqlite3_int64;
+}
+#[repr(C)]
+pub struct sqlite3_value {
+    _unused: [u8; 0],
+}
+#[repr(C)]
+pub struct sqlite3_context {
+    _unused: [u8; 0],
+}
+unsafe extern "C" {
+    pub fn sqlite3_create_function_v2(
+        db: *mut sqlite3,
+        zFunctionName: *const ::core::ffi::c_char,
+        nArg: ::core::ffi::c_int,
+        eTextRep: ::core::ffi::c_int,
+        pApp: *mut ::core::ffi::c_void,
+        xFunc: ::core::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut sqlite3_context,
+                arg2: ::core::ffi::c_int,
+                arg3: *mut *mut sqlite3_value,
+            ),
+        >,
+        xStep: ::core::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut sqlite3_context,
+                arg2: ::core::ffi::c_int,
+                arg3: *mut *mut sqlite3_value,
+            ),
+        >,
+        xFinal: ::core::option::Option<unsafe extern "C" fn(arg1: *mut sqlite3_context)>,
+        xDestroy: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_create_window_function(
+        db: *mut sqlite3,
+        zFunctionName: *const ::core::ffi::c_char,
+        nArg: ::core::ffi::c_int,
+        eTextRep: ::core::ffi::c_int,
+        pApp: *mut ::core::ffi::c_void,
+        xStep: ::core::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut sqlite3_context,
+                arg2: ::core::ffi::c_int,
+                arg3: *mut *mut sqlite3_value,
+            ),
+        >,
+        xFinal: ::core::option::Option<unsafe extern "C" fn(arg1: *mut sqlite3_context)>,
+        xValue: ::core::option::Option<unsafe extern "C" fn(arg1: *mut sqlite3_context)>,
+        xInverse: ::core::option::Option<
+            unsafe extern "C" fn(
+                arg1: *mut sqlite3_context,
+                arg2: ::core::ffi::c_int,
+                arg3: *mut *mut sqlite3_value,
+            ),
+        >,
+        xDestroy: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_blob(arg1: *mut sqlite3_value) -> *const ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_double(arg1: *mut sqlite3_value) -> f64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_int64(arg1: *mut sqlite3_value) -> sqlite3_int64;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_text(arg1: *mut sqlite3_value) -> *const ::core::ffi::c_uchar;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_bytes(arg1: *mut sqlite3_value) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_value_type(arg1: *mut sqlite3_value) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_aggregate_context(
+        arg1: *mut sqlite3_context,
+        nBytes: ::core::ffi::c_int,
+    ) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn sqlite3_user_data(arg1: *mut sqlite3_context) -> *mut ::core::ffi::c_void;
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_blob(
+        arg1: *mut sqlite3_context,
+        arg2: *const ::core::ffi::c_void,
+        arg3: ::core::ffi::c_int,
+        arg4: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    );
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_double(arg1: *mut sqlite3_context, arg2: f64);
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_error(
+        arg1: *mut sqlite3_context,
+        arg2: *const ::core::ffi::c_char,
+        arg3: ::core::ffi::c_int,
+    );
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_error_nomem(arg1: *mut sqlite3_context);
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_error_code(arg1: *mut sqlite3_context, arg2: ::core::ffi::c_int);
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_int64(arg1: *mut sqlite3_context, arg2: sqlite3_int64);
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_null(arg1: *mut sqlite3_context);
+}
+unsafe extern "C" {
+    pub fn sqlite3_result_text(
+        arg1: *mut sqlite3_context,
+        arg2: *const ::core::ffi::c_char,
+        arg3: ::core::ffi::c_int,
+        arg4: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    );
+}
+unsafe extern "C" {
+    pub fn sqlite3_db_status(
+        arg1: *mut sqlite3,
+        op: ::core::ffi::c_int,
+        pCur: *mut ::core::ffi::c_int,
+        pHiwtr: *mut ::core::ffi::c_int,
+        resetFlg: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_deserialize(
+        db: *mut sqlite3,
+        zSchema: *const ::core::ffi::c_char,
+        pData: *mut ::core::ffi::c_uchar,
+        szDb: sqlite3_int64,
+        szBuf: sqlite3_int64,
+        mFlags: ::core::ffi::c_uint,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_serialize(
+        db: *mut sqlite3,
+        zSchema: *const ::core::ffi::c_char,
+        piSize: *mut sqlite3_int64,
+        mFlags: ::core::ffi::c_uint,
+    ) -> *mut ::core::ffi::c_uchar;
+}
+#[repr(C)]
+pub struct sqlite3_vtab {
+    pub pModule: *const sqlite3_module,
+    pub nRef: ::core::ffi::c_int,
+    pub zErrMsg: *mut ::core::ffi::c_char,
+}
+#[repr(C)]
+pub struct sqlite3_vtab_cursor {
+    pub pVtab: *mut sqlite3_vtab,
+}
+#[repr(C)]
+pub struct sqlite3_index_info {
+    pub nConstraint: ::core::ffi::c_int,
+    pub aConstraint: *mut sqlite3_index_info_sqlite3_index_constraint,
+    pub nOrderBy: ::core::ffi::c_int,
+    pub aOrderBy: *mut sqlite3_index_info_sqlite3_index_orderby,
+    pub aConstraintUsage: *mut sqlite3_index_info_sqlite3_index_constraint_usage,
+    pub idxNum: ::core::ffi::c_int,
+    pub idxStr: *mut ::core::ffi::c_char,
+    pub needToFreeIdxStr: ::core::ffi::c_int,
+    pub orderByConsumed: ::core::ffi::c_int,
+    pub estimatedCost: f64,
+    pub estimatedRows: sqlite3_int64,
+    pub idxFlags: ::core::ffi::c_int,
+    pub colUsed: sqlite3_uint64,
+}
+#[repr(C)]
+pub struct sqlite3_index_info_sqlite3_index_constraint {
+    pub iColumn: ::core::ffi::c_int,
+    pub op: ::core::ffi::c_uchar,
+    pub usable: ::core::ffi::c_uchar,
+    pub iTermOffset: ::core::ffi::c_int,
+}
+#[repr(C)]
+pub struct sqlite3_index_info_sqlite3_index_orderby {
+    pub iColumn: ::core::ffi::c_int,
+    pub desc: ::core::ffi::c_uchar,
+}
+#[repr(C)]
+pub struct sqlite3_index_info_sqlite3_index_constraint_usage {
+    pub argvIndex: ::core::ffi::c_int,
+    pub omit: ::core::ffi::c_uchar,
+}
+#[repr(C)]
+pub struct sqlite3_module {
+    pub iVersion: ::core::ffi::c_int,
+    pub xCreate: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3,
+            pAux: *mut ::core::ffi::c_void,
+            argc: ::core::ffi::c_int,
+            argv: *const *const ::core::ffi::c_char,
+            ppVTab: *mut *mut sqlite3_vtab,
+            arg2: *mut *mut ::core::ffi::c_char,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xConnect: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3,
+            pAux: *mut ::core::ffi::c_void,
+            argc: ::core::ffi::c_int,
+            argv: *const *const ::core::ffi::c_char,
+            ppVTab: *mut *mut sqlite3_vtab,
+            arg2: *mut *mut ::core::ffi::c_char,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xBestIndex: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVTab: *mut sqlite3_vtab,
+            arg1: *mut sqlite3_index_info,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xDisconnect: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xDestroy: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xOpen: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVTab: *mut sqlite3_vtab,
+            ppCursor: *mut *mut sqlite3_vtab_cursor,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xClose: ::core::option::Option<
+        unsafe extern "C" fn(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int,
+    >,
+    pub xFilter: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3_vtab_cursor,
+            idxNum: ::core::ffi::c_int,
+            idxStr: *const ::core::ffi::c_char,
+            argc: ::core::ffi::c_int,
+            argv: *mut *mut sqlite3_value,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xNext: ::core::option::Option<
+        unsafe extern "C" fn(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int,
+    >,
+    pub xEof: ::core::option::Option<
+        unsafe extern "C" fn(arg1: *mut sqlite3_vtab_cursor) -> ::core::ffi::c_int,
+    >,
+    pub xColumn: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3_vtab_cursor,
+            arg2: *mut sqlite3_context,
+            arg3: ::core::ffi::c_int,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xRowid: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3_vtab_cursor,
+            pRowid: *mut sqlite3_int64,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xUpdate: ::core::option::Option<
+        unsafe extern "C" fn(
+            arg1: *mut sqlite3_vtab,
+            arg2: ::core::ffi::c_int,
+            arg3: *mut *mut sqlite3_value,
+            arg4: *mut sqlite3_int64,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xBegin: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xSync: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xCommit: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xRollback: ::core::option::Option<
+        unsafe extern "C" fn(pVTab: *mut sqlite3_vtab) -> ::core::ffi::c_int,
+    >,
+    pub xFindFunction: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVtab: *mut sqlite3_vtab,
+            nArg: ::core::ffi::c_int,
+            zName: *const ::core::ffi::c_char,
+            pxFunc: *mut ::core::option::Option<
+                unsafe extern "C" fn(
+                    arg1: *mut sqlite3_context,
+                    arg2: ::core::ffi::c_int,
+                    arg3: *mut *mut sqlite3_value,
+                ),
+            >,
+            ppArg: *mut *mut ::core::ffi::c_void,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xRename: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVtab: *mut sqlite3_vtab,
+            zNew: *const ::core::ffi::c_char,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xSavepoint: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVTab: *mut sqlite3_vtab,
+            arg1: ::core::ffi::c_int,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xRelease: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVTab: *mut sqlite3_vtab,
+            arg1: ::core::ffi::c_int,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xRollbackTo: ::core::option::Option<
+        unsafe extern "C" fn(
+            pVTab: *mut sqlite3_vtab,
+            arg1: ::core::ffi::c_int,
+        ) -> ::core::ffi::c_int,
+    >,
+    pub xShadowName: ::core::option::Option<
+        unsafe extern "C" fn(arg1: *const ::core::ffi::c_char) -> ::core::ffi::c_int,
+    >,
+}
+unsafe extern "C" {
+    pub fn sqlite3_create_module_v2(
+        db: *mut sqlite3,
+        zName: *const ::core::ffi::c_char,
+        p: *const sqlite3_module,
+        pClientData: *mut ::core::ffi::c_void,
+        xDestroy: ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void)>,
+    ) -> ::core::ffi::c_int;
+}
+unsafe extern "C" {
+    pub fn sqlite3_declare_vtab(
+        arg1: *mut sqlite3,
+        zSQL: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
+}
@@ -0,0 +1,18 @@
+//! Bindings which are only available in the bundled version of sqlite.
+//!
+//! The generated bindings in `base.rs` are produced from the header of the
+//! minimum supported version, so anything introduced after it is declared
+//! here by hand instead.
+
+use crate::sqlite3;
+
+pub const SQLITE_INDEX_CONSTRAINT_LIMIT: ::core::ffi::c_int = 73;
+pub const SQLITE_INDEX_CONSTRAINT_OFFSET: ::core::ffi::c_int = 74;
+
+unsafe extern "C" {
+    pub fn sqlite3_is_interrupted(arg1: *mut sqlite3) -> ::core::ffi::c_int;
+}
+
+unsafe extern "C" {
+    pub fn sqlite3_error_offset(db: *mut sqlite3) -> ::core::ffi::c_int;
+}
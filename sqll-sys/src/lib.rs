@@ -13,11 +13,26 @@
 //!
 //! * `bundled` - Use the bundled sqlite3 source code. If this feature is not
 //!   enabled see the building with system dependencies section below.
-//! * `threadsafe` - Build sqlite3 with threadsafe support. If this is not set
-//!   then the `bundled` feature has to be set since we otherwise cannot control
-//!   how sqlite is built.
+//! * `threadsafe` - Build sqlite3 with threadsafe support. This is an alias for
+//!   `threadsafe-serialized`. If none of the threadsafe features are set then
+//!   the `bundled` feature has to be set since we otherwise cannot control how
+//!   sqlite is built.
+//! * `threadsafe-serialized` - Build sqlite3 with the "serialized" threading mode
+//!   as the default (`SQLITE_THREADSAFE=1`). This is only used when the `bundled`
+//!   feature is enabled.
+//! * `threadsafe-multi` - Build sqlite3 with the "multi-thread" threading mode as
+//!   the default (`SQLITE_THREADSAFE=2`). This only has an effect if
+//!   `threadsafe-serialized` is not enabled and is only used when the `bundled`
+//!   feature is enabled. Individual connections can still opt into the
+//!   serialized threading mode when opened.
 //! * `strict` - Build sqlite3 with strict compiler flags enabled. This is only
 //!   used when the `bundled` feature is enabled.
+//! * `math` - Build sqlite3 with the built-in [math functions] such as `sqrt`,
+//!   `pow`, and `log` enabled. This is only used when the `bundled` feature is
+//!   enabled. System libraries may or may not have them enabled.
+//! * `normalize` - Build sqlite3 with [`sqlite3_normalized_sql`] enabled. This
+//!   is only used when the `bundled` feature is enabled. System libraries rarely
+//!   have it enabled.
 //!
 //! <br>
 //!
@@ -27,6 +42,12 @@
 //! This is specified in the [`sqlite3-version`] file and is checked at build
 //! time.
 //!
+//! The minimum can be lowered by setting the `SQLL_MIN_SQLITE_VERSION`
+//! environment variable to a version requirement such as `3.31.0`. This is
+//! useful for platforms which are stuck with an older system library, but is
+//! done at your own risk since functions missing from older versions might fail
+//! to link or misbehave at runtime.
+//!
 //! If the `bundled` feature is not set, this will attempt to find the native
 //! sqlite3 bindings using the following methods:
 //! * Calling `vcpkg`, this can be disabled by setting the `NO_VCPKG` or
@@ -49,6 +70,8 @@
 //!   compiler installation.
 //!
 //! [`sqlite3-version`]: https://github.com/udoprog/sqll/blob/main/sqll-sys/sqlite3-version
+//! [math functions]: https://www.sqlite.org/lang_mathfunc.html
+//! [`sqlite3_normalized_sql`]: https://www.sqlite.org/c3ref/expanded_sql.html
 //! [sqlite]: https://www.sqlite.org
 //! [sqll]: https://docs.rs/sqll
 
@@ -58,7 +81,19 @@
 mod base;
 pub use base::*;
 
-#[cfg(all(not(feature = "bundled"), not(feature = "threadsafe")))]
+#[cfg(feature = "bundled")]
+mod bundled;
+#[cfg(feature = "bundled")]
+pub use bundled::*;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(all(
+    not(feature = "bundled"),
+    not(feature = "threadsafe-serialized"),
+    not(feature = "threadsafe-multi")
+))]
 compile_error!(
-    "sqll-sys: If the `threadsafe` feature is disabled, the `bundled` feature must be enabled. Otherwise it has no effect."
+    "sqll-sys: If the `threadsafe`, `threadsafe-serialized`, or `threadsafe-multi` features are disabled, the `bundled` feature must be enabled. Otherwise it has no effect."
 );
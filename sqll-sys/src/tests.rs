@@ -0,0 +1,27 @@
+use core::ptr::null_mut;
+
+use super::{sqlite3_free, sqlite3_malloc, sqlite3_msize};
+
+#[test]
+fn test_free_null() {
+    unsafe {
+        sqlite3_free(null_mut());
+    }
+}
+
+#[test]
+fn test_msize_null() {
+    unsafe {
+        assert_eq!(sqlite3_msize(null_mut()), 0);
+    }
+}
+
+#[test]
+fn test_malloc_msize_free() {
+    unsafe {
+        let ptr = sqlite3_malloc(16);
+        assert!(!ptr.is_null());
+        assert!(sqlite3_msize(ptr) >= 16);
+        sqlite3_free(ptr);
+    }
+}
@@ -0,0 +1,208 @@
+use core::fmt;
+
+/// The type affinity of a column.
+///
+/// Every column in an sqlite table has a type affinity which is determined by
+/// its declared type. The affinity is the preferred storage class for data
+/// stored in that column, and is used to predict which [`ValueType`] values
+/// will be stored as.
+///
+/// See [`Affinity::of`] for how to determine the affinity of a declared type.
+///
+/// [`ValueType`]: crate::ValueType
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Affinity;
+///
+/// assert_eq!(Affinity::of("VARCHAR(255)"), Affinity::TEXT);
+/// assert_eq!(Affinity::of("BIGINT"), Affinity::INTEGER);
+/// assert_eq!(Affinity::of("DECIMAL(10, 5)"), Affinity::NUMERIC);
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct Affinity {
+    kind: AffinityKind,
+}
+
+impl Affinity {
+    /// The `TEXT` affinity.
+    ///
+    /// Columns with this affinity store all data using the [`TEXT`], [`BLOB`]
+    /// or [`NULL`] storage classes. Numerical data inserted is converted into
+    /// text.
+    ///
+    /// [`TEXT`]: crate::ValueType::TEXT
+    /// [`BLOB`]: crate::ValueType::BLOB
+    /// [`NULL`]: crate::ValueType::NULL
+    pub const TEXT: Self = Self::new(AffinityKind::Text);
+
+    /// The `NUMERIC` affinity.
+    ///
+    /// Columns with this affinity may contain values using all storage classes.
+    /// Text which is a well-formed integer or real literal is converted into
+    /// [`INTEGER`] or [`FLOAT`] respectively.
+    ///
+    /// [`INTEGER`]: crate::ValueType::INTEGER
+    /// [`FLOAT`]: crate::ValueType::FLOAT
+    pub const NUMERIC: Self = Self::new(AffinityKind::Numeric);
+
+    /// The `INTEGER` affinity.
+    ///
+    /// This behaves the same as [`NUMERIC`] except in `CAST` expressions.
+    ///
+    /// [`NUMERIC`]: Self::NUMERIC
+    pub const INTEGER: Self = Self::new(AffinityKind::Integer);
+
+    /// The `REAL` affinity.
+    ///
+    /// This behaves like [`NUMERIC`] except that it forces integer values into
+    /// a floating point representation.
+    ///
+    /// [`NUMERIC`]: Self::NUMERIC
+    pub const REAL: Self = Self::new(AffinityKind::Real);
+
+    /// The `BLOB` affinity.
+    ///
+    /// Columns with this affinity do not prefer one storage class over another
+    /// and no attempt is made to coerce data.
+    pub const BLOB: Self = Self::new(AffinityKind::Blob);
+
+    #[inline]
+    const fn new(kind: AffinityKind) -> Self {
+        Self { kind }
+    }
+
+    /// Determine the affinity of a declared column type.
+    ///
+    /// This follows the rules used by sqlite, which are applied in order:
+    ///
+    /// 1. If the declared type contains the string `"INT"` then it is assigned
+    ///    [`INTEGER`] affinity.
+    /// 2. If the declared type contains any of the strings `"CHAR"`, `"CLOB"`,
+    ///    or `"TEXT"` then the column has [`TEXT`] affinity.
+    /// 3. If the declared type contains the string `"BLOB"` or if no type is
+    ///    specified then the column has affinity [`BLOB`].
+    /// 4. If the declared type contains any of the strings `"REAL"`, `"FLOA"`,
+    ///    or `"DOUB"` then the column has [`REAL`] affinity.
+    /// 5. Otherwise, the affinity is [`NUMERIC`].
+    ///
+    /// All comparisons are case-insensitive.
+    ///
+    /// [`INTEGER`]: Self::INTEGER
+    /// [`TEXT`]: Self::TEXT
+    /// [`BLOB`]: Self::BLOB
+    /// [`REAL`]: Self::REAL
+    /// [`NUMERIC`]: Self::NUMERIC
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Affinity;
+    ///
+    /// assert_eq!(Affinity::of("INT"), Affinity::INTEGER);
+    /// assert_eq!(Affinity::of("unsigned big int"), Affinity::INTEGER);
+    /// assert_eq!(Affinity::of("VARCHAR"), Affinity::TEXT);
+    /// assert_eq!(Affinity::of("NATIVE CHARACTER(70)"), Affinity::TEXT);
+    /// assert_eq!(Affinity::of("CLOB"), Affinity::TEXT);
+    /// assert_eq!(Affinity::of("BLOB"), Affinity::BLOB);
+    /// assert_eq!(Affinity::of(""), Affinity::BLOB);
+    /// assert_eq!(Affinity::of("DOUBLE PRECISION"), Affinity::REAL);
+    /// assert_eq!(Affinity::of("float"), Affinity::REAL);
+    /// assert_eq!(Affinity::of("DECIMAL(10,5)"), Affinity::NUMERIC);
+    /// assert_eq!(Affinity::of("BOOLEAN"), Affinity::NUMERIC);
+    /// assert_eq!(Affinity::of("DATETIME"), Affinity::NUMERIC);
+    ///
+    /// // Note that rules are applied in order, so this is INTEGER and not REAL
+    /// // since it contains "INT".
+    /// assert_eq!(Affinity::of("FLOATING POINT"), Affinity::INTEGER);
+    /// // And this is TEXT, since "CHAR" is matched before "BLOB".
+    /// assert_eq!(Affinity::of("CHARBLOB"), Affinity::TEXT);
+    /// ```
+    #[inline]
+    pub fn of(declared: &str) -> Self {
+        Self::of_bytes(declared.as_bytes())
+    }
+
+    /// Determine the affinity of a declared column type which is not
+    /// necessarily valid UTF-8.
+    pub(crate) fn of_bytes(declared: &[u8]) -> Self {
+        if contains(declared, b"INT") {
+            return Self::INTEGER;
+        }
+
+        if contains(declared, b"CHAR") || contains(declared, b"CLOB") || contains(declared, b"TEXT")
+        {
+            return Self::TEXT;
+        }
+
+        if declared.is_empty() || contains(declared, b"BLOB") {
+            return Self::BLOB;
+        }
+
+        if contains(declared, b"REAL") || contains(declared, b"FLOA") || contains(declared, b"DOUB")
+        {
+            return Self::REAL;
+        }
+
+        Self::NUMERIC
+    }
+}
+
+/// Display implementation for [`Affinity`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Affinity;
+///
+/// assert_eq!(Affinity::TEXT.to_string(), "TEXT");
+/// assert_eq!(Affinity::NUMERIC.to_string(), "NUMERIC");
+/// assert_eq!(Affinity::INTEGER.to_string(), "INTEGER");
+/// assert_eq!(Affinity::REAL.to_string(), "REAL");
+/// assert_eq!(Affinity::BLOB.to_string(), "BLOB");
+/// ```
+impl fmt::Display for Affinity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+impl fmt::Debug for Affinity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+enum AffinityKind {
+    Text,
+    Numeric,
+    Integer,
+    Real,
+    Blob,
+}
+
+impl fmt::Display for AffinityKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AffinityKind::Text => write!(f, "TEXT"),
+            AffinityKind::Numeric => write!(f, "NUMERIC"),
+            AffinityKind::Integer => write!(f, "INTEGER"),
+            AffinityKind::Real => write!(f, "REAL"),
+            AffinityKind::Blob => write!(f, "BLOB"),
+        }
+    }
+}
+
+/// Test if the haystack contains the given uppercase needle ignoring ASCII
+/// case.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window.eq_ignore_ascii_case(needle))
+}
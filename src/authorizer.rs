@@ -0,0 +1,381 @@
+use core::ffi::{c_char, c_int};
+
+use crate::Text;
+use crate::ffi;
+use crate::utils::c_to_text;
+
+/// The result of an authorizer callback.
+///
+/// See [`Connection::set_authorizer`].
+///
+/// [`Connection::set_authorizer`]: crate::Connection::set_authorizer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthResult {
+    /// Allow the action.
+    Ok,
+    /// Deny the action, causing the statement which is being prepared to fail.
+    /// This is usually reported as [`Code::AUTH`].
+    ///
+    /// [`Code::AUTH`]: crate::Code::AUTH
+    Deny,
+    /// Disallow the specific action, but allow the statement to be prepared.
+    ///
+    /// For [`AuthKind::Read`] this causes the column to be read as `NULL`,
+    /// for [`AuthKind::Delete`] it disables the truncate optimization, and for
+    /// other actions it is treated like [`AuthResult::Deny`].
+    Ignore,
+}
+
+impl AuthResult {
+    #[inline]
+    pub(crate) fn raw(self) -> c_int {
+        match self {
+            AuthResult::Ok => ffi::SQLITE_OK,
+            AuthResult::Deny => ffi::SQLITE_DENY,
+            AuthResult::Ignore => ffi::SQLITE_IGNORE,
+        }
+    }
+}
+
+/// An action which is being authorized.
+///
+/// See [`Connection::set_authorizer`].
+///
+/// [`Connection::set_authorizer`]: crate::Connection::set_authorizer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuthAction<'a> {
+    kind: AuthKind<'a>,
+    database: Option<&'a Text>,
+    accessor: Option<&'a Text>,
+}
+
+impl<'a> AuthAction<'a> {
+    /// Decode an action from the arguments passed to the authorizer callback.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must either be null or valid null-terminated strings for
+    /// the duration of `'a`.
+    pub(crate) unsafe fn from_raw(
+        code: c_int,
+        arg1: *const c_char,
+        arg2: *const c_char,
+        database: *const c_char,
+        accessor: *const c_char,
+    ) -> Self {
+        unsafe {
+            Self {
+                kind: AuthKind::from_raw(code, c_to_text(arg1.cast()), c_to_text(arg2.cast())),
+                database: c_to_text(database.cast()),
+                accessor: c_to_text(accessor.cast()),
+            }
+        }
+    }
+
+    /// The kind of action being authorized, including its arguments.
+    #[inline]
+    pub fn kind(&self) -> AuthKind<'a> {
+        self.kind
+    }
+
+    /// The name of the database the action applies to, such as `main` or
+    /// `temp`, if applicable.
+    #[inline]
+    pub fn database(&self) -> Option<&'a Text> {
+        self.database
+    }
+
+    /// The name of the innermost trigger or view which is responsible for the
+    /// action, or `None` if the action is caused directly by top-level SQL.
+    #[inline]
+    pub fn accessor(&self) -> Option<&'a Text> {
+        self.accessor
+    }
+}
+
+/// The kind of an [`AuthAction`] along with its arguments.
+///
+/// See the [action codes] of SQLite for details.
+///
+/// [action codes]: https://sqlite.org/c3ref/c_alter_table.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum AuthKind<'a> {
+    /// An index is being created.
+    CreateIndex {
+        /// The name of the index.
+        index: &'a Text,
+        /// The table the index is created on.
+        table: &'a Text,
+    },
+    /// A table is being created.
+    CreateTable {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A temporary index is being created.
+    CreateTempIndex {
+        /// The name of the index.
+        index: &'a Text,
+        /// The table the index is created on.
+        table: &'a Text,
+    },
+    /// A temporary table is being created.
+    CreateTempTable {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A temporary trigger is being created.
+    CreateTempTrigger {
+        /// The name of the trigger.
+        trigger: &'a Text,
+        /// The table the trigger is created on.
+        table: &'a Text,
+    },
+    /// A temporary view is being created.
+    CreateTempView {
+        /// The name of the view.
+        view: &'a Text,
+    },
+    /// A trigger is being created.
+    CreateTrigger {
+        /// The name of the trigger.
+        trigger: &'a Text,
+        /// The table the trigger is created on.
+        table: &'a Text,
+    },
+    /// A view is being created.
+    CreateView {
+        /// The name of the view.
+        view: &'a Text,
+    },
+    /// Rows are being deleted from a table.
+    Delete {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// An index is being dropped.
+    DropIndex {
+        /// The name of the index.
+        index: &'a Text,
+        /// The table the index belongs to.
+        table: &'a Text,
+    },
+    /// A table is being dropped.
+    DropTable {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A temporary index is being dropped.
+    DropTempIndex {
+        /// The name of the index.
+        index: &'a Text,
+        /// The table the index belongs to.
+        table: &'a Text,
+    },
+    /// A temporary table is being dropped.
+    DropTempTable {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A temporary trigger is being dropped.
+    DropTempTrigger {
+        /// The name of the trigger.
+        trigger: &'a Text,
+        /// The table the trigger belongs to.
+        table: &'a Text,
+    },
+    /// A temporary view is being dropped.
+    DropTempView {
+        /// The name of the view.
+        view: &'a Text,
+    },
+    /// A trigger is being dropped.
+    DropTrigger {
+        /// The name of the trigger.
+        trigger: &'a Text,
+        /// The table the trigger belongs to.
+        table: &'a Text,
+    },
+    /// A view is being dropped.
+    DropView {
+        /// The name of the view.
+        view: &'a Text,
+    },
+    /// Rows are being inserted into a table.
+    Insert {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A pragma is being used.
+    Pragma {
+        /// The name of the pragma.
+        name: &'a Text,
+        /// The argument to the pragma, if any.
+        argument: Option<&'a Text>,
+    },
+    /// A column is being read.
+    Read {
+        /// The name of the table.
+        table: &'a Text,
+        /// The name of the column.
+        column: &'a Text,
+    },
+    /// A `SELECT` statement is being prepared.
+    Select,
+    /// A transaction is being started, committed or rolled back.
+    Transaction {
+        /// The operation, such as `BEGIN`, `COMMIT` or `ROLLBACK`.
+        operation: &'a Text,
+    },
+    /// A column is being updated.
+    Update {
+        /// The name of the table.
+        table: &'a Text,
+        /// The name of the column.
+        column: &'a Text,
+    },
+    /// A database is being attached.
+    Attach {
+        /// The filename of the database.
+        filename: &'a Text,
+    },
+    /// A database is being detached.
+    Detach {
+        /// The name of the database.
+        database: &'a Text,
+    },
+    /// A table is being altered.
+    AlterTable {
+        /// The name of the database.
+        database: &'a Text,
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// An index is being rebuilt.
+    Reindex {
+        /// The name of the index.
+        index: &'a Text,
+    },
+    /// A table is being analyzed.
+    Analyze {
+        /// The name of the table.
+        table: &'a Text,
+    },
+    /// A virtual table is being created.
+    CreateVtable {
+        /// The name of the table.
+        table: &'a Text,
+        /// The name of the module implementing the table.
+        module: &'a Text,
+    },
+    /// A virtual table is being dropped.
+    DropVtable {
+        /// The name of the table.
+        table: &'a Text,
+        /// The name of the module implementing the table.
+        module: &'a Text,
+    },
+    /// A function is being called.
+    Function {
+        /// The name of the function.
+        name: &'a Text,
+    },
+    /// A savepoint is being used.
+    Savepoint {
+        /// The operation, such as `BEGIN`, `RELEASE` or `ROLLBACK`.
+        operation: &'a Text,
+        /// The name of the savepoint.
+        name: &'a Text,
+    },
+    /// A recursive query is being prepared.
+    Recursive,
+    /// An action code which is not recognized.
+    Unknown {
+        /// The raw action code.
+        code: c_int,
+        /// The first argument to the action.
+        arg1: Option<&'a Text>,
+        /// The second argument to the action.
+        arg2: Option<&'a Text>,
+    },
+}
+
+impl<'a> AuthKind<'a> {
+    fn from_raw(code: c_int, arg1: Option<&'a Text>, arg2: Option<&'a Text>) -> Self {
+        // NB: SQLite documents which arguments are provided for each action,
+        // but to be safe a missing argument is treated as empty.
+        let a = arg1.unwrap_or(Text::new(""));
+        let b = arg2.unwrap_or(Text::new(""));
+
+        match code {
+            ffi::SQLITE_CREATE_INDEX => AuthKind::CreateIndex { index: a, table: b },
+            ffi::SQLITE_CREATE_TABLE => AuthKind::CreateTable { table: a },
+            ffi::SQLITE_CREATE_TEMP_INDEX => AuthKind::CreateTempIndex { index: a, table: b },
+            ffi::SQLITE_CREATE_TEMP_TABLE => AuthKind::CreateTempTable { table: a },
+            ffi::SQLITE_CREATE_TEMP_TRIGGER => AuthKind::CreateTempTrigger {
+                trigger: a,
+                table: b,
+            },
+            ffi::SQLITE_CREATE_TEMP_VIEW => AuthKind::CreateTempView { view: a },
+            ffi::SQLITE_CREATE_TRIGGER => AuthKind::CreateTrigger {
+                trigger: a,
+                table: b,
+            },
+            ffi::SQLITE_CREATE_VIEW => AuthKind::CreateView { view: a },
+            ffi::SQLITE_DELETE => AuthKind::Delete { table: a },
+            ffi::SQLITE_DROP_INDEX => AuthKind::DropIndex { index: a, table: b },
+            ffi::SQLITE_DROP_TABLE => AuthKind::DropTable { table: a },
+            ffi::SQLITE_DROP_TEMP_INDEX => AuthKind::DropTempIndex { index: a, table: b },
+            ffi::SQLITE_DROP_TEMP_TABLE => AuthKind::DropTempTable { table: a },
+            ffi::SQLITE_DROP_TEMP_TRIGGER => AuthKind::DropTempTrigger {
+                trigger: a,
+                table: b,
+            },
+            ffi::SQLITE_DROP_TEMP_VIEW => AuthKind::DropTempView { view: a },
+            ffi::SQLITE_DROP_TRIGGER => AuthKind::DropTrigger {
+                trigger: a,
+                table: b,
+            },
+            ffi::SQLITE_DROP_VIEW => AuthKind::DropView { view: a },
+            ffi::SQLITE_INSERT => AuthKind::Insert { table: a },
+            ffi::SQLITE_PRAGMA => AuthKind::Pragma {
+                name: a,
+                argument: arg2,
+            },
+            ffi::SQLITE_READ => AuthKind::Read {
+                table: a,
+                column: b,
+            },
+            ffi::SQLITE_SELECT => AuthKind::Select,
+            ffi::SQLITE_TRANSACTION => AuthKind::Transaction { operation: a },
+            ffi::SQLITE_UPDATE => AuthKind::Update {
+                table: a,
+                column: b,
+            },
+            ffi::SQLITE_ATTACH => AuthKind::Attach { filename: a },
+            ffi::SQLITE_DETACH => AuthKind::Detach { database: a },
+            ffi::SQLITE_ALTER_TABLE => AuthKind::AlterTable {
+                database: a,
+                table: b,
+            },
+            ffi::SQLITE_REINDEX => AuthKind::Reindex { index: a },
+            ffi::SQLITE_ANALYZE => AuthKind::Analyze { table: a },
+            ffi::SQLITE_CREATE_VTABLE => AuthKind::CreateVtable {
+                table: a,
+                module: b,
+            },
+            ffi::SQLITE_DROP_VTABLE => AuthKind::DropVtable {
+                table: a,
+                module: b,
+            },
+            ffi::SQLITE_FUNCTION => AuthKind::Function { name: b },
+            ffi::SQLITE_SAVEPOINT => AuthKind::Savepoint {
+                operation: a,
+                name: b,
+            },
+            ffi::SQLITE_RECURSIVE => AuthKind::Recursive,
+            code => AuthKind::Unknown { code, arg1, arg2 },
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use core::ffi::c_int;
+use core::fmt::{self, Write};
+use core::str;
+
+use crate::ty::{self, AnyKind};
+use crate::{
+    BIND_INDEX, Bind, BindValue, Code, Error, FromColumn, FromUnsizedColumn, Result, Statement,
+    ValueType,
+};
+
+/// The maximum number of bytes needed to format a supported integer in
+/// decimal, which is the length of `i128::MIN`.
+const MAX_LEN: usize = 40;
+
+/// An adapter which stores integers that might not fit in a 64-bit signed
+/// SQLite `INTEGER` as decimal text.
+///
+/// SQLite only supports integers in the range of [`i64`], so types like
+/// [`u64`], [`i128`] and [`u128`] error when they are bound or read if the
+/// value is out of range. Wrapping them in `BigInt` instead binds them as
+/// decimal `TEXT` and parses them back when read, allowing the full range of
+/// values to round-trip losslessly.
+///
+/// Values should be stored in columns with `TEXT` affinity. A column with
+/// `INTEGER` or `NUMERIC` affinity converts text which looks like a number to
+/// an integer if it fits, or otherwise to a lossy `REAL`. For convenience,
+/// reading an `INTEGER` column is supported, but reading a `REAL` column
+/// errors.
+///
+/// Note that since the values are stored as text, they do not sort or compare
+/// numerically in SQL.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{BigInt, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE balances (amount TEXT);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("INSERT INTO balances (amount) VALUES (?)")?;
+/// stmt.execute(BigInt::new(u128::MAX))?;
+/// stmt.execute(BigInt::new(42u128))?;
+///
+/// let mut stmt = c.prepare("SELECT amount FROM balances")?;
+///
+/// assert_eq!(stmt.next::<BigInt<u128>>()?, Some(BigInt::new(u128::MAX)));
+/// assert_eq!(stmt.next::<BigInt<u128>>()?, Some(BigInt::new(42)));
+/// assert_eq!(stmt.next::<BigInt<u128>>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigInt<T> {
+    value: T,
+}
+
+impl<T> BigInt<T> {
+    /// Construct a new `BigInt` wrapping the given integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::BigInt;
+    ///
+    /// let value = BigInt::new(u64::MAX);
+    /// assert_eq!(value.into_inner(), u64::MAX);
+    /// ```
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self { value }
+    }
+
+    /// Get a reference to the wrapped integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::BigInt;
+    ///
+    /// let value = BigInt::new(i128::MIN);
+    /// assert_eq!(*value.get(), i128::MIN);
+    /// ```
+    #[inline]
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Coerce into the wrapped integer.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> From<T> for BigInt<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A stack buffer used to format integers.
+pub(crate) struct Buf {
+    data: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Buf {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            data: [0; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_str(&self) -> &str {
+        // SAFETY: Only string slices are written to the buffer.
+        unsafe { str::from_utf8_unchecked(&self.data[..self.len]) }
+    }
+}
+
+impl Write for Buf {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let Some(data) = self.data.get_mut(self.len..self.len + s.len()) else {
+            return Err(fmt::Error);
+        };
+
+        data.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+#[inline]
+fn mismatch(actual: ValueType) -> Error {
+    Error::new(
+        Code::MISMATCH,
+        format_args!("expected column type TEXT or INTEGER but found {actual}"),
+    )
+}
+
+macro_rules! big_int {
+    ($ty:ty) => {
+        #[doc = concat!("[`BindValue`] implementation for [`BigInt<", stringify!($ty), ">`].")]
+        ///
+        /// The integer is bound as decimal text.
+        impl BindValue for BigInt<$ty> {
+            #[inline]
+            fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+                let mut buf = Buf::new();
+
+                if write!(buf, "{}", self.value).is_err() {
+                    return Err(Error::new(
+                        Code::TOOBIG,
+                        format_args!("integer {} is too large to format", self.value),
+                    ));
+                }
+
+                buf.as_str().bind_value(stmt, index)
+            }
+        }
+
+        #[doc = concat!("[`Bind`] implementation for [`BigInt<", stringify!($ty), ">`].")]
+        impl Bind for BigInt<$ty> {
+            #[inline]
+            fn bind(&self, stmt: &mut Statement) -> Result<()> {
+                self.bind_value(stmt, BIND_INDEX)
+            }
+        }
+
+        #[doc = concat!("[`FromColumn`] implementation for [`BigInt<", stringify!($ty), ">`].")]
+        ///
+        /// The value is parsed from decimal text, or converted from an integer.
+        impl FromColumn<'_> for BigInt<$ty> {
+            type Type = ty::Any;
+
+            #[inline]
+            fn from_column(stmt: &Statement, index: ty::Any) -> Result<Self> {
+                let value = match index.into_kind() {
+                    AnyKind::Text(index) => {
+                        let text = str::from_unsized_column(stmt, index)?;
+
+                        let Ok(value) = text.parse::<$ty>() else {
+                            return Err(Error::new(
+                                Code::MISMATCH,
+                                format_args!(
+                                    concat!("text {:?} cannot be parsed as ", stringify!($ty)),
+                                    text
+                                ),
+                            ));
+                        };
+
+                        value
+                    }
+                    AnyKind::Integer(index) => {
+                        let value = i64::from_column(stmt, index)?;
+
+                        <$ty>::try_from(value).map_err(|_| {
+                            Error::new(
+                                Code::MISMATCH,
+                                format_args!(
+                                    concat!("integer {} cannot be converted to ", stringify!($ty)),
+                                    value
+                                ),
+                            )
+                        })?
+                    }
+                    AnyKind::Float(..) => return Err(mismatch(ValueType::FLOAT)),
+                    AnyKind::Blob(..) => return Err(mismatch(ValueType::BLOB)),
+                };
+
+                Ok(Self::new(value))
+            }
+        }
+    };
+}
+
+big_int!(i64);
+big_int!(u64);
+big_int!(i128);
+big_int!(u128);
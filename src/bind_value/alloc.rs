@@ -1,7 +1,10 @@
 use core::ffi::c_int;
 
 use alloc::boxed::Box;
+use alloc::rc::Rc;
 use alloc::string::String;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use crate::{BIND_INDEX, Bind, Result, Statement};
@@ -251,3 +254,259 @@ where
         self.bind_value(stmt, BIND_INDEX)
     }
 }
+
+/// [`BindValue`] implementation for an atomically reference-counted value.
+///
+/// # Examples
+///
+/// Using a shared byte slice:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (id INTEGER, data BLOB);
+///
+///     INSERT INTO files (id, data) VALUES (0, X'48656C6C6F20576F726C6421');
+///     INSERT INTO files (id, data) VALUES (1, X'48656C6C6F');
+///     INSERT INTO files (id, data) VALUES (2, X'');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM files WHERE data = ?")?;
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Arc::<[u8]>::from([b'H', b'e', b'l', b'l', b'o']))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(1)]);
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Arc::<[u8]>::from([]))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(2)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Using a shared string:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Arc::<str>::from("Alice"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+impl<T> BindValue for Arc<T>
+where
+    T: ?Sized + BindValue,
+{
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        self.as_ref().bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for an atomically reference-counted value.
+///
+/// # Examples
+///
+/// Using a shared byte slice:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (id INTEGER, data BLOB);
+///
+///     INSERT INTO files (id, data) VALUES (0, X'48656C6C6F20576F726C6421');
+///     INSERT INTO files (id, data) VALUES (1, X'48656C6C6F');
+///     INSERT INTO files (id, data) VALUES (2, X'');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM files WHERE data = ?")?;
+///
+/// stmt.bind(Arc::<[u8]>::from([b'H', b'e', b'l', b'l', b'o']))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(1)]);
+///
+/// stmt.bind(Arc::<[u8]>::from([]))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(2)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Using a shared string:
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+///
+/// stmt.bind(Arc::<str>::from("Alice"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Bind for Arc<T>
+where
+    T: ?Sized + BindValue,
+{
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
+
+/// [`BindValue`] implementation for a reference-counted value.
+///
+/// # Examples
+///
+/// Using a shared byte slice:
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (id INTEGER, data BLOB);
+///
+///     INSERT INTO files (id, data) VALUES (0, X'48656C6C6F20576F726C6421');
+///     INSERT INTO files (id, data) VALUES (1, X'48656C6C6F');
+///     INSERT INTO files (id, data) VALUES (2, X'');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM files WHERE data = ?")?;
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Rc::<[u8]>::from([b'H', b'e', b'l', b'l', b'o']))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(1)]);
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Rc::<[u8]>::from([]))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(2)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Using a shared string:
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, Rc::<str>::from("Alice"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<T> BindValue for Rc<T>
+where
+    T: ?Sized + BindValue,
+{
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        self.as_ref().bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for a reference-counted value.
+///
+/// # Examples
+///
+/// Using a shared byte slice:
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (id INTEGER, data BLOB);
+///
+///     INSERT INTO files (id, data) VALUES (0, X'48656C6C6F20576F726C6421');
+///     INSERT INTO files (id, data) VALUES (1, X'48656C6C6F');
+///     INSERT INTO files (id, data) VALUES (2, X'');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM files WHERE data = ?")?;
+///
+/// stmt.bind(Rc::<[u8]>::from([b'H', b'e', b'l', b'l', b'o']))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(1)]);
+///
+/// stmt.bind(Rc::<[u8]>::from([]))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(2)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Using a shared string:
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+///
+/// stmt.bind(Rc::<str>::from("Alice"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<T> Bind for Rc<T>
+where
+    T: ?Sized + BindValue,
+{
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
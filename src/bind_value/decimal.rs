@@ -0,0 +1,76 @@
+use core::ffi::c_int;
+use core::fmt::Write;
+
+use rust_decimal::Decimal;
+
+use crate::big_int::Buf;
+use crate::{BIND_INDEX, Bind, Code, Error, Result, Statement};
+
+use super::BindValue;
+
+/// [`BindValue`] implementation for [`rust_decimal::Decimal`].
+///
+/// The decimal is bound as text, since binding it as a float would lose
+/// precision. The scale of the decimal is preserved, so `1.50` is bound as the
+/// text `'1.50'`.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT typeof(?), ?1")?;
+/// stmt.bind_value(BIND_INDEX, Decimal::new(150, 2))?;
+///
+/// assert_eq!(stmt.next::<(String, String)>()?, Some((String::from("text"), String::from("1.50"))));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for Decimal {
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        let mut buf = Buf::new();
+
+        if write!(buf, "{self}").is_err() {
+            return Err(Error::new(
+                Code::TOOBIG,
+                format_args!("decimal {self} is too large to format"),
+            ));
+        }
+
+        buf.as_str().bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for [`rust_decimal::Decimal`].
+///
+/// The value is bound to the first parameter as described in the
+/// [`BindValue`] implementation.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE prices (amount TEXT);
+/// "#)?;
+///
+/// let mut insert = c.prepare("INSERT INTO prices (amount) VALUES (?)")?;
+/// insert.execute(Decimal::new(1999, 2))?;
+///
+/// let mut stmt = c.prepare("SELECT amount FROM prices")?;
+/// assert_eq!(stmt.next::<Decimal>()?, Some(Decimal::new(1999, 2)));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for Decimal {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
@@ -1,5 +1,11 @@
 #[cfg(feature = "alloc")]
 mod alloc;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "std")]
+mod std;
 
 use core::ffi::c_int;
 
@@ -96,13 +102,38 @@ where
 impl BindValue for Null {
     #[inline]
     fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
-        unsafe {
-            sqlite3_try! {
-                stmt, ffi::sqlite3_bind_null(stmt.as_ptr_mut(), index)
-            };
-        }
+        stmt.bind_null(index)
+    }
+}
 
-        Ok(())
+/// [`BindValue`] implementation for `()`, which binds `NULL` like [`Null`].
+///
+/// This allows `()` to be used as a `NULL` placeholder in tuples. Note that
+/// `()` on its own when used through [`Bind`] binds no parameters at all.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+/// "#)?;
+///
+/// let mut insert = c.prepare("INSERT INTO users (name, age) VALUES (?, ?)")?;
+/// insert.execute(("Alice", ()))?;
+/// insert.execute(("Bob", 30))?;
+///
+/// let mut stmt = c.prepare("SELECT name FROM users WHERE age IS NULL")?;
+/// assert_eq!(stmt.iter::<String>().collect::<Vec<_>>(), [Ok(String::from("Alice"))]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for () {
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        Null.bind_value(stmt, index)
     }
 }
 
@@ -198,6 +229,45 @@ impl Bind for Value<'_> {
     }
 }
 
+/// [`Bind`] implementation for a slice of dynamic [`Value`]s.
+///
+/// The values are bound one after another starting from the first index. This
+/// is useful when the parameters of a query are built up dynamically.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, Value};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let params = [Value::text("Alice"), Value::integer(42)];
+///
+/// let mut stmt = c.prepare("SELECT COUNT(*) FROM users WHERE name = ? AND age = ?")?;
+/// stmt.bind(&params[..])?;
+/// assert_eq!(stmt.next::<i64>()?, Some(1));
+///
+/// let e = stmt.bind(&[Value::integer(1), Value::integer(2), Value::integer(3)][..]).unwrap_err();
+/// assert_eq!(e.code(), Code::RANGE);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for [Value<'_>] {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        for (index, value) in (BIND_INDEX..).zip(self) {
+            value.bind_value(stmt, index)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// [`BindValue`] implementation for byte slices.
 ///
 /// # Examples
@@ -0,0 +1,95 @@
+use core::ffi::c_int;
+
+use serde_json::Value;
+
+use crate::{BIND_INDEX, Bind, Code, Error, Null, Result, Statement};
+
+use super::BindValue;
+
+/// [`BindValue`] implementation for a dynamic [`serde_json::Value`].
+///
+/// Scalars are bound as the corresponding SQLite value:
+/// * `null` is bound as `NULL`.
+/// * Booleans are bound as the integers `0` or `1`, which is how SQLite
+///   represents them.
+/// * Numbers are bound as integers if they fit in an `i64`, otherwise as
+///   floats.
+/// * Strings are bound as text.
+///
+/// Arrays and objects are serialized and bound as JSON text, which can be
+/// processed using the built-in [JSON functions] of SQLite.
+///
+/// [JSON functions]: https://sqlite.org/json1.html
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT typeof(?), ?2 ->> '$.name'")?;
+///
+/// stmt.bind_value(BIND_INDEX, json!(42))?;
+/// stmt.bind_value(BIND_INDEX + 1, json!({"name": "Alice"}))?;
+///
+/// assert_eq!(stmt.next::<(String, String)>()?, Some((String::from("integer"), String::from("Alice"))));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for Value {
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        match self {
+            Value::Null => Null.bind_value(stmt, index),
+            Value::Bool(value) => value.bind_value(stmt, index),
+            Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    value.bind_value(stmt, index)
+                } else if let Some(value) = number.as_f64() {
+                    value.bind_value(stmt, index)
+                } else {
+                    Err(Error::new(
+                        Code::RANGE,
+                        format_args!("number {number} cannot be represented"),
+                    ))
+                }
+            }
+            Value::String(value) => value.as_str().bind_value(stmt, index),
+            Value::Array(..) | Value::Object(..) => {
+                let json = serde_json::to_string(self).map_err(|e| Error::new(Code::ERROR, e))?;
+                json.as_str().bind_value(stmt, index)
+            }
+        }
+    }
+}
+
+/// [`Bind`] implementation for a dynamic [`serde_json::Value`].
+///
+/// The value is bound to the first parameter as described in the
+/// [`BindValue`] implementation.
+///
+/// # Examples
+///
+/// ```
+/// use serde_json::json;
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE documents (body TEXT);
+/// "#)?;
+///
+/// let mut insert = c.prepare("INSERT INTO documents (body) VALUES (?)")?;
+/// insert.execute(json!({"tags": ["a", "b"]}))?;
+///
+/// let mut stmt = c.prepare("SELECT json_array_length(body, '$.tags') FROM documents")?;
+/// assert_eq!(stmt.next::<i64>()?, Some(2));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for Value {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
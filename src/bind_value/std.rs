@@ -0,0 +1,143 @@
+use core::ffi::c_int;
+
+use std::path::{Path, PathBuf};
+
+use crate::{BIND_INDEX, Bind, Code, Error, Result, Statement};
+
+use super::BindValue;
+
+/// [`BindValue`] implementation for a [`Path`].
+///
+/// The path is stored as text, which requires it to be valid UTF-8.
+///
+/// # Errors
+///
+/// Errors with [`Code::MISUSE`] if the path is not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (path TEXT, size INTEGER);
+///
+///     INSERT INTO files (path, size) VALUES ('src/lib.rs', 42), ('README.md', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT size FROM files WHERE path = ?")?;
+///
+/// stmt.bind_value(BIND_INDEX, Path::new("src/lib.rs"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for Path {
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        let Some(path) = self.to_str() else {
+            return Err(Error::new(Code::MISUSE, "path is not valid utf-8"));
+        };
+
+        path.bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for a [`Path`].
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (path TEXT, size INTEGER);
+///
+///     INSERT INTO files (path, size) VALUES ('src/lib.rs', 42), ('README.md', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT size FROM files WHERE path = ?")?;
+///
+/// stmt.bind(Path::new("README.md"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(30)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for Path {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
+
+/// [`BindValue`] implementation for a [`PathBuf`].
+///
+/// The path is stored as text, which requires it to be valid UTF-8.
+///
+/// # Errors
+///
+/// Errors with [`Code::MISUSE`] if the path is not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use sqll::{Connection, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (path TEXT, size INTEGER);
+///
+///     INSERT INTO files (path, size) VALUES ('src/lib.rs', 42), ('README.md', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT size FROM files WHERE path = ?")?;
+///
+/// stmt.bind_value(BIND_INDEX, PathBuf::from("src/lib.rs"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for PathBuf {
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        self.as_path().bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for a [`PathBuf`].
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (path TEXT, size INTEGER);
+///
+///     INSERT INTO files (path, size) VALUES ('src/lib.rs', 42), ('README.md', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT size FROM files WHERE path = ?")?;
+///
+/// stmt.bind(PathBuf::from("README.md"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(30)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for PathBuf {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
@@ -0,0 +1,96 @@
+use core::fmt::{self, Write};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// Displays a blob as a lowercase hexadecimal string.
+///
+/// See [`Value::blob_to_hex`].
+///
+/// [`Value::blob_to_hex`]: crate::Value::blob_to_hex
+///
+/// # Examples
+///
+/// ```
+/// use sqll::BlobHex;
+///
+/// assert_eq!(BlobHex::new(&[0xDE, 0xAD, 0xBE, 0xEF]).to_string(), "deadbeef");
+/// assert_eq!(BlobHex::new(&[]).to_string(), "");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BlobHex<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BlobHex<'a> {
+    /// Construct a new hexadecimal display of the given bytes.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl fmt::Display for BlobHex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &b in self.bytes {
+            f.write_char(HEX[(b >> 4) as usize] as char)?;
+            f.write_char(HEX[(b & 0xf) as usize] as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays a blob as a [base64] string using the standard alphabet with
+/// padding.
+///
+/// See [`Value::blob_to_base64`].
+///
+/// [`Value::blob_to_base64`]: crate::Value::blob_to_base64
+/// [base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+///
+/// # Examples
+///
+/// ```
+/// use sqll::BlobBase64;
+///
+/// assert_eq!(BlobBase64::new(b"Hello").to_string(), "SGVsbG8=");
+/// assert_eq!(BlobBase64::new(&[]).to_string(), "");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct BlobBase64<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BlobBase64<'a> {
+    /// Construct a new base64 display of the given bytes.
+    #[inline]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl fmt::Display for BlobBase64<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.bytes.chunks(3) {
+            let b = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or_default(),
+                chunk.get(2).copied().unwrap_or_default(),
+            ];
+
+            let n = u32::from_be_bytes([0, b[0], b[1], b[2]]);
+
+            for i in 0..4 {
+                if i > chunk.len() {
+                    f.write_char('=')?;
+                } else {
+                    let index = (n >> (18 - i * 6)) & 0x3f;
+                    f.write_char(BASE64[index as usize] as char)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+//! Support for busy handlers which abort with a custom error.
+//!
+//! The busy handler is called synchronously on the thread performing the
+//! blocked operation, but sqlite only allows it to signal that the operation
+//! should fail with `SQLITE_BUSY`. To surface a custom error we stash it in a
+//! thread-local slot tagged with the database it belongs to, which is picked up
+//! when the blocked operation reports the failure.
+
+use core::cell::Cell;
+use core::ffi::c_int;
+
+use std::thread_local;
+
+use crate::ffi;
+use crate::{Code, Error};
+
+thread_local! {
+    static ABORTED: Cell<Option<(usize, Error)>> = const { Cell::new(None) };
+}
+
+/// Store an error which aborted a busy handler for the given database.
+pub(crate) fn set_aborted(db: *mut ffi::sqlite3, error: Error) {
+    ABORTED.with(|slot| slot.set(Some((db as usize, error))));
+}
+
+/// Clear any stale error stored for the given database.
+pub(crate) fn clear_aborted(db: *mut ffi::sqlite3) {
+    ABORTED.with(|slot| {
+        if let Some((existing, error)) = slot.take()
+            && existing != db as usize
+        {
+            slot.set(Some((existing, error)));
+        }
+    });
+}
+
+/// Take the error which aborted a busy handler for the given database if the
+/// code indicates that the operation failed because it was busy.
+pub(crate) fn take_aborted(db: *mut ffi::sqlite3, code: c_int) -> Option<Error> {
+    if Code::new(code).base() != Code::BUSY {
+        return None;
+    }
+
+    ABORTED.with(|slot| match slot.take() {
+        Some((existing, error)) if existing == db as usize => Some(error),
+        other => {
+            slot.set(other);
+            None
+        }
+    })
+}
@@ -0,0 +1,77 @@
+/// Statistics about the page cache of a connection.
+///
+/// See [`Connection::cache_stats`].
+///
+/// [`Connection::cache_stats`]: crate::Connection::cache_stats
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT);
+///
+///     INSERT INTO users VALUES ('Alice'), ('Bob');
+/// "#)?;
+///
+/// let stats = c.cache_stats(false)?;
+/// assert!(stats.used() > 0);
+/// assert!(stats.hit() > 0);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct CacheStats {
+    used: i64,
+    hit: i64,
+    miss: i64,
+    write: i64,
+}
+
+impl CacheStats {
+    #[inline]
+    pub(crate) const fn new(used: i64, hit: i64, miss: i64, write: i64) -> Self {
+        Self {
+            used,
+            hit,
+            miss,
+            write,
+        }
+    }
+
+    /// The approximate number of bytes of heap memory used by all pager caches
+    /// associated with the connection.
+    ///
+    /// This corresponds to `SQLITE_DBSTATUS_CACHE_USED`.
+    #[inline]
+    pub const fn used(&self) -> i64 {
+        self.used
+    }
+
+    /// The number of pager cache hits.
+    ///
+    /// This corresponds to `SQLITE_DBSTATUS_CACHE_HIT`.
+    #[inline]
+    pub const fn hit(&self) -> i64 {
+        self.hit
+    }
+
+    /// The number of pager cache misses.
+    ///
+    /// This corresponds to `SQLITE_DBSTATUS_CACHE_MISS`.
+    #[inline]
+    pub const fn miss(&self) -> i64 {
+        self.miss
+    }
+
+    /// The number of dirty cache entries which have been written to disk.
+    ///
+    /// This corresponds to `SQLITE_DBSTATUS_CACHE_WRITE`.
+    #[inline]
+    pub const fn write(&self) -> i64 {
+        self.write
+    }
+}
@@ -1,11 +1,8 @@
 use core::ffi::c_int;
 use core::fmt;
 
-#[cfg(not(feature = "alloc"))]
 use crate::Text;
-#[cfg(not(feature = "alloc"))]
 use crate::ffi;
-#[cfg(not(feature = "alloc"))]
 use crate::utils::c_to_error_text;
 
 /// Error code.
@@ -43,8 +40,42 @@ impl Code {
         Self::new(self.raw & 0xff)
     }
 
+    /// Construct a code from its raw numeric value.
+    ///
+    /// This is useful when interoperating with other sqlite libraries. Any
+    /// value is accepted, including ones which do not correspond to a known
+    /// code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Code;
+    ///
+    /// assert_eq!(Code::from_raw(19), Code::CONSTRAINT);
+    /// assert_eq!(Code::from_raw(2067), Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(Code::from_raw(Code::BUSY.as_i32()), Code::BUSY);
+    /// ```
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self::new(raw)
+    }
+
+    /// Get the raw numeric value of the code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Code;
+    ///
+    /// assert_eq!(Code::CONSTRAINT.as_i32(), 19);
+    /// assert_eq!(Code::CONSTRAINT_UNIQUE.as_i32(), 2067);
+    /// ```
+    #[inline]
+    pub const fn as_i32(self) -> i32 {
+        self.raw
+    }
+
     /// Get the error message associated with this code.
-    #[cfg(not(feature = "alloc"))]
     pub(crate) fn message(&self) -> &Text {
         unsafe { c_to_error_text(ffi::sqlite3_errstr(self.raw)) }
     }
@@ -60,14 +91,53 @@ macro_rules! codes {
                 $(#[doc = $doc])*
                 $vis const $name: Code = Code::new($value);
             )*
+
+            /// All known codes, in the order they are defined.
+            const ALL: &[Code] = &[$(Code::$name),*];
+
+            /// Iterate over all known codes.
+            ///
+            /// This includes both primary and extended result codes, in the
+            /// order they are defined.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use sqll::Code;
+            ///
+            /// assert!(Code::all().any(|code| code == Code::CONSTRAINT_UNIQUE));
+            ///
+            /// let extended = Code::all().filter(|code| code.base() == Code::IOERR).count();
+            /// assert!(extended > 1);
+            /// ```
+            pub fn all() -> impl ExactSizeIterator<Item = Code> + Clone {
+                Self::ALL.iter().copied()
+            }
+
+            /// Get the name of the code, or `None` if it is not a known code.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use sqll::Code;
+            ///
+            /// assert_eq!(Code::CONSTRAINT_UNIQUE.name(), Some("CONSTRAINT_UNIQUE"));
+            /// assert_eq!(Code::from_raw(0xfff).name(), None);
+            /// ```
+            pub const fn name(self) -> Option<&'static str> {
+                match self {
+                    $(Code::$name => Some(stringify!($name)),)*
+                    _ => None,
+                }
+            }
         }
 
         impl fmt::Display for Code {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                match *self {
-                    $(Code::$name => write!(f, stringify!($name)),)*
-                    Code { raw } => write!(f, "UNKNOWN({raw})"),
+                match self.name() {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "UNKNOWN({})", self.raw),
                 }
             }
         }
@@ -75,10 +145,7 @@ macro_rules! codes {
         impl fmt::Debug for Code {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                match *self {
-                    $(Code::$name => write!(f, stringify!($name)),)*
-                    Code { raw } => write!(f, "UNKNOWN({raw})"),
-                }
+                fmt::Display::fmt(self, f)
             }
         }
     };
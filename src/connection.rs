@@ -1,20 +1,66 @@
 use core::ffi::CStr;
 #[cfg(feature = "alloc")]
-use core::ffi::c_void;
+use core::ffi::{c_char, c_void};
 use core::ffi::{c_int, c_uint};
 use core::fmt;
 use core::mem::MaybeUninit;
 use core::ops::{BitOr, Deref, DerefMut};
-use core::ptr::{NonNull, null_mut};
+use core::ptr::{self, NonNull, null_mut};
+#[cfg(feature = "std")]
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::path::Path;
+#[cfg(feature = "std")]
+use std::thread;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
+#[cfg(feature = "alloc")]
+use crate::authorizer::{AuthAction, AuthResult};
+#[cfg(feature = "std")]
+use crate::busy;
 use crate::ffi;
 #[cfg(feature = "alloc")]
+use crate::function::{self, Arguments, Arity, FunctionFlags, ReturnValue, WindowAggregate};
+#[cfg(feature = "alloc")]
 use crate::owned::Owned;
-use crate::utils::{c_to_error_text, sqlite3_try};
-use crate::{Code, DatabaseNotFound, Error, NotThreadSafe, OpenOptions, Result, Statement, Text};
+use crate::pragma_snapshot::JOURNAL_MODES;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use crate::statement_cache::StatementCache;
+use crate::utils::{c_to_error_text, is_insignificant_sql, is_insignificant_sql16, sqlite3_try};
+#[cfg(feature = "alloc")]
+use crate::virtual_table::{self, VirtualTable};
+use crate::{
+    Bind, CacheStats, Code, DatabaseNotFound, DeserializeOptions, Error, InsertResult,
+    NotThreadSafe, OpenOptions, Parameters, PragmaSnapshot, Result, Sql, Statement, Text, TxnState,
+};
+
+/// Construct a [`Savepoint`] with the given name.
+macro_rules! savepoint {
+    ($name:literal) => {
+        Savepoint {
+            begin: concat!("SAVEPOINT ", $name).as_bytes(),
+            rollback: concat!("ROLLBACK TO ", $name).as_bytes(),
+            release: concat!("RELEASE ", $name).as_bytes(),
+        }
+    };
+}
+
+/// The statements used to manage a named savepoint, see
+/// [`Connection::with_savepoint`].
+struct Savepoint {
+    begin: &'static [u8],
+    rollback: &'static [u8],
+    release: &'static [u8],
+}
 
 /// A collection of flags use to prepare a statement.
 pub struct Prepare(c_uint);
@@ -92,11 +138,16 @@ pub struct Connection {
     raw: NonNull<ffi::sqlite3>,
     #[cfg(feature = "alloc")]
     busy_callback: Option<Owned>,
+    #[cfg(feature = "alloc")]
+    authorizer_callback: Option<Owned>,
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    statement_cache: Option<Arc<StatementCache>>,
+    busy_timeout: c_int,
     is_thread_safe: bool,
 }
 
 /// Connection is `Send`.
-#[cfg(feature = "threadsafe")]
+#[cfg(any(feature = "threadsafe-serialized", feature = "threadsafe-multi"))]
 unsafe impl Send for Connection {}
 
 impl Connection {
@@ -107,6 +158,11 @@ impl Connection {
             raw,
             #[cfg(feature = "alloc")]
             busy_callback: None,
+            #[cfg(feature = "alloc")]
+            authorizer_callback: None,
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            statement_cache: None,
+            busy_timeout: 0,
             is_thread_safe,
         }
     }
@@ -261,6 +317,9 @@ impl Connection {
     /// allocating for regular paths, you can use [`open_c_str`], however you
     /// are responsible for ensuring the c-string is a valid path.
     ///
+    /// This requires the `std` feature. Without it, databases can be opened
+    /// through [`open_c_str`], [`open_in_memory`], or [`open_temporary`].
+    ///
     /// This is the same as calling:
     ///
     /// ```
@@ -278,6 +337,7 @@ impl Connection {
     ///
     /// [`open_in_memory`]: Self::open_in_memory
     /// [`open_c_str`]: Self::open_c_str
+    /// [`open_temporary`]: Self::open_temporary
     #[cfg(feature = "std")]
     #[cfg_attr(docsrs, cfg(feature = "std"))]
     #[inline]
@@ -320,8 +380,6 @@ impl Connection {
 
     /// Open an in-memory database.
     ///
-    /// This is the same as calling
-    ///
     /// This is the same as calling:
     ///
     /// ```
@@ -344,6 +402,83 @@ impl Connection {
             .open_in_memory()
     }
 
+    /// Open a private temporary on-disk database.
+    ///
+    /// The database is deleted once the connection is closed. See
+    /// [`OpenOptions::open_temporary`] for how this differs from
+    /// [`Connection::open_in_memory`].
+    ///
+    /// This is the same as calling:
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let c = OpenOptions::new()
+    ///     .extended_result_codes()
+    ///     .read_write()
+    ///     .create()
+    ///     .open_temporary()?;
+    ///
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn open_temporary() -> Result<Connection> {
+        OpenOptions::new()
+            .extended_result_codes()
+            .read_write()
+            .create()
+            .open_temporary()
+    }
+
+    /// Open a named in-memory database which can be shared between
+    /// connections.
+    ///
+    /// Every connection opened with the same name in the same process sees
+    /// the same database, which lives for as long as at least one connection
+    /// to it is open.
+    ///
+    /// This is the same as calling:
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    /// # let name = c"connection_shared";
+    ///
+    /// let c = OpenOptions::new()
+    ///     .extended_result_codes()
+    ///     .read_write()
+    ///     .create()
+    ///     .open_in_memory_named(name)?;
+    ///
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// See [`OpenOptions::open_in_memory_named`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let a = Connection::open_in_memory_named(c"connection_shared")?;
+    /// let b = Connection::open_in_memory_named(c"connection_shared")?;
+    ///
+    /// a.execute("CREATE TABLE users (name TEXT); INSERT INTO users VALUES ('Alice')")?;
+    ///
+    /// let mut stmt = b.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    #[inline]
+    pub fn open_in_memory_named(name: &CStr) -> Result<Connection> {
+        OpenOptions::new()
+            .extended_result_codes()
+            .read_write()
+            .create()
+            .open_in_memory_named(name)
+    }
+
     /// Check if the database connection is read-only.
     ///
     /// # Examples
@@ -374,338 +509,472 @@ impl Connection {
         }
     }
 
-    /// Execute a batch of statements.
-    ///
-    /// Unlike [`prepare`], this can be used to execute multiple statements
-    /// separated by a semi-colon `;` and is internally optimized for one-off
-    /// queries.
-    ///
-    /// [`prepare`]: Self::prepare
+    /// Deserialize `data` into the database with the given schema name, such
+    /// as `c"main"`, discarding its existing content.
     ///
-    /// # Errors
+    /// The data is expected to be the content of a database file, and the
+    /// database is read-only once deserialized. To use other options, see
+    /// [`DeserializeOptions`].
     ///
-    /// If any of the statements fail, an error is returned.
+    /// # Examples
     ///
     /// ```
     /// use sqll::{Code, Connection};
     ///
-    /// let c = Connection::open_in_memory()?;
-    ///
-    /// let e = c.execute(":)").unwrap_err();
-    /// assert_eq!(e.code(), Code::ERROR);
-    /// # Ok::<_, sqll::Error>(())
-    /// ```
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use sqll::{Connection, Result};
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("data.db");
     ///
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
-    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     CREATE TABLE users (name TEXT);
     ///
-    ///     INSERT INTO users VALUES ('Alice', 42);
-    ///     INSERT INTO users VALUES ('Bob', 72);
+    ///     INSERT INTO users VALUES ('Alice');
     /// "#)?;
     ///
-    /// let results = c.prepare("SELECT name, age FROM users")?
-    ///     .iter::<(String, u32)>()
-    ///     .collect::<Result<Vec<_>>>()?;
+    /// c.execute(format!("VACUUM INTO '{}'", path.display()))?;
+    /// let data = std::fs::read(&path)?;
     ///
-    /// assert_eq!(results, [("Alice".to_string(), 42), ("Bob".to_string(), 72)]);
-    /// # Ok::<_, sqll::Error>(())
+    /// let c = Connection::open_in_memory()?;
+    /// c.deserialize(c"main", &data)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    ///
+    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
+    /// assert_eq!(e.code(), Code::READONLY);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     #[inline]
-    pub fn execute(&self, stmt: impl AsRef<str>) -> Result<()> {
-        self._execute(stmt.as_ref())
+    pub fn deserialize(&self, schema: &CStr, data: &[u8]) -> Result<()> {
+        DeserializeOptions::new().deserialize(self, schema, data)
     }
 
-    fn _execute(&self, stmt: &str) -> Result<()> {
+    pub(crate) fn deserialize_with(&self, schema: &CStr, data: &[u8], flags: c_uint) -> Result<()> {
+        let (Ok(len), Ok(size)) = (i64::try_from(data.len()), u64::try_from(data.len())) else {
+            return Err(Error::new(
+                Code::TOOBIG,
+                format_args!("database size {} is too large", data.len()),
+            ));
+        };
+
         unsafe {
-            let mut ptr = stmt.as_ptr().cast();
-            let mut len = stmt.len();
-
-            while len > 0 {
-                let mut raw = MaybeUninit::uninit();
-                let mut rest = MaybeUninit::uninit();
-
-                let l = i32::try_from(len).unwrap_or(i32::MAX);
-
-                sqlite3_try!(
-                    self,
-                    ffi::sqlite3_prepare_v3(
-                        self.raw.as_ptr(),
-                        ptr,
-                        l,
-                        0,
-                        raw.as_mut_ptr(),
-                        rest.as_mut_ptr(),
-                    )
-                );
-
-                let rest = rest.assume_init();
-
-                // If statement is null then it's simply empty, so we can safely
-                // skip it, otherwise iterate over all rows.
-                if let Some(raw) = NonNull::new(raw.assume_init()) {
-                    let mut statement = Statement::from_raw(raw, self.is_thread_safe);
-                    while statement.step()?.is_row() {}
-                }
+            // NB: Allocate at least one byte, since sqlite returns a null
+            // pointer for zero-sized allocations.
+            let ptr = ffi::sqlite3_malloc64(size.max(1)).cast::<u8>();
 
-                // Skip over empty statements.
-                let o = rest.offset_from_unsigned(ptr);
-                len -= o;
-                ptr = rest;
+            if ptr.is_null() {
+                return Err(Error::new(Code::NOMEM, "allocation failed"));
             }
 
-            Ok(())
+            ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+
+            // SAFETY: With FREEONCLOSE, sqlite takes ownership of the buffer,
+            // including freeing it if deserialization fails.
+            let flags = flags | ffi::SQLITE_DESERIALIZE_FREEONCLOSE as c_uint;
+
+            let code = ffi::sqlite3_deserialize(
+                self.raw.as_ptr(),
+                schema.as_ptr(),
+                ptr,
+                len,
+                len.max(1),
+                flags,
+            );
+
+            if code == ffi::SQLITE_OK {
+                return Ok(());
+            }
+
+            // NB: Some failures, like a missing schema, don't set an error
+            // message on the connection.
+            if ffi::sqlite3_errcode(self.raw.as_ptr()) != code {
+                return Err(Error::new(
+                    Code::new(code),
+                    format_args!(
+                        "failed to deserialize database {}",
+                        Text::new(schema.to_bytes())
+                    ),
+                ));
+            }
+
+            Err(self.error(code))
         }
     }
 
-    /// Enable or disable extended result codes.
+    /// Serialize the database with the given schema name, such as `c"main"`,
+    /// and write it to `writer`, returning the number of bytes written.
     ///
-    /// This can also be set during construction with
-    /// [`OpenOptions::extended_result_codes`].
+    /// The written data is the content of a database file, which can be
+    /// loaded again using [`deserialize`]. The serialized copy is freed as
+    /// soon as it has been written, so at most one copy of the database is
+    /// held in memory in addition to the database itself.
+    ///
+    /// [`deserialize`]: Self::deserialize
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is no database with the given schema name, if the
+    /// memory for the copy can't be allocated, or with [`Code::IOERR`] if
+    /// writing fails.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// let e = c.serialize_to(c"missing", Vec::new()).unwrap_err();
+    /// assert_eq!(e, Code::ERROR);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::{OpenOptions, Code};
+    /// use sqll::Connection;
     ///
-    /// let mut c = OpenOptions::new().create().read_write().open_in_memory()?;
+    /// let c = Connection::open_in_memory()?;
     ///
-    /// let e = c.execute("
+    /// c.execute(r#"
     ///     CREATE TABLE users (name TEXT);
-    ///     CREATE UNIQUE INDEX idx_users_name ON users (name);
     ///
-    ///     INSERT INTO users VALUES ('Bob');
-    /// ");
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
     ///
-    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
-    /// assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
-    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    /// let mut data = Vec::new();
+    /// let n = c.serialize_to(c"main", &mut data)?;
+    /// assert_eq!(n, data.len() as u64);
     ///
-    /// c.extended_result_codes(false)?;
-    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
-    /// assert_eq!(e.code(), Code::CONSTRAINT);
-    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    /// let c = Connection::open_in_memory()?;
+    /// c.deserialize(c"main", &data)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    pub fn extended_result_codes(&mut self, enabled: bool) -> Result<()> {
-        unsafe {
-            let onoff = i32::from(enabled);
-            sqlite3_try!(
-                self,
-                ffi::sqlite3_extended_result_codes(self.raw.as_ptr(), onoff)
-            );
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    pub fn serialize_to<W>(&self, schema: &CStr, mut writer: W) -> Result<u64>
+    where
+        W: std::io::Write,
+    {
+        /// Frees memory allocated by sqlite when dropped.
+        struct Guard(*mut u8);
+
+        impl Drop for Guard {
+            #[inline]
+            fn drop(&mut self) {
+                unsafe { ffi::sqlite3_free(self.0.cast()) };
+            }
         }
 
-        Ok(())
+        let mut size = 0;
+
+        let guard = unsafe {
+            Guard(ffi::sqlite3_serialize(
+                self.raw.as_ptr(),
+                schema.as_ptr(),
+                &mut size,
+                0,
+            ))
+        };
+
+        // NB: A negative size means that the database couldn't be found or
+        // read, while a null pointer for a non-empty database means that the
+        // copy couldn't be allocated.
+        let Ok(len) = usize::try_from(size) else {
+            return Err(Error::new(
+                Code::ERROR,
+                format_args!(
+                    "failed to serialize database {}",
+                    Text::new(schema.to_bytes())
+                ),
+            ));
+        };
+
+        if len == 0 {
+            return Ok(0);
+        }
+
+        if guard.0.is_null() {
+            return Err(Error::new(Code::NOMEM, "allocation failed"));
+        }
+
+        // SAFETY: sqlite allocated exactly `len` bytes for the copy.
+        let data = unsafe { slice::from_raw_parts(guard.0, len) };
+
+        match writer.write_all(data) {
+            Ok(()) => Ok(size as u64),
+            Err(e) => Err(Error::new(Code::IOERR, e)),
+        }
     }
 
-    /// Get the last error message for this connection.
+    /// Get the transaction state of a database.
     ///
-    /// When operating in multi-threaded environment, the error message seen
-    /// here might not correspond to the query that failed unless some kind of
-    /// external synchronization is in use which is the recommended way to use
-    /// sqlite.
+    /// If `schema` is `None`, the highest transaction state of any attached
+    /// database is returned. Otherwise it is the transaction state of the
+    /// database with the given schema name, such as `c"main"`.
     ///
-    /// This is only meaningful if an error has occured. If no errors have
-    /// occured, this returns a non-erronous message like `"not an error"`
-    /// (default for sqlite3).
+    /// Together with [`Connection::is_autocommit`] this can be used to
+    /// determine whether the current transaction holds a write lock.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::{Connection, Code};
+    /// use sqll::{Connection, DatabaseNotFound, TxnState};
     ///
     /// let c = Connection::open_in_memory()?;
     ///
-    /// let e = c.execute("
+    /// c.execute(r#"
     ///     CREATE TABLE users (name TEXT);
-    ///     CREATE UNIQUE INDEX idx_users_name ON users (name);
-    ///
-    ///     INSERT INTO users VALUES ('Bob');
-    /// ");
-    ///
-    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
-    /// assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
-    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
-    /// # Ok::<_, sqll::Error>(())
-    /// ```
-    pub fn error_message(&self) -> &Text {
-        unsafe { c_to_error_text(ffi::sqlite3_errmsg(self.raw.as_ptr())) }
-    }
-
-    /// Build a prepared statement.
     ///
-    /// This is the same as calling `prepare_with` with `Prepare::EMPTY`.
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
     ///
-    /// The database connection will be kept open for the lifetime of this
-    /// statement.
+    /// assert_eq!(c.transaction_state(None)?, TxnState::NONE);
+    /// assert!(c.is_autocommit());
     ///
-    /// # Errors
+    /// c.execute("BEGIN")?;
+    /// assert!(!c.is_autocommit());
+    /// // Transactions are deferred until the database is first accessed.
+    /// assert_eq!(c.transaction_state(None)?, TxnState::NONE);
     ///
-    /// If the prepare call contains multiple statements, it will error. To
-    /// execute multiple statements, use [`execute`] instead.
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert!(stmt.step()?.is_row());
+    /// drop(stmt);
     ///
-    /// ```
-    /// use sqll::{Connection, Code};
+    /// assert_eq!(c.transaction_state(None)?, TxnState::READ);
+    /// assert_eq!(c.transaction_state(Some(c"main"))?, TxnState::READ);
     ///
-    /// let c = Connection::open_in_memory()?;
+    /// c.execute("INSERT INTO users VALUES ('Bob')")?;
+    /// assert_eq!(c.transaction_state(Some(c"main"))?, TxnState::WRITE);
     ///
-    /// let e = c.prepare("CREATE TABLE test (id INTEGER) /* test */; INSERT INTO test (id) VALUES (1);").unwrap_err();
+    /// c.execute("COMMIT")?;
+    /// assert_eq!(c.transaction_state(None)?, TxnState::NONE);
     ///
-    /// assert_eq!(e.code(), Code::MISUSE);
-    /// # Ok::<_, sqll::Error>(())
+    /// let e = c.transaction_state(Some(c"not a db")).unwrap_err();
+    /// assert!(matches!(e, DatabaseNotFound { .. }));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
+    pub fn transaction_state(&self, schema: Option<&CStr>) -> Result<TxnState, DatabaseNotFound> {
+        let schema = schema.map_or(ptr::null(), CStr::as_ptr);
+
+        unsafe {
+            match ffi::sqlite3_txn_state(self.raw.as_ptr(), schema) {
+                -1 => Err(DatabaseNotFound),
+                raw => Ok(TxnState::new(raw)),
+            }
+        }
+    }
+
+    /// Test if the connection is in autocommit mode.
     ///
-    /// [`execute`]: Self::execute
+    /// Autocommit mode is on by default, and is disabled by a `BEGIN`
+    /// statement. It is re-enabled when the transaction is committed or rolled
+    /// back.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::{Connection, Prepare};
+    /// use sqll::Connection;
     ///
     /// let c = Connection::open_in_memory()?;
+    /// assert!(c.is_autocommit());
     ///
-    /// c.execute(r#"
-    ///     CREATE TABLE test (id INTEGER);
-    /// "#)?;
-    ///
-    /// let mut insert_stmt = c.prepare("INSERT INTO test (id) VALUES (?);")?;
-    /// let mut query_stmt = c.prepare("SELECT id FROM test;")?;
-    ///
-    /// drop(c);
-    ///
-    /// insert_stmt.execute(42)?;
+    /// c.execute("BEGIN")?;
+    /// assert!(!c.is_autocommit());
     ///
-    /// query_stmt.bind(())?;
-    /// assert_eq!(query_stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+    /// c.execute("ROLLBACK")?;
+    /// assert!(c.is_autocommit());
     /// # Ok::<_, sqll::Error>(())
     /// ```
     #[inline]
-    pub fn prepare(&self, stmt: impl AsRef<str>) -> Result<Statement> {
-        self.prepare_with(stmt, Prepare::EMPTY)
+    pub fn is_autocommit(&self) -> bool {
+        unsafe { ffi::sqlite3_get_autocommit(self.raw.as_ptr()) != 0 }
     }
 
-    /// Build a prepared statement with custom flags.
+    /// Count the number of statements associated with the connection which
+    /// have not been finalized.
     ///
-    /// For long-running statements it is recommended that they have the
-    /// [`Prepare::PERSISTENT`] flag set.
+    /// A [`Statement`] is finalized when it's dropped. Outstanding statements
+    /// keep the underlying connection alive after the [`Connection`] has been
+    /// dropped, and a statement which is in the middle of being stepped can
+    /// cause operations such as `DROP TABLE` to fail with [`Code::LOCKED`].
+    /// This can be used to diagnose such situations.
     ///
-    /// The database connection will be kept open for the lifetime of this
-    /// statement.
+    /// Note that a statement retained by the [`single_cache`] counts as
+    /// outstanding.
     ///
-    /// # Errors
+    /// [`single_cache`]: Self::single_cache
     ///
-    /// If the prepare call contains multiple statements, it will error. To
-    /// execute multiple statements, use [`execute`] instead.
+    /// # Examples
     ///
     /// ```
-    /// use sqll::{Connection, Code, Prepare};
+    /// use sqll::Connection;
     ///
     /// let c = Connection::open_in_memory()?;
+    /// assert_eq!(c.statement_count(), 0);
     ///
-    /// let e = c.prepare_with("CREATE TABLE test (id INTEGER); INSERT INTO test (id) VALUES (1);", Prepare::PERSISTENT).unwrap_err();
-    /// assert_eq!(e.code(), Code::MISUSE);
-    /// # Ok::<_, sqll::Error>(())
+    /// let a = c.prepare("SELECT 1")?;
+    /// let b = c.prepare("SELECT 2")?;
+    /// assert_eq!(c.statement_count(), 2);
+    ///
+    /// drop(a);
+    /// assert_eq!(c.statement_count(), 1);
+    ///
+    /// drop(b);
+    /// assert_eq!(c.statement_count(), 0);
+    /// # Ok::<_, sqll::Error>(())
     /// ```
+    pub fn statement_count(&self) -> usize {
+        let mut count = 0;
+
+        unsafe {
+            let mut stmt = ffi::sqlite3_next_stmt(self.raw.as_ptr(), null_mut());
+
+            while !stmt.is_null() {
+                count += 1;
+                stmt = ffi::sqlite3_next_stmt(self.raw.as_ptr(), stmt);
+            }
+        }
+
+        count
+    }
+
+    /// Check that the connection is usable by running a trivial query.
     ///
-    /// [`execute`]: Self::execute
+    /// This is typically used by connection pools to validate a connection
+    /// before handing it out, so that broken connections can be discarded.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the query could not be prepared or executed.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::{Connection, Prepare};
+    /// use sqll::Connection;
     ///
     /// let c = Connection::open_in_memory()?;
+    /// c.ping()?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn ping(&self) -> Result<()> {
+        self._execute(b"SELECT 1")?;
+        Ok(())
+    }
+
+    /// Get statistics about the page cache of the connection.
     ///
-    /// c.execute(r#"
-    ///     CREATE TABLE test (id INTEGER);
-    /// "#)?;
+    /// The ratio of [`hit`] to [`miss`] is useful when tuning the size of the
+    /// cache through `PRAGMA cache_size`.
     ///
-    /// let mut insert_stmt = c.prepare_with("INSERT INTO test (id) VALUES (?)", Prepare::PERSISTENT)?;
-    /// let mut query_stmt = c.prepare_with("SELECT id FROM test", Prepare::PERSISTENT)?;
+    /// If `reset` is `true`, the hit, miss, and write counters are reset to
+    /// zero after they have been read.
     ///
-    /// drop(c);
+    /// See the [sqlite documentation] for more information.
     ///
-    /// /* .. */
+    /// [`hit`]: CacheStats::hit
+    /// [`miss`]: CacheStats::miss
+    /// [sqlite documentation]: https://www.sqlite.org/c3ref/c_dbstatus_options.html
     ///
-    /// insert_stmt.bind(42)?;
-    /// assert!(insert_stmt.step()?.is_done());
+    /// # Examples
     ///
-    /// query_stmt.bind(())?;
-    /// assert_eq!(query_stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice'), ('Bob');
+    /// "#)?;
+    ///
+    /// let before = c.cache_stats(true)?;
+    /// assert!(before.hit() > 0);
+    /// assert_eq!(c.cache_stats(false)?.hit(), 0);
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// while stmt.step()?.is_row() {}
+    ///
+    /// assert!(c.cache_stats(false)?.hit() > 0);
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    pub fn prepare_with(&self, stmt: impl AsRef<str>, flags: Prepare) -> Result<Statement> {
-        let stmt = stmt.as_ref();
+    pub fn cache_stats(&self, reset: bool) -> Result<CacheStats> {
+        let reset = c_int::from(reset);
 
-        unsafe {
-            let mut raw = MaybeUninit::uninit();
-            let mut rest = MaybeUninit::uninit();
+        let used = self.db_status(ffi::SQLITE_DBSTATUS_CACHE_USED, 0)?;
+        let hit = self.db_status(ffi::SQLITE_DBSTATUS_CACHE_HIT, reset)?;
+        let miss = self.db_status(ffi::SQLITE_DBSTATUS_CACHE_MISS, reset)?;
+        let write = self.db_status(ffi::SQLITE_DBSTATUS_CACHE_WRITE, reset)?;
+        Ok(CacheStats::new(used, hit, miss, write))
+    }
 
-            let ptr = stmt.as_ptr().cast();
-            let len = i32::try_from(stmt.len()).unwrap_or(i32::MAX);
+    /// Read the current value of the given `sqlite3_db_status` verb.
+    fn db_status(&self, op: c_int, reset: c_int) -> Result<i64> {
+        let mut current = 0;
+        let mut highwater = 0;
 
+        unsafe {
             sqlite3_try! {
                 self,
-                ffi::sqlite3_prepare_v3(
-                    self.raw.as_ptr(),
-                    ptr,
-                    len,
-                    flags.0,
-                    raw.as_mut_ptr(),
-                    rest.as_mut_ptr(),
-                )
+                ffi::sqlite3_db_status(self.raw.as_ptr(), op, &mut current, &mut highwater, reset)
             };
-
-            let rest = rest.assume_init();
-
-            let o = rest.offset_from_unsigned(ptr);
-
-            if o != stmt.len() {
-                return Err(Error::new(
-                    Code::MISUSE,
-                    "multiple statements in a single prepare are not allowed",
-                ));
-            }
-
-            let raw = NonNull::new_unchecked(raw.assume_init());
-            Ok(Statement::from_raw(raw, self.is_thread_safe))
         }
+
+        Ok(i64::from(current))
     }
 
-    /// Return the number of rows inserted, updated, or deleted by the most
-    /// recent INSERT, UPDATE, or DELETE statement.
+    /// Interrupt any pending database operation on the connection.
+    ///
+    /// Running statements abort at the earliest opportunity and return an
+    /// error with the code [`Code::INTERRUPT`]. Statements which are started
+    /// while others are still running are interrupted as well. If no
+    /// statements are running this has no effect.
+    ///
+    /// Note that a [`Connection`] can be sent to another thread if sqlite is
+    /// thread safe, in which case this can be used to abort work performed by
+    /// statements which are still held by the original thread.
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::Connection;
+    /// use sqll::{Code, Connection};
     ///
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
-    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     CREATE TABLE numbers (value INTEGER);
     ///
-    ///     INSERT INTO users VALUES ('Alice', 42);
-    ///     INSERT INTO users VALUES ('Bob', 72);
+    ///     INSERT INTO numbers VALUES (1), (2), (3);
     /// "#)?;
     ///
-    /// assert_eq!(c.changes(), 1);
+    /// let mut stmt = c.prepare("SELECT value FROM numbers")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(1));
+    ///
+    /// c.interrupt();
+    ///
+    /// let e = stmt.next::<i64>().unwrap_err();
+    /// assert_eq!(e.code(), Code::INTERRUPT);
     /// # Ok::<_, sqll::Error>(())
     /// ```
     #[inline]
-    pub fn changes(&self) -> usize {
-        unsafe { ffi::sqlite3_changes(self.raw.as_ptr()) as usize }
+    pub fn interrupt(&self) {
+        unsafe { ffi::sqlite3_interrupt(self.raw.as_ptr()) }
     }
 
-    /// Return the total number of rows inserted, updated, and deleted by all
-    /// INSERT, UPDATE, and DELETE statements since the connection was opened.
+    /// Test if the connection has been [interrupted].
+    ///
+    /// This allows for cooperative loops which interleave calls to sqlite with
+    /// other work to bail out promptly, without relying on an in-flight query
+    /// returning an error. The flag is cleared when a statement is started
+    /// while no other statements are running.
+    ///
+    /// This requires sqlite 3.41.0 or later, which is newer than the minimum
+    /// system version supported by this crate. It's therefore only available
+    /// with the `bundled` feature.
+    ///
+    /// [interrupted]: Connection::interrupt
     ///
     /// # Examples
     ///
@@ -715,111 +984,152 @@ impl Connection {
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
-    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     CREATE TABLE numbers (value INTEGER);
     ///
-    ///     INSERT INTO users VALUES ('Alice', 42);
-    ///     INSERT INTO users VALUES ('Bob', 72);
+    ///     INSERT INTO numbers VALUES (1), (2), (3);
     /// "#)?;
     ///
-    /// assert_eq!(c.total_changes(), 2);
+    /// let mut stmt = c.prepare("SELECT value FROM numbers")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(1));
+    /// assert!(!c.is_interrupted());
+    ///
+    /// c.interrupt();
+    /// assert!(c.is_interrupted());
+    ///
+    /// assert!(stmt.next::<i64>().is_err());
     /// # Ok::<_, sqll::Error>(())
     /// ```
+    #[cfg(feature = "bundled")]
+    #[cfg_attr(docsrs, cfg(feature = "bundled"))]
     #[inline]
-    pub fn total_changes(&self) -> usize {
-        unsafe { ffi::sqlite3_total_changes(self.raw.as_ptr()) as usize }
+    pub fn is_interrupted(&self) -> bool {
+        unsafe { ffi::sqlite3_is_interrupted(self.raw.as_ptr()) != 0 }
     }
 
-    /// Return the rowid of the most recent successful INSERT into a rowid table
-    /// or virtual table.
+    /// Set whether the connection is in query-only mode.
     ///
-    /// # Examples
+    /// While enabled, all attempts to modify the database through this
+    /// connection fail with [`Code::READONLY`]. Unlike opening the database
+    /// with [`OpenOptions::read_only`], this can be toggled at runtime which
+    /// allows a connection to be temporarily restricted while performing
+    /// untrusted operations and then restored afterwards.
     ///
-    /// If there is no primary key, the last inserted row id is an internal
-    /// identifier for the row:
+    /// This corresponds to the [`query_only` pragma].
+    ///
+    /// [`query_only` pragma]: https://sqlite.org/pragma.html#pragma_query_only
+    ///
+    /// # Examples
     ///
     /// ```
-    /// use sqll::Connection;
+    /// use sqll::{Code, Connection};
     ///
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
     ///     CREATE TABLE users (name TEXT);
-    ///
-    ///     INSERT INTO users VALUES ('Alice');
-    ///     INSERT INTO users VALUES ('Bob');
     /// "#)?;
-    /// assert_eq!(c.last_insert_rowid(), 2);
     ///
-    /// c.execute(r#"
-    ///     INSERT INTO users VALUES ('Charlie');
-    /// "#)?;
-    /// assert_eq!(c.last_insert_rowid(), 3);
+    /// c.set_query_only(true)?;
+    /// assert!(c.query_only()?);
     ///
-    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?)")?;
-    /// stmt.execute("Dave")?;
+    /// let e = c.execute("INSERT INTO users VALUES ('Alice')").unwrap_err();
+    /// assert_eq!(e.code(), Code::READONLY);
     ///
-    /// assert_eq!(c.last_insert_rowid(), 4);
+    /// c.set_query_only(false)?;
+    /// assert!(!c.query_only()?);
+    ///
+    /// c.execute("INSERT INTO users VALUES ('Alice')")?;
     /// # Ok::<_, sqll::Error>(())
     /// ```
+    pub fn set_query_only(&self, enabled: bool) -> Result<()> {
+        if enabled {
+            self._execute(b"PRAGMA query_only = ON")?;
+        } else {
+            self._execute(b"PRAGMA query_only = OFF")?;
+        }
+
+        Ok(())
+    }
+
+    /// Check if the connection is in query-only mode.
     ///
-    /// If there is a primary key, the last inserted row id corresponds to it:
+    /// See [`Connection::set_query_only`].
+    ///
+    /// # Examples
     ///
     /// ```
     /// use sqll::Connection;
     ///
     /// let c = Connection::open_in_memory()?;
+    /// assert!(!c.query_only()?);
     ///
-    /// c.execute(r#"
-    ///     CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);
-    ///
-    ///     INSERT INTO users (name) VALUES ('Alice');
-    ///     INSERT INTO users (name) VALUES ('Bob');
-    /// "#)?;
-    /// assert_eq!(c.last_insert_rowid(), 2);
+    /// c.set_query_only(true)?;
+    /// assert!(c.query_only()?);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn query_only(&self) -> Result<bool> {
+        let mut stmt = self.prepare("PRAGMA query_only")?;
+        Ok(stmt.next::<i64>()?.is_some_and(|value| value != 0))
+    }
+
+    /// Capture the current value of commonly changed pragmas, so that they
+    /// can be restored later using [`restore_pragma_snapshot`].
     ///
-    /// c.execute("INSERT INTO users (name) VALUES ('Charlie')")?;
-    /// assert_eq!(c.last_insert_rowid(), 3);
+    /// This captures the [`foreign_keys`], [`recursive_triggers`],
+    /// [`busy_timeout`], and [`journal_mode`] pragmas. This is useful when a
+    /// routine needs to temporarily change settings, such as disabling foreign
+    /// keys during a bulk load.
     ///
-    /// c.execute("INSERT INTO users (name) VALUES ('Dave')")?;
-    /// assert_eq!(c.last_insert_rowid(), 4);
+    /// [`restore_pragma_snapshot`]: Self::restore_pragma_snapshot
+    /// [`foreign_keys`]: https://sqlite.org/pragma.html#pragma_foreign_keys
+    /// [`recursive_triggers`]: https://sqlite.org/pragma.html#pragma_recursive_triggers
+    /// [`busy_timeout`]: https://sqlite.org/pragma.html#pragma_busy_timeout
+    /// [`journal_mode`]: https://sqlite.org/pragma.html#pragma_journal_mode
     ///
-    /// let mut select = c.prepare("SELECT id FROM users WHERE name = ?")?;
-    /// select.bind("Dave")?;
+    /// # Examples
     ///
-    /// for id in select.iter::<i64>() {
-    ///     assert_eq!(id?, 4);
-    /// }
+    /// ```
+    /// use sqll::Connection;
     ///
-    /// c.execute("DELETE FROM users WHERE id = 3")?;
-    /// assert_eq!(c.last_insert_rowid(), 4);
+    /// let mut c = Connection::open_in_memory()?;
+    /// c.execute("PRAGMA foreign_keys = ON")?;
     ///
-    /// c.execute("INSERT INTO users (name) VALUES ('Charlie')")?;
-    /// assert_eq!(c.last_insert_rowid(), 5);
+    /// let snapshot = c.pragma_snapshot()?;
+    /// assert!(snapshot.foreign_keys());
     ///
-    /// select.bind("Charlie")?;
+    /// c.execute("PRAGMA foreign_keys = OFF")?;
+    /// /* bulk load */
+    /// c.restore_pragma_snapshot(&snapshot)?;
     ///
-    /// while let Some(id) = select.next::<i64>()? {
-    ///     assert_eq!(id, 5);
-    /// }
+    /// assert_eq!(c.pragma_snapshot()?, snapshot);
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    #[inline]
-    pub fn last_insert_rowid(&self) -> i64 {
-        unsafe { ffi::sqlite3_last_insert_rowid(self.raw.as_ptr()) }
+    pub fn pragma_snapshot(&self) -> Result<PragmaSnapshot> {
+        Ok(PragmaSnapshot {
+            foreign_keys: self.pragma_integer("PRAGMA foreign_keys")? != 0,
+            recursive_triggers: self.pragma_integer("PRAGMA recursive_triggers")? != 0,
+            busy_timeout: self.pragma_integer("PRAGMA busy_timeout")?,
+            journal_mode: self.journal_mode()?,
+        })
     }
 
-    /// Set a callback for handling busy events.
+    /// Restore pragmas captured using [`pragma_snapshot`].
     ///
-    /// The callback is triggered when the database cannot perform an operation
-    /// due to processing of some other request. If the callback returns `true`,
-    /// the operation will be repeated.
+    /// Pragmas which haven't changed since the snapshot was taken are left
+    /// untouched. The busy timeout is restored as if by calling
+    /// [`busy_timeout`], so any busy handler which was installed after the
+    /// snapshot was taken is removed if the busy timeout differs.
     ///
-    /// The busy callback should not take any actions which modify the database
-    /// connection that invoked the busy handler. In other words, the busy
-    /// handler is not reentrant. Any such actions result in undefined behavior.
+    /// Note that sqlite silently ignores changes to `foreign_keys` inside of a
+    /// transaction, so this should be called outside of one.
     ///
-    /// Since this needs to allocate space to store the closure the `alloc`
-    /// feature has to be enabled.
+    /// [`pragma_snapshot`]: Self::pragma_snapshot
+    /// [`busy_timeout`]: Self::busy_timeout
+    ///
+    /// # Errors
+    ///
+    /// Errors if the journal mode couldn't be restored, such as while a
+    /// transaction is active.
     ///
     /// # Examples
     ///
@@ -827,115 +1137,2426 @@ impl Connection {
     /// use sqll::Connection;
     ///
     /// let mut c = Connection::open_in_memory()?;
+    /// let snapshot = c.pragma_snapshot()?;
     ///
-    /// c.busy_handler(|attempts| {
-    ///     println!("busy attempt: {attempts}");
-    ///     attempts < 5
-    /// })?;
+    /// c.execute(r#"
+    ///     PRAGMA recursive_triggers = ON;
+    ///     PRAGMA journal_mode = OFF;
+    /// "#)?;
+    /// c.busy_timeout(5000)?;
+    ///
+    /// c.restore_pragma_snapshot(&snapshot)?;
+    ///
+    /// let restored = c.pragma_snapshot()?;
+    /// assert!(!restored.recursive_triggers());
+    /// assert_eq!(restored.busy_timeout(), 0);
+    /// assert_eq!(restored.journal_mode(), "memory");
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    #[cfg(feature = "alloc")]
-    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
-    pub fn busy_handler<F>(&mut self, callback: F) -> Result<()>
-    where
-        F: FnMut(usize) -> bool + Send + 'static,
-    {
-        extern "C" fn glue<F>(callback: *mut c_void, attempts: c_int) -> c_int
-        where
-            F: FnMut(usize) -> bool,
-        {
-            unsafe {
-                if (*(callback as *mut F))(attempts as usize) {
-                    1
-                } else {
-                    0
-                }
+    pub fn restore_pragma_snapshot(&mut self, snapshot: &PragmaSnapshot) -> Result<()> {
+        let current = self.pragma_snapshot()?;
+
+        if current.foreign_keys != snapshot.foreign_keys {
+            if snapshot.foreign_keys {
+                self._execute(b"PRAGMA foreign_keys = ON")?;
+            } else {
+                self._execute(b"PRAGMA foreign_keys = OFF")?;
             }
         }
 
-        unsafe {
-            let callback = Owned::new(callback)?;
+        if current.recursive_triggers != snapshot.recursive_triggers {
+            if snapshot.recursive_triggers {
+                self._execute(b"PRAGMA recursive_triggers = ON")?;
+            } else {
+                self._execute(b"PRAGMA recursive_triggers = OFF")?;
+            }
+        }
 
-            let result = ffi::sqlite3_busy_handler(
-                self.raw.as_ptr(),
-                Some(glue::<F>),
-                callback.as_ptr().cast(),
-            );
+        if current.busy_timeout != snapshot.busy_timeout {
+            self.busy_timeout(snapshot.busy_timeout)?;
+        }
 
-            // NB: Old callback will be dropped and freed when we set the new
-            // one here.
-            self.busy_callback = Some(callback);
-            sqlite3_try!(self, result);
+        if current.journal_mode != snapshot.journal_mode {
+            let sql = match snapshot.journal_mode() {
+                "delete" => "PRAGMA journal_mode = DELETE",
+                "truncate" => "PRAGMA journal_mode = TRUNCATE",
+                "persist" => "PRAGMA journal_mode = PERSIST",
+                "memory" => "PRAGMA journal_mode = MEMORY",
+                "wal" => "PRAGMA journal_mode = WAL",
+                _ => "PRAGMA journal_mode = OFF",
+            };
+
+            self._execute(sql.as_bytes())?;
+
+            if self.journal_mode()? != snapshot.journal_mode {
+                return Err(Error::new(
+                    Code::ERROR,
+                    format_args!(
+                        "could not restore journal mode `{}`",
+                        snapshot.journal_mode()
+                    ),
+                ));
+            }
         }
 
         Ok(())
     }
 
-    /// Clear any previously registered busy handler.
+    /// Read a pragma which returns a single integer.
+    fn pragma_integer(&self, pragma: &str) -> Result<c_int> {
+        let mut stmt = self.prepare(pragma)?;
+        Ok(stmt.next::<c_int>()?.unwrap_or_default())
+    }
+
+    /// Read the journal mode of the main database as an index into
+    /// [`JOURNAL_MODES`].
+    fn journal_mode(&self) -> Result<usize> {
+        let mut stmt = self.prepare("PRAGMA journal_mode")?;
+        let mode = stmt.next::<&str>()?.unwrap_or_default();
+
+        let Some(index) = JOURNAL_MODES
+            .iter()
+            .position(|m| m.eq_ignore_ascii_case(mode))
+        else {
+            return Err(Error::new(
+                Code::ERROR,
+                format_args!("unsupported journal mode `{mode}`"),
+            ));
+        };
+
+        Ok(index)
+    }
+
+    /// Run `PRAGMA optimize` to update the statistics used by the query
+    /// planner.
+    ///
+    /// This is cheap when there is nothing to do. The sqlite documentation
+    /// recommends calling it periodically on long-lived connections, such as
+    /// once every few hours, and just before closing a connection which has
+    /// been used to run queries.
+    ///
+    /// See the [sqlite documentation] for more information.
+    ///
+    /// [sqlite documentation]: https://www.sqlite.org/pragma.html#pragma_optimize
     ///
     /// # Examples
     ///
     /// ```
     /// use sqll::Connection;
     ///
-    /// let mut c = Connection::open_in_memory()?;
+    /// let c = Connection::open_in_memory()?;
     ///
-    /// c.busy_handler(|attempts| {
-    ///     println!("busy attempt: {attempts}");
-    ///     attempts < 5
-    /// })?;
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     CREATE INDEX users_age ON users (age);
     ///
-    /// c.clear_busy_handler()?;
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users WHERE age > ?")?;
+    /// stmt.bind(50)?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Bob"));
+    /// drop(stmt);
+    ///
+    /// c.optimize()?;
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    #[inline]
-    pub fn clear_busy_handler(&mut self) -> Result<()> {
-        unsafe {
-            sqlite3_try! {
-                self,
-                ffi::sqlite3_busy_handler(
-                    self.raw.as_ptr(),
-                    None,
-                    null_mut()
-                )
-            };
-        }
+    pub fn optimize(&self) -> Result<()> {
+        self._execute(b"PRAGMA optimize")?;
+        Ok(())
+    }
 
-        #[cfg(feature = "alloc")]
-        {
+    /// List the databases attached to this connection.
+    ///
+    /// Each entry contains the sequence number, the schema name, and the file
+    /// backing the database. The file is `None` for in-memory and temporary
+    /// databases.
+    ///
+    /// The main database always has sequence number `0` and is named `main`.
+    /// The `temp` database is only listed once it has been used.
+    ///
+    /// This corresponds to the [`database_list` pragma].
+    ///
+    /// [`database_list` pragma]: https://sqlite.org/pragma.html#pragma_database_list
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// assert_eq!(c.database_list()?, [(0, String::from("main"), None)]);
+    ///
+    /// c.execute("ATTACH DATABASE ':memory:' AS other")?;
+    ///
+    /// let names = c.database_list()?.into_iter().map(|(_, name, _)| name).collect::<Vec<_>>();
+    /// assert_eq!(names, ["main", "other"]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn database_list(&self) -> Result<Vec<(i32, String, Option<String>)>> {
+        let mut stmt = self.prepare("PRAGMA database_list")?;
+        let mut list = Vec::new();
+
+        while let Some((seq, name, file)) = stmt.next::<(i32, String, String)>()? {
+            let file = (!file.is_empty()).then_some(file);
+            list.push((seq, name, file));
+        }
+
+        Ok(list)
+    }
+
+    /// Run an integrity check over all attached databases.
+    ///
+    /// This returns the list of problems found. If the database is intact, the
+    /// returned list is empty.
+    ///
+    /// This corresponds to the [`integrity_check` pragma]. See
+    /// [`quick_check`] for a faster but less thorough check.
+    ///
+    /// [`integrity_check` pragma]: https://sqlite.org/pragma.html#pragma_integrity_check
+    /// [`quick_check`]: Self::quick_check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT UNIQUE, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 69);
+    /// "#)?;
+    ///
+    /// assert!(c.integrity_check()?.is_empty());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn integrity_check(&self) -> Result<Vec<String>> {
+        self.check("PRAGMA integrity_check")
+    }
+
+    /// Run a quick integrity check over all attached databases.
+    ///
+    /// This is like [`integrity_check`], except that it skips verifying that
+    /// index content matches table content, which makes it run in linear time.
+    /// If the database is intact, the returned list is empty.
+    ///
+    /// This corresponds to the [`quick_check` pragma].
+    ///
+    /// [`integrity_check`]: Self::integrity_check
+    /// [`quick_check` pragma]: https://sqlite.org/pragma.html#pragma_quick_check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT UNIQUE, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 69);
+    /// "#)?;
+    ///
+    /// assert!(c.quick_check()?.is_empty());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn quick_check(&self) -> Result<Vec<String>> {
+        self.check("PRAGMA quick_check")
+    }
+
+    #[cfg(feature = "alloc")]
+    fn check(&self, pragma: &str) -> Result<Vec<String>> {
+        let mut stmt = self.prepare(pragma)?;
+        let mut problems = Vec::new();
+
+        while let Some(message) = stmt.next::<String>()? {
+            problems.push(message);
+        }
+
+        // A single row containing "ok" is reported when no problems are found.
+        if problems.len() == 1 && problems[0] == "ok" {
+            problems.clear();
+        }
+
+        Ok(problems)
+    }
+
+    /// Execute a batch of statements.
+    ///
+    /// Unlike [`prepare`], this can be used to execute multiple statements
+    /// separated by a semi-colon `;` and is internally optimized for one-off
+    /// queries.
+    ///
+    /// [`prepare`]: Self::prepare
+    ///
+    /// # Errors
+    ///
+    /// If any of the statements fail, an error is returned. An interior null
+    /// byte in the SQL results in a [`Code::MISUSE`] error before any
+    /// statement is executed.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.execute(":)").unwrap_err();
+    /// assert_eq!(e.code(), Code::ERROR);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Result};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42);
+    ///     INSERT INTO users VALUES ('Bob', 72);
+    /// "#)?;
+    ///
+    /// let results = c.prepare("SELECT name, age FROM users")?
+    ///     .iter::<(String, u32)>()
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(results, [("Alice".to_string(), 42), ("Bob".to_string(), 72)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn execute(&self, stmt: impl AsRef<str>) -> Result<()> {
+        self._execute(stmt.as_ref().as_bytes())?;
+        Ok(())
+    }
+
+    /// Execute a batch of statements provided as bytes.
+    ///
+    /// This is the same as [`execute`], except that it doesn't require the SQL
+    /// to be a [`str`]. This is useful when the SQL comes from a trusted source
+    /// such as a [`CStr`] and checking that it is valid UTF-8 would be
+    /// redundant.
+    ///
+    /// The SQL is still expected to be valid UTF-8, but this is not checked.
+    /// Invalid UTF-8 does not cause undefined behavior, but might result in
+    /// confusing errors or unexpected names.
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::MISUSE`] if the SQL contains an interior null byte,
+    /// since sqlite would otherwise silently ignore everything following it.
+    /// This is checked before any statement is executed.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.execute_bytes(b"CREATE TABLE a (id INTEGER);\0CREATE TABLE b (id INTEGER);").unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// assert!(c.prepare("SELECT * FROM a").is_err());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let sql = c"CREATE TABLE users (name TEXT); INSERT INTO users VALUES ('Alice');";
+    /// c.execute_bytes(sql.to_bytes())?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn execute_bytes(&self, stmt: impl AsRef<[u8]>) -> Result<()> {
+        self._execute(stmt.as_ref())?;
+        Ok(())
+    }
+
+    /// Execute a batch of statements provided as UTF-16.
+    ///
+    /// This is the same as [`execute`], except that the SQL is encoded as
+    /// native-endian UTF-16. This is useful for interoperating with systems
+    /// which produce UTF-16 query text, but since sqlite converts it to UTF-8
+    /// internally, [`execute`] should be preferred where possible.
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::MISUSE`] if the SQL contains an interior null
+    /// character, since sqlite would otherwise silently ignore everything
+    /// following it. This is checked before any statement is executed.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let sql = "CREATE TABLE a (id INTEGER);\0CREATE TABLE b (id INTEGER);";
+    /// let sql = sql.encode_utf16().collect::<Vec<u16>>();
+    ///
+    /// let e = c.execute_utf16(&sql).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// assert!(c.prepare("SELECT * FROM a").is_err());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let sql = "CREATE TABLE users (name TEXT); INSERT INTO users VALUES ('Alice');";
+    /// let sql = sql.encode_utf16().collect::<Vec<u16>>();
+    /// c.execute_utf16(&sql)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn execute_utf16(&self, stmt: &[u16]) -> Result<()> {
+        self._execute_with(stmt, |_| {}, |_| Ok(()))?;
+        Ok(())
+    }
+
+    /// Execute a batch of statements and return the total number of rows
+    /// inserted, updated, or deleted by all of them.
+    ///
+    /// Unlike [`changes`], which only reflects the most recent statement, this
+    /// accounts for every statement in the batch. It's computed as the
+    /// difference in [`total_changes`] before and after executing the batch,
+    /// so it also counts changes made by triggers.
+    ///
+    /// If an error occurs, the number of changes made by the statements which
+    /// completed before it is lost.
+    ///
+    /// [`changes`]: Self::changes
+    /// [`total_changes`]: Self::total_changes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let n = c.execute_counting(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    ///     UPDATE users SET age = age + 1;
+    ///     DELETE FROM users WHERE name = 'Bob';
+    /// "#)?;
+    ///
+    /// assert_eq!(n, 5);
+    /// assert_eq!(c.changes(), 1);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_counting(&self, stmt: impl AsRef<str>) -> Result<usize> {
+        // NB: The 64-bit counter is used since the difference is wrong if the
+        // 32-bit one overflows.
+        let before = unsafe { ffi::sqlite3_total_changes64(self.raw.as_ptr()) };
+        self._execute(stmt.as_ref().as_bytes())?;
+        let after = unsafe { ffi::sqlite3_total_changes64(self.raw.as_ptr()) };
+        Ok(usize::try_from(after - before).unwrap_or(usize::MAX))
+    }
+
+    /// Execute a batch of statements, calling `progress` before each statement
+    /// is executed.
+    ///
+    /// The callback is passed the zero-based index of the statement which is
+    /// about to be executed. Empty statements, such as those only consisting of
+    /// comments or semi-colons, are skipped and do not count towards the index.
+    ///
+    /// This behaves like [`execute`] otherwise, and is useful for reporting
+    /// progress when running long scripts such as migrations.
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// c.execute_with_progress(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     -- Seed the table.
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    /// "#, |index| log.push(format!("running statement {}", index + 1)))?;
+    ///
+    /// assert_eq!(log, ["running statement 1", "running statement 2"]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_with_progress(
+        &self,
+        stmt: impl AsRef<str>,
+        progress: impl FnMut(usize),
+    ) -> Result<()> {
+        self._execute_with(stmt.as_ref().as_bytes(), progress, |_| Ok(()))?;
+        Ok(())
+    }
+
+    /// Execute a batch of statements, calling `row` for each row produced by
+    /// any of them.
+    ///
+    /// Where [`execute`] silently discards the rows of statements such as
+    /// `SELECT`, this allows them to be observed. This is useful for scripts
+    /// which mix schema changes with queries whose output is of interest.
+    ///
+    /// The callback is passed the statement which produced the row, from
+    /// which columns can be read using for example [`Statement::row`] or
+    /// [`Statement::column`]. Since each statement in the batch can produce a
+    /// different set of columns, [`Statement::column_count`] and
+    /// [`Statement::column_name`] can be used to tell them apart.
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Errors
+    ///
+    /// If any of the statements or the callback fail, execution stops and the
+    /// error is returned.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection, Error};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.execute_with_rows("SELECT 1; SELECT 2;", |_| {
+    ///     Err(Error::new(Code::ABORT, "stopped"))
+    /// }).unwrap_err();
+    ///
+    /// assert_eq!(e.code(), Code::ABORT);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut names = Vec::new();
+    ///
+    /// c.execute_with_rows(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    ///     SELECT name FROM users ORDER BY name;
+    ///     DROP TABLE users;
+    /// "#, |stmt| {
+    ///     names.push(stmt.column::<String>(0)?);
+    ///     Ok(())
+    /// })?;
+    ///
+    /// assert_eq!(names, ["Alice", "Bob"]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_with_rows(
+        &self,
+        stmt: impl AsRef<str>,
+        row: impl FnMut(&mut Statement) -> Result<()>,
+    ) -> Result<()> {
+        self._execute_with(stmt.as_ref().as_bytes(), |_| {}, row)?;
+        Ok(())
+    }
+
+    /// Prepare a single statement and execute it once for each set of
+    /// parameters in `rows`.
+    ///
+    /// All executions are performed inside of a savepoint, so either all of
+    /// them are applied or none of them are. Since savepoints nest, this can
+    /// also be used inside of an already open transaction.
+    ///
+    /// This is useful for things like loading fixtures.
+    ///
+    /// # Errors
+    ///
+    /// If preparing the statement or any of the executions fail, all changes
+    /// are rolled back and the error is returned.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT NOT NULL UNIQUE);
+    /// "#)?;
+    ///
+    /// let e = c.execute_each("INSERT INTO users (name) VALUES (?)", ["Alice", "Bob", "Alice"]).unwrap_err();
+    /// assert_eq!(e, Code::CONSTRAINT_UNIQUE);
+    ///
+    /// let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(0));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Result};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// c.execute_each("INSERT INTO users (name, age) VALUES (?, ?)", [("Alice", 42), ("Bob", 72)])?;
+    ///
+    /// let results = c.prepare("SELECT name, age FROM users")?
+    ///     .iter::<(String, u32)>()
+    ///     .collect::<Result<Vec<_>>>()?;
+    ///
+    /// assert_eq!(results, [("Alice".to_string(), 42), ("Bob".to_string(), 72)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_each<I>(&self, stmt: impl AsRef<str>, rows: I) -> Result<()>
+    where
+        I: IntoIterator<Item: Bind>,
+    {
+        self.with_savepoint(savepoint!("sqll_execute_each"), || {
+            let mut stmt = self.prepare(stmt)?;
+
+            for row in rows {
+                stmt.execute(row)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Execute a batch of statements, erroring unless exactly `n` statements
+    /// were executed.
+    ///
+    /// Empty statements, such as stray semicolons and comments, are not
+    /// counted. This is intended as a safety check for things like migrations,
+    /// where a script which contains fewer statements than expected might have
+    /// been truncated.
+    ///
+    /// All statements are executed inside of a savepoint, so if the count
+    /// doesn't match or any statement fails no changes are applied. As a
+    /// consequence the script cannot contain statements which are not allowed
+    /// inside of a transaction, such as `VACUUM`, or statements which manage
+    /// transactions themselves.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::ERROR`] if the number of executed statements
+    /// doesn't match `n`.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.execute_exactly(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#, 3).unwrap_err();
+    ///
+    /// assert_eq!(e.code(), Code::ERROR);
+    /// assert!(c.prepare("SELECT * FROM users").is_err());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute_exactly(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///     -- Seed the table.
+    ///     INSERT INTO users VALUES ('Alice');;
+    /// "#, 2)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_exactly(&self, stmt: impl AsRef<str>, n: usize) -> Result<()> {
+        self.with_savepoint(savepoint!("sqll_execute_exactly"), || {
+            let count = self._execute(stmt.as_ref().as_bytes())?;
+
+            if count != n {
+                return Err(Error::new(
+                    Code::ERROR,
+                    format_args!("expected {n} statements but executed {count}"),
+                ));
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Call `f` inside of the given savepoint, rolling back any changes it
+    /// made if it errors.
+    fn with_savepoint<T>(&self, savepoint: Savepoint, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        self._execute(savepoint.begin)?;
+
+        match f() {
+            Ok(value) => {
+                self._execute(savepoint.release)?;
+                Ok(value)
+            }
+            Err(error) => {
+                // NB: Any error from rolling back or releasing is less
+                // interesting than the one which caused it. These also fail if
+                // sqlite has already rolled back the whole transaction, such as
+                // through `RAISE(ROLLBACK)` or after an I/O error, since that
+                // discards the savepoint.
+                _ = self._execute(savepoint.rollback);
+                _ = self._execute(savepoint.release);
+                Err(error)
+            }
+        }
+    }
+
+    /// Execute a batch of statements, returning the number of non-empty
+    /// statements executed.
+    #[inline]
+    fn _execute(&self, stmt: &[u8]) -> Result<usize> {
+        self._execute_with(stmt, |_| {}, |_| Ok(()))
+    }
+
+    /// Execute a batch of statements, calling `progress` with the index of
+    /// each non-empty statement before it is executed and `row` for each row
+    /// produced.
+    fn _execute_with<T>(
+        &self,
+        stmt: &[T],
+        mut progress: impl FnMut(usize),
+        mut row: impl FnMut(&mut Statement) -> Result<()>,
+    ) -> Result<usize>
+    where
+        T: SqlUnit,
+    {
+        // Reject interior nulls up front, since sqlite would otherwise stop at
+        // them after having executed the statements preceding them.
+        if stmt.contains(&T::NUL) {
+            return Err(Error::new(Code::MISUSE, T::NUL_MESSAGE));
+        }
+
+        let mut rest = stmt;
+        let mut count = 0;
+
+        while !rest.is_empty() {
+            let (raw, consumed) = unsafe { T::prepare(self, rest, stmt.len() - rest.len())? };
+
+            // If statement is null then it's simply empty, so we can safely
+            // skip it, otherwise iterate over all rows.
+            if let Some(raw) = raw {
+                let mut statement = Statement::from_raw(raw, self.is_thread_safe);
+                progress(count);
+                while statement.step()?.is_row() {
+                    row(&mut statement)?;
+                }
+                count += 1;
+            }
+
+            // NB: sqlite only stops without consuming anything at a null,
+            // which has been rejected above.
+            if consumed == 0 {
+                break;
+            }
+
+            // Skip over empty statements.
+            rest = &rest[consumed..];
+        }
+
+        Ok(count)
+    }
+
+    /// Construct an error for a failed prepare, including where in the SQL it
+    /// occured if available. The offset reported by sqlite is relative to the
+    /// statement being prepared in `sql`, which starts at `start`.
+    #[cfg_attr(not(feature = "bundled"), allow(unused_variables))]
+    fn prepare_error<T>(&self, code: c_int, sql: &[T], start: usize) -> Error
+    where
+        T: SqlUnit,
+    {
+        let error = self.error(code);
+
+        #[cfg(feature = "bundled")]
+        if let Ok(offset) = usize::try_from(unsafe { ffi::sqlite3_error_offset(self.raw.as_ptr()) })
+        {
+            return error.with_offset(start + T::offset(sql, offset));
+        }
+
+        error
+    }
+
+    /// Construct an error for a failed operation on the connection.
+    ///
+    /// If the operation failed because a busy handler aborted with a custom
+    /// error, that error is returned instead.
+    #[cold]
+    pub(crate) fn error(&self, code: c_int) -> Error {
+        #[cfg(feature = "std")]
+        if let Some(error) = busy::take_aborted(self.raw.as_ptr(), code) {
+            return error;
+        }
+
+        Error::new(Code::new(code), self.error_message())
+    }
+
+    /// Enable or disable extended result codes.
+    ///
+    /// This can also be set during construction with
+    /// [`OpenOptions::extended_result_codes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{OpenOptions, Code};
+    ///
+    /// let mut c = OpenOptions::new().create().read_write().open_in_memory()?;
+    ///
+    /// let e = c.execute("
+    ///     CREATE TABLE users (name TEXT);
+    ///     CREATE UNIQUE INDEX idx_users_name ON users (name);
+    ///
+    ///     INSERT INTO users VALUES ('Bob');
+    /// ");
+    ///
+    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
+    /// assert_eq!(e, Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    ///
+    /// c.extended_result_codes(false)?;
+    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
+    /// assert_eq!(e.code(), Code::CONSTRAINT);
+    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn extended_result_codes(&mut self, enabled: bool) -> Result<()> {
+        unsafe {
+            let onoff = i32::from(enabled);
+            sqlite3_try!(
+                self,
+                ffi::sqlite3_extended_result_codes(self.raw.as_ptr(), onoff)
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Get the last error message for this connection.
+    ///
+    /// When operating in multi-threaded environment, the error message seen
+    /// here might not correspond to the query that failed unless some kind of
+    /// external synchronization is in use which is the recommended way to use
+    /// sqlite.
+    ///
+    /// This is only meaningful if an error has occured. If no errors have
+    /// occured, this returns a non-erronous message like `"not an error"`
+    /// (default for sqlite3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.execute("
+    ///     CREATE TABLE users (name TEXT);
+    ///     CREATE UNIQUE INDEX idx_users_name ON users (name);
+    ///
+    ///     INSERT INTO users VALUES ('Bob');
+    /// ");
+    ///
+    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
+    /// assert_eq!(e, Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn error_message(&self) -> &Text {
+        unsafe { c_to_error_text(ffi::sqlite3_errmsg(self.raw.as_ptr())) }
+    }
+
+    /// Get the code of the last error for this connection.
+    ///
+    /// Unless [extended result codes] are enabled, which they are by default,
+    /// this only returns the base code of the error. To always get the
+    /// extended code, use [`Connection::extended_error_code`].
+    ///
+    /// The same caveats apply as for [`Connection::error_message`]. If no
+    /// errors have occured, this returns [`Code::OK`].
+    ///
+    /// [extended result codes]: Connection::extended_result_codes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    /// assert_eq!(c.error_code(), Code::OK);
+    ///
+    /// c.execute("
+    ///     CREATE TABLE users (name TEXT UNIQUE);
+    ///
+    ///     INSERT INTO users VALUES ('Bob');
+    /// ")?;
+    ///
+    /// c.extended_result_codes(false)?;
+    ///
+    /// let e = c.execute("INSERT INTO users VALUES ('Bob')").unwrap_err();
+    /// assert_eq!(e.code(), Code::CONSTRAINT);
+    /// assert_eq!(c.error_code(), Code::CONSTRAINT);
+    /// assert_eq!(c.extended_error_code(), Code::CONSTRAINT_UNIQUE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn error_code(&self) -> Code {
+        unsafe { Code::new(ffi::sqlite3_errcode(self.raw.as_ptr())) }
+    }
+
+    /// Get the extended code of the last error for this connection.
+    ///
+    /// This returns the extended code regardless of whether [extended result
+    /// codes] are enabled or not.
+    ///
+    /// The same caveats apply as for [`Connection::error_message`]. If no
+    /// errors have occured, this returns [`Code::OK`].
+    ///
+    /// [extended result codes]: Connection::extended_result_codes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute("
+    ///     CREATE TABLE users (name TEXT UNIQUE);
+    ///
+    ///     INSERT INTO users VALUES ('Bob');
+    /// ")?;
+    ///
+    /// // The error is swallowed, but the code can still be recovered.
+    /// _ = c.execute("INSERT INTO users VALUES ('Bob')");
+    /// assert_eq!(c.extended_error_code(), Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(c.extended_error_code().base(), Code::CONSTRAINT);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn extended_error_code(&self) -> Code {
+        unsafe { Code::new(ffi::sqlite3_extended_errcode(self.raw.as_ptr())) }
+    }
+
+    /// Build a prepared statement.
+    ///
+    /// This is the same as calling `prepare_with` with `Prepare::EMPTY`,
+    /// except that the most recently prepared statement is reused if the
+    /// [single statement cache] is enabled.
+    ///
+    /// The database connection will be kept open for the lifetime of this
+    /// statement.
+    ///
+    /// [single statement cache]: Self::single_cache
+    ///
+    /// # Errors
+    ///
+    /// If the prepare call contains multiple statements, it will error. To
+    /// execute multiple statements, use [`execute`] instead. Trailing
+    /// whitespace, comments, and semi-colons after a single statement are
+    /// permitted.
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.prepare("CREATE TABLE test (id INTEGER) /* test */; INSERT INTO test (id) VALUES (1);").unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    ///
+    /// let e = c.prepare("  -- nothing to see here").unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    ///
+    /// c.prepare("CREATE TABLE test (id INTEGER); /* test */\n")?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE test (id INTEGER);
+    /// "#)?;
+    ///
+    /// let mut insert_stmt = c.prepare("INSERT INTO test (id) VALUES (?);")?;
+    /// let mut query_stmt = c.prepare("SELECT id FROM test;")?;
+    ///
+    /// drop(c);
+    ///
+    /// insert_stmt.execute(42)?;
+    ///
+    /// query_stmt.bind(())?;
+    /// assert_eq!(query_stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn prepare(&self, stmt: impl AsRef<str>) -> Result<Statement> {
+        #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+        if let Some(cache) = &self.statement_cache {
+            let stmt = stmt.as_ref();
+
+            let stmt = match cache.take(stmt) {
+                Some(raw) => Statement::from_raw(raw, self.is_thread_safe),
+                None => self.prepare_with(stmt, Prepare::EMPTY)?,
+            };
+
+            return Ok(stmt.with_cache(cache.clone()));
+        }
+
+        self.prepare_with(stmt, Prepare::EMPTY)
+    }
+
+    /// Build a prepared statement with custom flags.
+    ///
+    /// For long-running statements it is recommended that they have the
+    /// [`Prepare::PERSISTENT`] flag set.
+    ///
+    /// The database connection will be kept open for the lifetime of this
+    /// statement.
+    ///
+    /// # Errors
+    ///
+    /// If the prepare call contains multiple statements, it will error. To
+    /// execute multiple statements, use [`execute`] instead. Trailing
+    /// whitespace, comments, and semi-colons after a single statement are
+    /// permitted.
+    ///
+    /// ```
+    /// use sqll::{Connection, Code, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.prepare_with("CREATE TABLE test (id INTEGER); INSERT INTO test (id) VALUES (1);", Prepare::PERSISTENT).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// [`execute`]: Self::execute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE test (id INTEGER);
+    /// "#)?;
+    ///
+    /// let mut insert_stmt = c.prepare_with("INSERT INTO test (id) VALUES (?)", Prepare::PERSISTENT)?;
+    /// let mut query_stmt = c.prepare_with("SELECT id FROM test", Prepare::PERSISTENT)?;
+    ///
+    /// drop(c);
+    ///
+    /// /* .. */
+    ///
+    /// insert_stmt.bind(42)?;
+    /// assert!(insert_stmt.step()?.is_done());
+    ///
+    /// query_stmt.bind(())?;
+    /// assert_eq!(query_stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn prepare_with(&self, stmt: impl AsRef<str>, flags: Prepare) -> Result<Statement> {
+        let stmt = stmt.as_ref();
+
+        unsafe {
+            let mut raw = MaybeUninit::uninit();
+            let mut rest = MaybeUninit::uninit();
+
+            let ptr = stmt.as_ptr().cast();
+            let len = i32::try_from(stmt.len()).unwrap_or(i32::MAX);
+
+            let code = ffi::sqlite3_prepare_v3(
+                self.raw.as_ptr(),
+                ptr,
+                len,
+                flags.0,
+                raw.as_mut_ptr(),
+                rest.as_mut_ptr(),
+            );
+
+            if code != ffi::SQLITE_OK {
+                return Err(self.prepare_error(code, stmt.as_bytes(), 0));
+            }
+
+            let raw = raw.assume_init();
+            let rest = rest.assume_init();
+
+            let o = rest.offset_from_unsigned(ptr);
+
+            if !is_insignificant_sql(&stmt.as_bytes()[o..]) {
+                ffi::sqlite3_finalize(raw);
+
+                return Err(Error::new(
+                    Code::MISUSE,
+                    "multiple statements in a single prepare are not allowed",
+                ));
+            }
+
+            let Some(raw) = NonNull::new(raw) else {
+                return Err(Error::new(Code::MISUSE, "no statement to prepare"));
+            };
+
+            Ok(Statement::from_raw(raw, self.is_thread_safe))
+        }
+    }
+
+    /// Build a prepared statement with custom flags from a null-terminated
+    /// c-string.
+    ///
+    /// This is the same as [`prepare_with`], except that the length of the SQL
+    /// doesn't have to be known in advance. This is useful when the SQL
+    /// already comes as a [`CStr`], such as from a c-string literal or over
+    /// FFI.
+    ///
+    /// [`prepare_with`]: Self::prepare_with
+    ///
+    /// # Errors
+    ///
+    /// Like [`prepare_with`], this errors if the SQL contains multiple
+    /// statements.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let e = c.prepare_c_str(c"SELECT 1; SELECT 2", Prepare::EMPTY).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare_c_str(c"SELECT 1 + ?", Prepare::EMPTY)?;
+    /// stmt.bind(41)?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(42));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn prepare_c_str(&self, stmt: &CStr, flags: Prepare) -> Result<Statement> {
+        unsafe {
+            let mut raw = MaybeUninit::uninit();
+            let mut rest = MaybeUninit::uninit();
+
+            let code = ffi::sqlite3_prepare_v3(
+                self.raw.as_ptr(),
+                stmt.as_ptr(),
+                -1,
+                flags.0,
+                raw.as_mut_ptr(),
+                rest.as_mut_ptr(),
+            );
+
+            if code != ffi::SQLITE_OK {
+                return Err(self.prepare_error(code, stmt.to_bytes(), 0));
+            }
+
+            let raw = raw.assume_init();
+            let rest = CStr::from_ptr(rest.assume_init());
+
+            if !is_insignificant_sql(rest.to_bytes()) {
+                ffi::sqlite3_finalize(raw);
+
+                return Err(Error::new(
+                    Code::MISUSE,
+                    "multiple statements in a single prepare are not allowed",
+                ));
+            }
+
+            let Some(raw) = NonNull::new(raw) else {
+                return Err(Error::new(Code::MISUSE, "no statement to prepare"));
+            };
+
+            Ok(Statement::from_raw(raw, self.is_thread_safe))
+        }
+    }
+
+    /// Build a prepared statement with custom flags from UTF-16 SQL.
+    ///
+    /// This is the same as [`prepare_with`], except that the SQL is encoded as
+    /// native-endian UTF-16. See [`execute_utf16`] for details.
+    ///
+    /// [`prepare_with`]: Self::prepare_with
+    /// [`execute_utf16`]: Self::execute_utf16
+    ///
+    /// # Errors
+    ///
+    /// Like [`prepare_with`], this errors if the SQL contains multiple
+    /// statements.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let sql = "SELECT 1; SELECT 2".encode_utf16().collect::<Vec<u16>>();
+    /// let e = c.prepare_utf16(&sql, Prepare::EMPTY).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Prepare};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let sql = "SELECT 1 + ?".encode_utf16().collect::<Vec<u16>>();
+    /// let mut stmt = c.prepare_utf16(&sql, Prepare::EMPTY)?;
+    /// stmt.bind(41)?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(42));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn prepare_utf16(&self, stmt: &[u16], flags: Prepare) -> Result<Statement> {
+        unsafe {
+            let mut raw = MaybeUninit::uninit();
+            let mut rest = MaybeUninit::uninit();
+
+            let ptr = stmt.as_ptr();
+            let len = i32::try_from(stmt.len().saturating_mul(2)).unwrap_or(i32::MAX - 1);
+
+            let code = ffi::sqlite3_prepare16_v3(
+                self.raw.as_ptr(),
+                ptr.cast(),
+                len,
+                flags.0,
+                raw.as_mut_ptr(),
+                rest.as_mut_ptr(),
+            );
+
+            if code != ffi::SQLITE_OK {
+                return Err(self.prepare_error(code, stmt, 0));
+            }
+
+            let raw = raw.assume_init();
+            let rest = rest.assume_init();
+
+            let o = rest.cast::<u16>().offset_from_unsigned(ptr);
+
+            if !is_insignificant_sql16(&stmt[o..]) {
+                ffi::sqlite3_finalize(raw);
+
+                return Err(Error::new(
+                    Code::MISUSE,
+                    "multiple statements in a single prepare are not allowed",
+                ));
+            }
+
+            let Some(raw) = NonNull::new(raw) else {
+                return Err(Error::new(Code::MISUSE, "no statement to prepare"));
+            };
+
+            Ok(Statement::from_raw(raw, self.is_thread_safe))
+        }
+    }
+
+    /// Enable or disable the single statement cache.
+    ///
+    /// While enabled, a statement prepared through [`prepare`] is not
+    /// finalized when it is dropped. Instead it is reset, has its bindings
+    /// cleared, and is retained by the connection. If the next call to
+    /// [`prepare`] uses the exact same SQL the retained statement is returned
+    /// instead of preparing a new one.
+    ///
+    /// Only a single statement is retained at a time, so this is only useful
+    /// for the common case where the same statement is repeatedly prepared and
+    /// dropped, such as inside of a loop. For anything more elaborate, keep the
+    /// [`Statement`] around and reuse it directly.
+    ///
+    /// The cache is disabled by default. Disabling it finalizes the retained
+    /// statement.
+    ///
+    /// [`prepare`]: Self::prepare
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    /// c.single_cache(true);
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice'), ('Bob');
+    /// "#)?;
+    ///
+    /// for name in ["Alice", "Bob"] {
+    ///     // After the first iteration this reuses the statement prepared in
+    ///     // the previous iteration.
+    ///     let mut stmt = c.prepare("SELECT COUNT(*) FROM users WHERE name = ?")?;
+    ///     stmt.bind(name)?;
+    ///     assert_eq!(stmt.next::<i64>()?, Some(1));
+    /// }
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, cfg(all(feature = "alloc", target_has_atomic = "ptr")))]
+    pub fn single_cache(&mut self, enabled: bool) {
+        match (&self.statement_cache, enabled) {
+            (None, true) => {
+                self.statement_cache = Some(Arc::new(StatementCache::new()));
+            }
+            (Some(cache), false) => {
+                cache.clear();
+                self.statement_cache = None;
+            }
+            _ => {}
+        }
+    }
+
+    /// Return the number of rows inserted, updated, or deleted by the most
+    /// recent INSERT, UPDATE, or DELETE statement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42);
+    ///     INSERT INTO users VALUES ('Bob', 72);
+    /// "#)?;
+    ///
+    /// assert_eq!(c.changes(), 1);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn changes(&self) -> usize {
+        unsafe { ffi::sqlite3_changes(self.raw.as_ptr()) as usize }
+    }
+
+    /// Return the total number of rows inserted, updated, and deleted by all
+    /// INSERT, UPDATE, and DELETE statements since the connection was opened.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42);
+    ///     INSERT INTO users VALUES ('Bob', 72);
+    /// "#)?;
+    ///
+    /// assert_eq!(c.total_changes(), 2);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn total_changes(&self) -> usize {
+        unsafe { ffi::sqlite3_total_changes(self.raw.as_ptr()) as usize }
+    }
+
+    /// Return the rowid of the most recent successful INSERT into a rowid table
+    /// or virtual table.
+    ///
+    /// # Examples
+    ///
+    /// If there is no primary key, the last inserted row id is an internal
+    /// identifier for the row:
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice');
+    ///     INSERT INTO users VALUES ('Bob');
+    /// "#)?;
+    /// assert_eq!(c.last_insert_rowid(), 2);
+    ///
+    /// c.execute(r#"
+    ///     INSERT INTO users VALUES ('Charlie');
+    /// "#)?;
+    /// assert_eq!(c.last_insert_rowid(), 3);
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?)")?;
+    /// stmt.execute("Dave")?;
+    ///
+    /// assert_eq!(c.last_insert_rowid(), 4);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// If there is a primary key, the last inserted row id corresponds to it:
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);
+    ///
+    ///     INSERT INTO users (name) VALUES ('Alice');
+    ///     INSERT INTO users (name) VALUES ('Bob');
+    /// "#)?;
+    /// assert_eq!(c.last_insert_rowid(), 2);
+    ///
+    /// c.execute("INSERT INTO users (name) VALUES ('Charlie')")?;
+    /// assert_eq!(c.last_insert_rowid(), 3);
+    ///
+    /// c.execute("INSERT INTO users (name) VALUES ('Dave')")?;
+    /// assert_eq!(c.last_insert_rowid(), 4);
+    ///
+    /// let mut select = c.prepare("SELECT id FROM users WHERE name = ?")?;
+    /// select.bind("Dave")?;
+    ///
+    /// for id in select.iter::<i64>() {
+    ///     assert_eq!(id?, 4);
+    /// }
+    ///
+    /// c.execute("DELETE FROM users WHERE id = 3")?;
+    /// assert_eq!(c.last_insert_rowid(), 4);
+    ///
+    /// c.execute("INSERT INTO users (name) VALUES ('Charlie')")?;
+    /// assert_eq!(c.last_insert_rowid(), 5);
+    ///
+    /// select.bind("Charlie")?;
+    ///
+    /// while let Some(id) = select.next::<i64>()? {
+    ///     assert_eq!(id, 5);
+    /// }
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn last_insert_rowid(&self) -> i64 {
+        unsafe { ffi::sqlite3_last_insert_rowid(self.raw.as_ptr()) }
+    }
+
+    /// Prepare and execute an insert, returning the rowid of the inserted row
+    /// and the number of rows changed.
+    ///
+    /// Both values are read immediately after the insert completes, before any
+    /// other statement has had the chance to run on this connection and
+    /// change them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let result = c.insert("INSERT INTO users (name, age) VALUES (?, ?)", ("Alice", 42))?;
+    /// assert_eq!(result.rowid(), 1);
+    /// assert_eq!(result.changes(), 1);
+    ///
+    /// let result = c.insert("INSERT INTO users (name, age) VALUES (?, ?)", ("Bob", 72))?;
+    /// assert_eq!(result.rowid(), 2);
+    /// assert_eq!(result.changes(), 1);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn insert(&self, stmt: impl AsRef<str>, bind: impl Bind) -> Result<InsertResult> {
+        let mut stmt = self.prepare(stmt)?;
+        stmt.execute(bind)?;
+        Ok(InsertResult::new(self.last_insert_rowid(), self.changes()))
+    }
+
+    /// Prepare and execute a single statement constructed using [`sql!`] with
+    /// the given parameters.
+    ///
+    /// The number of parameters being bound is checked against the number of
+    /// parameters in the SQL at compile time, see [`Parameters`].
+    ///
+    /// [`sql!`]: crate::sql!
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{sql, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// c.execute_sql(sql!("INSERT INTO users (name, age) VALUES (?, ?)"), ("Alice", 42))?;
+    /// c.execute_sql(sql!("INSERT INTO users (name, age) VALUES (:name, 72)"), "Bob")?;
+    /// c.execute_sql(sql!("DELETE FROM users WHERE age > 70"), ())?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// assert_eq!(stmt.next::<String>()?, None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// Binding the wrong number of parameters fails to compile:
+    ///
+    /// ```compile_fail
+    /// use sqll::{sql, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// c.execute_sql(sql!("INSERT INTO users (name, age) VALUES (?, ?)"), ("Alice",))?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_sql<const N: usize>(&self, sql: Sql<N>, bind: impl Parameters<N>) -> Result<()> {
+        let mut stmt = self.prepare(sql)?;
+        stmt.execute(bind)
+    }
+
+    /// Prepare and execute a single statement with the given parameters,
+    /// erroring unless it changed exactly `expected` rows.
+    ///
+    /// This is useful for optimistic concurrency control, where an update like
+    /// `UPDATE documents SET body = ?, version = version + 1 WHERE id = ? AND
+    /// version = ?` is expected to affect exactly one row, and affecting none
+    /// means that someone else modified the row first.
+    ///
+    /// The statement is executed inside of a savepoint, so if the number of
+    /// changes doesn't match the changes are rolled back. Since savepoints
+    /// nest, this can also be used inside of an already open transaction.
+    ///
+    /// Like [`changes`], rows changed by triggers are not counted.
+    ///
+    /// [`changes`]: Self::changes
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::ABORT`] if the number of changed rows does not
+    /// match `expected`.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE documents (id INTEGER PRIMARY KEY, body TEXT, version INTEGER);
+    ///
+    ///     INSERT INTO documents VALUES (1, 'Hello', 2);
+    /// "#)?;
+    ///
+    /// let update = "UPDATE documents SET body = ?, version = version + 1 WHERE id = ? AND version = ?";
+    ///
+    /// // Someone else already updated the document.
+    /// let e = c.execute_expecting(update, ("Hello World", 1, 1), 1).unwrap_err();
+    /// assert_eq!(e.code(), Code::ABORT);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE documents (id INTEGER PRIMARY KEY, body TEXT, version INTEGER);
+    ///
+    ///     INSERT INTO documents VALUES (1, 'Hello', 2);
+    /// "#)?;
+    ///
+    /// let update = "UPDATE documents SET body = ?, version = version + 1 WHERE id = ? AND version = ?";
+    /// c.execute_expecting(update, ("Hello World", 1, 2), 1)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT body, version FROM documents WHERE id = 1")?;
+    /// assert_eq!(stmt.next::<(String, i64)>()?, Some((String::from("Hello World"), 3)));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn execute_expecting(
+        &self,
+        stmt: impl AsRef<str>,
+        bind: impl Bind,
+        expected: usize,
+    ) -> Result<()> {
+        self.with_savepoint(savepoint!("sqll_execute_expecting"), || {
+            let mut stmt = self.prepare(stmt)?;
+            stmt.execute(bind)?;
+
+            let changes = self.changes();
+
+            if changes != expected {
+                return Err(Error::new(
+                    Code::ABORT,
+                    format_args!("expected {expected} changed rows but {changes} were changed"),
+                ));
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Set a callback for handling busy events.
+    ///
+    /// The callback is triggered when the database cannot perform an operation
+    /// due to processing of some other request. If the callback returns `true`,
+    /// the operation will be repeated.
+    ///
+    /// The busy callback should not take any actions which modify the database
+    /// connection that invoked the busy handler. In other words, the busy
+    /// handler is not reentrant. Any such actions result in undefined behavior.
+    ///
+    /// Since this needs to allocate space to store the closure the `alloc`
+    /// feature has to be enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// c.busy_handler(|attempts| {
+    ///     println!("busy attempt: {attempts}");
+    ///     attempts < 5
+    /// })?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn busy_handler<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: FnMut(usize) -> bool + Send + 'static,
+    {
+        extern "C" fn glue<F>(callback: *mut c_void, attempts: c_int) -> c_int
+        where
+            F: FnMut(usize) -> bool,
+        {
+            unsafe {
+                if (*(callback as *mut F))(attempts as usize) {
+                    1
+                } else {
+                    0
+                }
+            }
+        }
+
+        unsafe {
+            let callback = Owned::new(callback)?;
+
+            let result = ffi::sqlite3_busy_handler(
+                self.raw.as_ptr(),
+                Some(glue::<F>),
+                callback.as_ptr().cast(),
+            );
+
+            // NB: If registering the new callback failed, sqlite still refers
+            // to the old one so it must be kept alive. Otherwise the old
+            // callback is dropped and freed when we set the new one here.
+            sqlite3_try!(self, result);
+            self.busy_callback = Some(callback);
+            self.busy_timeout = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Set a fallible callback for handling busy events.
+    ///
+    /// This is like [`busy_handler`], except that the callback can abort the
+    /// blocked operation with a custom error. The callback receives the number
+    /// of times it has previously been invoked for the same busy event, and
+    /// returns `Ok(true)` to retry the operation or `Ok(false)` to fail it with
+    /// [`Code::BUSY`].
+    ///
+    /// If the callback returns an error, the blocked operation fails with that
+    /// error instead. This is only supported for operations which step
+    /// statements, such as [`Statement::step`] and [`execute`].
+    ///
+    /// The same reentrancy restrictions as for [`busy_handler`] apply.
+    ///
+    /// [`busy_handler`]: Self::busy_handler
+    /// [`execute`]: Self::execute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use sqll::{Code, Connection, Error};
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(5);
+    ///
+    /// c.try_busy_handler(move |_| {
+    ///     if Instant::now() >= deadline {
+    ///         return Err(Error::new(Code::BUSY_TIMEOUT, "deadline exceeded"));
+    ///     }
+    ///
+    ///     std::thread::sleep(Duration::from_millis(10));
+    ///     Ok(true)
+    /// })?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    pub fn try_busy_handler<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: FnMut(usize) -> Result<bool> + Send + 'static,
+    {
+        struct Handler<F> {
+            db: *mut ffi::sqlite3,
+            callback: F,
+        }
+
+        extern "C" fn glue<F>(handler: *mut c_void, attempts: c_int) -> c_int
+        where
+            F: FnMut(usize) -> Result<bool>,
+        {
+            unsafe {
+                let handler = &mut *(handler as *mut Handler<F>);
+                busy::clear_aborted(handler.db);
+
+                match (handler.callback)(attempts as usize) {
+                    Ok(true) => 1,
+                    Ok(false) => 0,
+                    Err(error) => {
+                        busy::set_aborted(handler.db, error);
+                        0
+                    }
+                }
+            }
+        }
+
+        unsafe {
+            let callback = Owned::new(Handler {
+                db: self.raw.as_ptr(),
+                callback,
+            })?;
+
+            let result = ffi::sqlite3_busy_handler(
+                self.raw.as_ptr(),
+                Some(glue::<F>),
+                callback.as_ptr().cast(),
+            );
+
+            // NB: If registering the new callback failed, sqlite still refers
+            // to the old one so it must be kept alive. Otherwise the old
+            // callback is dropped and freed when we set the new one here.
+            sqlite3_try!(self, result);
+            self.busy_callback = Some(callback);
+            self.busy_timeout = 0;
+        }
+
+        Ok(())
+    }
+
+    /// Clear any previously registered busy handler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// c.busy_handler(|attempts| {
+    ///     println!("busy attempt: {attempts}");
+    ///     attempts < 5
+    /// })?;
+    ///
+    /// c.clear_busy_handler()?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn clear_busy_handler(&mut self) -> Result<()> {
+        unsafe {
+            sqlite3_try! {
+                self,
+                ffi::sqlite3_busy_handler(
+                    self.raw.as_ptr(),
+                    None,
+                    null_mut()
+                )
+            };
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            self.busy_callback = None;
+        }
+
+        self.busy_timeout = 0;
+        Ok(())
+    }
+
+    /// Set an implicit callback for handling busy events that tries to repeat
+    /// rejected operations until a timeout expires.
+    ///
+    /// A timeout of zero or less turns off all busy handlers. A busy timeout
+    /// can also be applied when the connection is opened with
+    /// [`OpenOptions::busy_timeout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// c.busy_timeout(5000)?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn busy_timeout(&mut self, ms: c_int) -> Result<()> {
+        unsafe {
+            sqlite3_try! {
+                self,
+                ffi::sqlite3_busy_timeout(
+                    self.raw.as_ptr(),
+                    ms
+                )
+            };
+        }
+
+        // NB: Setting a busy timeout replaces any busy handler.
+        #[cfg(feature = "alloc")]
+        {
             self.busy_callback = None;
         }
 
+        self.busy_timeout = ms.max(0);
+        Ok(())
+    }
+
+    /// Get the busy timeout in milliseconds which was set through
+    /// [`busy_timeout`], or `0` if there is none.
+    ///
+    /// Registering a busy handler or clearing it through
+    /// [`clear_busy_handler`] also clears the busy timeout.
+    ///
+    /// [`busy_timeout`]: Self::busy_timeout
+    /// [`clear_busy_handler`]: Self::clear_busy_handler
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    /// assert_eq!(c.get_busy_timeout(), 0);
+    ///
+    /// c.busy_timeout(5000)?;
+    /// assert_eq!(c.get_busy_timeout(), 5000);
+    ///
+    /// c.clear_busy_handler()?;
+    /// assert_eq!(c.get_busy_timeout(), 0);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn get_busy_timeout(&self) -> c_int {
+        self.busy_timeout
+    }
+
+    /// Set a callback which authorizes actions when statements are prepared.
+    ///
+    /// The callback is invoked while SQL is being compiled, once for each
+    /// action that the statement would perform, such as reading a column or
+    /// inserting into a table. It can allow the action with [`AuthResult::Ok`],
+    /// cause preparing the statement to fail using [`AuthResult::Deny`], or
+    /// selectively disallow the action using [`AuthResult::Ignore`].
+    ///
+    /// This is the mechanism to use when running untrusted SQL, since it can
+    /// prevent things like attaching databases or reading sensitive columns.
+    ///
+    /// Only a single authorizer can be set at a time, setting a new one
+    /// replaces the old one. Statements might be re-prepared when they are
+    /// stepped, which invokes the authorizer again.
+    ///
+    /// The callback should not take any actions which modify the database
+    /// connection, including preparing statements. Any such actions result in
+    /// undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{AuthKind, AuthResult, Code, Connection};
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, password TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 'secret');
+    /// "#)?;
+    ///
+    /// c.set_authorizer(|action| match action.kind() {
+    ///     AuthKind::Read { column, .. } if column == "password" => AuthResult::Ignore,
+    ///     AuthKind::Attach { .. } | AuthKind::Delete { .. } => AuthResult::Deny,
+    ///     _ => AuthResult::Ok,
+    /// })?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name, password FROM users")?;
+    /// assert_eq!(stmt.next::<(String, Option<String>)>()?, Some((String::from("Alice"), None)));
+    ///
+    /// let e = c.execute("DELETE FROM users").unwrap_err();
+    /// assert_eq!(e.code(), Code::AUTH);
+    ///
+    /// let e = c.execute("ATTACH DATABASE ':memory:' AS other").unwrap_err();
+    /// assert_eq!(e.code(), Code::AUTH);
+    ///
+    /// c.clear_authorizer()?;
+    /// c.execute("DELETE FROM users")?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn set_authorizer<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: FnMut(AuthAction<'_>) -> AuthResult + Send + 'static,
+    {
+        extern "C" fn glue<F>(
+            callback: *mut c_void,
+            code: c_int,
+            arg1: *const c_char,
+            arg2: *const c_char,
+            database: *const c_char,
+            accessor: *const c_char,
+        ) -> c_int
+        where
+            F: FnMut(AuthAction<'_>) -> AuthResult,
+        {
+            unsafe {
+                let action = AuthAction::from_raw(code, arg1, arg2, database, accessor);
+                (*(callback as *mut F))(action).raw()
+            }
+        }
+
+        unsafe {
+            let callback = Owned::new(callback)?;
+
+            let result = ffi::sqlite3_set_authorizer(
+                self.raw.as_ptr(),
+                Some(glue::<F>),
+                callback.as_ptr().cast(),
+            );
+
+            // NB: If registering the new callback failed, sqlite still refers
+            // to the old one so it must be kept alive.
+            sqlite3_try!(self, result);
+            self.authorizer_callback = Some(callback);
+        }
+
+        Ok(())
+    }
+
+    /// Clear any previously set authorizer.
+    ///
+    /// See [`set_authorizer`].
+    ///
+    /// [`set_authorizer`]: Self::set_authorizer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{AuthResult, Connection};
+    ///
+    /// let mut c = Connection::open_in_memory()?;
+    ///
+    /// c.set_authorizer(|_| AuthResult::Deny)?;
+    /// assert!(c.prepare("SELECT 1").is_err());
+    ///
+    /// c.clear_authorizer()?;
+    /// assert!(c.prepare("SELECT 1").is_ok());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn clear_authorizer(&mut self) -> Result<()> {
+        unsafe {
+            sqlite3_try! {
+                self,
+                ffi::sqlite3_set_authorizer(
+                    self.raw.as_ptr(),
+                    None,
+                    null_mut()
+                )
+            };
+        }
+
+        self.authorizer_callback = None;
+        Ok(())
+    }
+
+    /// Register a custom scalar function.
+    ///
+    /// The function is registered with the given `name` and takes the number
+    /// of arguments specified by `arity`. The callback
+    /// is called once for each invocation of the function, and errors returned
+    /// from it are propagated as errors to the statement using the function.
+    ///
+    /// The `flags` argument specifies how the function may be used. In
+    /// particular a function has to be [`FunctionFlags::DETERMINISTIC`] for it
+    /// to be usable in indexes and generated columns. Use
+    /// [`FunctionFlags::EMPTY`] for no flags.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the function could not be registered, such as if `arity` is
+    /// out of range.
+    ///
+    /// ```
+    /// use sqll::{Arity, Code, Connection, FunctionFlags};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// let e = c.create_scalar_function(c"nothing", Arity::Exact(u16::MAX), FunctionFlags::EMPTY, |_| Ok(0)).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Arity, Connection, FunctionFlags};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.create_scalar_function(c"double", Arity::Exact(1), FunctionFlags::DETERMINISTIC, |args| {
+    ///     Ok(args.get(0).and_then(|v| v.as_integer()).map(|v| v * 2))
+    /// })?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE numbers (value INTEGER);
+    ///
+    ///     INSERT INTO numbers VALUES (1), (2), (NULL);
+    ///
+    ///     CREATE INDEX numbers_double ON numbers (double(value));
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT double(value) FROM numbers")?;
+    /// let values = stmt.iter::<Option<i64>>().collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(values, [Some(2), Some(4), None]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn create_scalar_function<F, T>(
+        &self,
+        name: impl AsRef<CStr>,
+        arity: Arity,
+        flags: FunctionFlags,
+        f: F,
+    ) -> Result<()>
+    where
+        F: Fn(&Arguments<'_>) -> Result<T> + Send + 'static,
+        T: ReturnValue,
+    {
+        unsafe {
+            sqlite3_try! {
+                self,
+                function::create_scalar_function(
+                    self.raw.as_ptr(),
+                    name.as_ref().as_ptr(),
+                    arity.raw(),
+                    flags.raw(),
+                    f,
+                )
+            };
+        }
+
         Ok(())
     }
 
-    /// Set an implicit callback for handling busy events that tries to repeat
-    /// rejected operations until a timeout expires.
+    /// Register a custom window function.
+    ///
+    /// The function is registered with the given `name` and takes the number
+    /// of arguments specified by `arity`. Each time the
+    /// function is used, a new aggregate is constructed using `init` which is
+    /// then driven through the [`WindowAggregate`] trait.
+    ///
+    /// Window functions can be used both as regular aggregate functions and
+    /// with an `OVER (...)` clause. Errors returned from the aggregate are
+    /// propagated as errors to the statement using the function.
+    ///
+    /// The `flags` argument specifies how the function may be used, like with
+    /// [`create_scalar_function`]. Use [`FunctionFlags::EMPTY`] for no flags.
+    ///
+    /// [`create_scalar_function`]: Self::create_scalar_function
+    ///
+    /// # Errors
+    ///
+    /// Errors if the function could not be registered, such as if `arity` is
+    /// out of range.
+    ///
+    /// ```
+    /// use sqll::{Arguments, Arity, Code, Connection, FunctionFlags, Result, WindowAggregate};
+    ///
+    /// #[derive(Default)]
+    /// struct Nothing;
+    ///
+    /// impl WindowAggregate for Nothing {
+    ///     type Output = i64;
+    ///
+    ///     fn step(&mut self, _: &Arguments<'_>) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn inverse(&mut self, _: &Arguments<'_>) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn value(&self) -> Result<i64> {
+    ///         Ok(0)
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// let e = c.create_window_function(c"nothing", Arity::Exact(u16::MAX), FunctionFlags::EMPTY, Nothing::default).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
     ///
     /// # Examples
     ///
     /// ```
-    /// use sqll::Connection;
+    /// use sqll::{Arguments, Arity, Connection, FunctionFlags, Result, WindowAggregate};
     ///
-    /// let mut c = Connection::open_in_memory()?;
+    /// #[derive(Default)]
+    /// struct Sum(i64);
     ///
-    /// c.busy_timeout(5000)?;
+    /// impl WindowAggregate for Sum {
+    ///     type Output = i64;
+    ///
+    ///     fn step(&mut self, args: &Arguments<'_>) -> Result<()> {
+    ///         self.0 += args.get(0).and_then(|v| v.as_integer()).unwrap_or(0);
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn inverse(&mut self, args: &Arguments<'_>) -> Result<()> {
+    ///         self.0 -= args.get(0).and_then(|v| v.as_integer()).unwrap_or(0);
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn value(&self) -> Result<i64> {
+    ///         Ok(self.0)
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// c.create_window_function(c"windowed_sum", Arity::Exact(1), FunctionFlags::DETERMINISTIC, Sum::default)?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE numbers (value INTEGER);
+    ///
+    ///     INSERT INTO numbers VALUES (1), (2), (3), (4), (5);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare(r#"
+    ///     SELECT windowed_sum(value) OVER (
+    ///         ORDER BY value ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+    ///     )
+    ///     FROM numbers
+    /// "#)?;
+    ///
+    /// let sums = stmt.iter::<i64>().collect::<Result<Vec<_>>>()?;
+    /// assert_eq!(sums, [3, 6, 9, 12, 9]);
+    ///
+    /// let mut stmt = c.prepare("SELECT windowed_sum(value) FROM numbers")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(15));
     /// # Ok::<_, sqll::Error>(())
     /// ```
-    #[inline]
-    pub fn busy_timeout(&mut self, ms: c_int) -> Result<()> {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn create_window_function<F, A>(
+        &self,
+        name: impl AsRef<CStr>,
+        arity: Arity,
+        flags: FunctionFlags,
+        init: F,
+    ) -> Result<()>
+    where
+        F: Fn() -> A + Send + 'static,
+        A: WindowAggregate,
+    {
         unsafe {
             sqlite3_try! {
                 self,
-                ffi::sqlite3_busy_timeout(
+                function::create_window_function(
                     self.raw.as_ptr(),
-                    ms
+                    name.as_ref().as_ptr(),
+                    arity.raw(),
+                    flags.raw(),
+                    init,
                 )
             };
         }
 
         Ok(())
     }
+
+    /// Register a read-only eponymous virtual table module.
+    ///
+    /// The table can be queried directly using the given `name`, and columns
+    /// declared as `HIDDEN` can be passed as arguments like a table-valued
+    /// function. See [`VirtualTable`] for more information.
+    ///
+    /// Registering a module with the same name as an existing one replaces it.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the module could not be registered. Errors from the table
+    /// itself are reported by the statements using it.
+    ///
+    /// ```
+    /// use core::ffi::{CStr, c_int};
+    ///
+    /// use sqll::{Arguments, Code, Connection, Context, Error, IndexInfo, Result};
+    /// use sqll::{VirtualCursor, VirtualTable};
+    ///
+    /// struct Broken;
+    ///
+    /// impl VirtualTable for Broken {
+    ///     type Cursor = Empty;
+    ///
+    ///     fn connect(&self) -> Result<&CStr> {
+    ///         Ok(c"CREATE TABLE x(value INTEGER)")
+    ///     }
+    ///
+    ///     fn best_index(&self, _: &mut IndexInfo<'_>) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn open(&self) -> Result<Empty> {
+    ///         Err(Error::new(Code::ABORT, "table is broken"))
+    ///     }
+    /// }
+    ///
+    /// struct Empty;
+    ///
+    /// impl VirtualCursor for Empty {
+    ///     fn filter(&mut self, _: c_int, _: &Arguments<'_>) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn next(&mut self) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn eof(&self) -> bool {
+    ///         true
+    ///     }
+    ///
+    ///     fn column(&self, _: &mut Context, _: c_int) -> Result<()> {
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn rowid(&self) -> Result<i64> {
+    ///         Ok(0)
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// c.create_module(c"broken", Broken)?;
+    ///
+    /// let e = c.execute("SELECT * FROM broken").unwrap_err();
+    /// assert_eq!(e, Code::ABORT);
+    /// assert_eq!(c.error_message(), "table is broken");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// See [`VirtualTable`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn create_module<T>(&self, name: impl AsRef<CStr>, table: T) -> Result<()>
+    where
+        T: VirtualTable,
+    {
+        unsafe {
+            sqlite3_try! {
+                self,
+                virtual_table::create_module(self.raw.as_ptr(), name.as_ref().as_ptr(), table)
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Run the given closure, retrying it with exponential backoff if it fails
+    /// because the database is busy or locked.
+    ///
+    /// This is the same as calling [`with_retry_timeout`] with a timeout of
+    /// five seconds.
+    ///
+    /// [`with_retry_timeout`]: Self::with_retry_timeout
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// c.with_retry(|c| {
+    ///     c.execute("INSERT INTO users VALUES ('Alice', 42)")
+    /// })?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    #[inline]
+    pub fn with_retry<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnMut(&Self) -> Result<T>,
+    {
+        self.with_retry_timeout(Duration::from_secs(5), f)
+    }
+
+    /// Run the given closure, retrying it with exponential backoff if it fails
+    /// with [`Code::BUSY`] or [`Code::LOCKED`], including their extended
+    /// codes.
+    ///
+    /// The closure is retried with a delay starting at one millisecond which
+    /// doubles for each attempt up to a maximum of 100 milliseconds. Once the
+    /// total time spent would exceed `timeout` the last error is returned.
+    ///
+    /// Any other error is returned immediately.
+    ///
+    /// # Transactions
+    ///
+    /// When used with transactions, the closure must encompass the *entire*
+    /// transaction. Retrying only a single statement which failed inside of a
+    /// transaction can lead to deadlocks, since the transaction might hold a
+    /// lock which prevents other connections from making progress. The closure
+    /// must also take care to roll back the transaction if it fails, otherwise
+    /// the retried `BEGIN` statement will fail since a transaction is already
+    /// in progress.
+    ///
+    /// Consider using `BEGIN IMMEDIATE` for transactions which write to the
+    /// database, since it acquires the write lock up front instead of failing
+    /// part way through the transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// c.with_retry_timeout(Duration::from_millis(500), |c| {
+    ///     c.execute("BEGIN IMMEDIATE")?;
+    ///
+    ///     let result = c.execute(r#"
+    ///         INSERT INTO users VALUES ('Alice', 42);
+    ///         INSERT INTO users VALUES ('Bob', 72);
+    ///     "#);
+    ///
+    ///     match result {
+    ///         Ok(()) => c.execute("COMMIT"),
+    ///         Err(e) => {
+    ///             c.execute("ROLLBACK")?;
+    ///             Err(e)
+    ///         }
+    ///     }
+    /// })?;
+    ///
+    /// let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(2));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    pub fn with_retry_timeout<F, T>(&self, timeout: Duration, mut f: F) -> Result<T>
+    where
+        F: FnMut(&Self) -> Result<T>,
+    {
+        const INITIAL_DELAY: Duration = Duration::from_millis(1);
+        const MAX_DELAY: Duration = Duration::from_millis(100);
+
+        let start = Instant::now();
+        let mut delay = INITIAL_DELAY;
+
+        loop {
+            let e = match f(self) {
+                Ok(value) => return Ok(value),
+                Err(e) => e,
+            };
+
+            if !matches!(e.code().base(), Code::BUSY | Code::LOCKED) {
+                return Err(e);
+            }
+
+            if start.elapsed() + delay > timeout {
+                return Err(e);
+            }
+
+            thread::sleep(delay);
+            delay = (delay * 2).min(MAX_DELAY);
+        }
+    }
 }
 
 impl fmt::Debug for Connection {
@@ -949,9 +3570,25 @@ impl fmt::Debug for Connection {
 
 impl Drop for Connection {
     #[inline]
-    #[allow(unused_must_use)]
     fn drop(&mut self) {
-        self.clear_busy_handler();
+        // NB: Statements which outlive the connection keep the database alive
+        // and can still invoke the busy handler, so it has to be unregistered
+        // before the callback is freed. If that fails the callback is leaked
+        // instead.
+        if self.clear_busy_handler().is_err() {
+            #[cfg(feature = "alloc")]
+            core::mem::forget(self.busy_callback.take());
+        }
+
+        // NB: Statements can be re-prepared when they are stepped, which
+        // invokes the authorizer, so the same applies to it.
+        #[cfg(feature = "alloc")]
+        if self.clear_authorizer().is_err() {
+            core::mem::forget(self.authorizer_callback.take());
+        }
+
+        #[cfg(feature = "std")]
+        busy::clear_aborted(self.raw.as_ptr());
 
         // Will close the connection unconditionally. The database will stay
         // alive until all associated prepared statements have been closed since
@@ -992,3 +3629,134 @@ impl fmt::Debug for SendConnection {
         self.inner.fmt(f)
     }
 }
+
+/// A unit of SQL text which can be prepared, either UTF-8 bytes or UTF-16
+/// characters.
+trait SqlUnit: Copy + PartialEq {
+    /// The null unit.
+    const NUL: Self;
+
+    /// The error message used when the SQL contains a null unit.
+    const NUL_MESSAGE: &'static str;
+
+    /// Prepare the first statement in `sql`, returning it together with the
+    /// number of units consumed. The statement is `None` if it's empty.
+    ///
+    /// The `start` offset is the position of `sql` in the batch being
+    /// executed, used for error reporting.
+    ///
+    /// # Safety
+    ///
+    /// The returned statement must be finalized by the caller.
+    unsafe fn prepare(
+        c: &Connection,
+        sql: &[Self],
+        start: usize,
+    ) -> Result<(Option<NonNull<ffi::sqlite3_stmt>>, usize)>;
+
+    /// Translate an error offset reported by sqlite, which is in bytes of the
+    /// UTF-8 form of `sql`, into an offset in units of `sql`.
+    #[cfg(feature = "bundled")]
+    fn offset(sql: &[Self], offset: usize) -> usize;
+}
+
+impl SqlUnit for u8 {
+    const NUL: Self = 0;
+    const NUL_MESSAGE: &'static str = "interior null byte in SQL";
+
+    unsafe fn prepare(
+        c: &Connection,
+        sql: &[Self],
+        start: usize,
+    ) -> Result<(Option<NonNull<ffi::sqlite3_stmt>>, usize)> {
+        unsafe {
+            let mut raw = MaybeUninit::uninit();
+            let mut rest = MaybeUninit::uninit();
+
+            let len = c_int::try_from(sql.len()).unwrap_or(c_int::MAX);
+
+            let code = ffi::sqlite3_prepare_v3(
+                c.raw.as_ptr(),
+                sql.as_ptr().cast(),
+                len,
+                0,
+                raw.as_mut_ptr(),
+                rest.as_mut_ptr(),
+            );
+
+            if code != ffi::SQLITE_OK {
+                return Err(c.prepare_error(code, sql, start));
+            }
+
+            let consumed = rest
+                .assume_init()
+                .cast::<u8>()
+                .offset_from_unsigned(sql.as_ptr());
+            Ok((NonNull::new(raw.assume_init()), consumed))
+        }
+    }
+
+    #[cfg(feature = "bundled")]
+    #[inline]
+    fn offset(_: &[Self], offset: usize) -> usize {
+        offset
+    }
+}
+
+impl SqlUnit for u16 {
+    const NUL: Self = 0;
+    const NUL_MESSAGE: &'static str = "interior null character in SQL";
+
+    unsafe fn prepare(
+        c: &Connection,
+        sql: &[Self],
+        start: usize,
+    ) -> Result<(Option<NonNull<ffi::sqlite3_stmt>>, usize)> {
+        unsafe {
+            let mut raw = MaybeUninit::uninit();
+            let mut rest = MaybeUninit::uninit();
+
+            // NB: The length is in bytes and must be even.
+            let len = c_int::try_from(sql.len().saturating_mul(2)).unwrap_or(c_int::MAX - 1);
+
+            let code = ffi::sqlite3_prepare16_v3(
+                c.raw.as_ptr(),
+                sql.as_ptr().cast(),
+                len,
+                0,
+                raw.as_mut_ptr(),
+                rest.as_mut_ptr(),
+            );
+
+            if code != ffi::SQLITE_OK {
+                return Err(c.prepare_error(code, sql, start));
+            }
+
+            let consumed = rest
+                .assume_init()
+                .cast::<u16>()
+                .offset_from_unsigned(sql.as_ptr());
+            Ok((NonNull::new(raw.assume_init()), consumed))
+        }
+    }
+
+    #[cfg(feature = "bundled")]
+    fn offset(sql: &[Self], offset: usize) -> usize {
+        let mut bytes = 0;
+        let mut units = 0;
+
+        // NB: sqlite doesn't translate unpaired surrogates consistently, so
+        // the offset is only approximate if the SQL contains any.
+        for c in char::decode_utf16(sql.iter().copied()) {
+            if bytes >= offset {
+                break;
+            }
+
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            bytes += c.len_utf8();
+            units += c.len_utf16();
+        }
+
+        units
+    }
+}
@@ -0,0 +1,124 @@
+use core::ffi::{CStr, c_uint};
+
+use crate::ffi;
+use crate::{Connection, Result};
+
+const READONLY: c_uint = ffi::SQLITE_DESERIALIZE_READONLY as c_uint;
+const RESIZEABLE: c_uint = ffi::SQLITE_DESERIALIZE_RESIZEABLE as c_uint;
+
+/// Options used when deserializing a database with
+/// [`Connection::deserialize`].
+///
+/// The deserialized data is always copied into memory allocated by sqlite,
+/// which takes ownership of it and frees it once the database is closed or
+/// replaced. Sqlite can't borrow memory owned by Rust, since prepared
+/// statements are allowed to outlive the [`Connection`] they were prepared
+/// from and the database would be freed out from under them.
+///
+/// By default the database is read-only.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, DeserializeOptions};
+///
+/// let dir = tempfile::tempdir()?;
+/// let path = dir.path().join("data.db");
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT);
+///
+///     INSERT INTO users VALUES ('Alice');
+/// "#)?;
+///
+/// c.execute(format!("VACUUM INTO '{}'", path.display()))?;
+/// let data = std::fs::read(&path)?;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// DeserializeOptions::new()
+///     .read_write()
+///     .resizeable()
+///     .deserialize(&c, c"main", &data)?;
+///
+/// c.execute("INSERT INTO users VALUES ('Bob')")?;
+///
+/// let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+/// assert_eq!(stmt.next::<i64>()?, Some(2));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DeserializeOptions {
+    raw: c_uint,
+}
+
+impl DeserializeOptions {
+    /// Construct default options, which deserializes a read-only database.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::DeserializeOptions;
+    ///
+    /// let options = DeserializeOptions::new();
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self { raw: READONLY }
+    }
+
+    /// The database is read-only, any attempt to modify it fails with
+    /// [`Code::READONLY`].
+    ///
+    /// This is the default.
+    ///
+    /// [`Code::READONLY`]: crate::Code::READONLY
+    #[inline]
+    pub fn read_only(&mut self) -> &mut Self {
+        self.raw |= READONLY;
+        self
+    }
+
+    /// The database can be modified.
+    ///
+    /// Unless [`resizeable`] is also set, the database can't grow beyond the
+    /// size of the deserialized data and attempting to do so fails with
+    /// [`Code::FULL`].
+    ///
+    /// [`resizeable`]: Self::resizeable
+    /// [`Code::FULL`]: crate::Code::FULL
+    #[inline]
+    pub fn read_write(&mut self) -> &mut Self {
+        self.raw &= !READONLY;
+        self
+    }
+
+    /// Allow sqlite to grow the database beyond the size of the deserialized
+    /// data.
+    ///
+    /// This has no effect unless the database is also [`read_write`].
+    ///
+    /// [`read_write`]: Self::read_write
+    #[inline]
+    pub fn resizeable(&mut self) -> &mut Self {
+        self.raw |= RESIZEABLE;
+        self
+    }
+
+    /// Deserialize `data` into the database with the given schema name of the
+    /// connection, such as `c"main"`.
+    ///
+    /// Any existing content of the database is discarded.
+    ///
+    /// # Errors
+    ///
+    /// Errors if there is no database with the given schema name, if the
+    /// connection is currently reading from or writing to it, or if the
+    /// memory for the copy can't be allocated.
+    #[inline]
+    pub fn deserialize(&self, c: &Connection, schema: &CStr, data: &[u8]) -> Result<()> {
+        c.deserialize_with(schema, data, self.raw)
+    }
+}
@@ -0,0 +1,220 @@
+use core::ffi::c_int;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
+
+#[cfg(feature = "alloc")]
+use crate::FromColumn;
+#[cfg(feature = "alloc")]
+use crate::ty::Type;
+use crate::{Code, Error, Result, Row, Statement, Value};
+
+/// A handle to the current row of a [`Statement`] whose columns are read as
+/// dynamic [`Value`]s.
+///
+/// This allows walking a row without knowing the types of its columns in
+/// advance, which is useful for dynamic tooling such as query explorers or
+/// generic exporters. `NULL` columns are represented as `None`.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, DynamicRow, Value};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER, photo BLOB);
+///
+///     INSERT INTO users VALUES ('Alice', 42, NULL);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name, age, photo FROM users")?;
+///
+/// while let Some(mut row) = stmt.next::<DynamicRow<'_>>()? {
+///     assert_eq!(row.len(), 3);
+///     assert_eq!(row.get_value(0)?, Some(Value::text("Alice")));
+///     assert_eq!(row.get_value(1)?, Some(Value::integer(42)));
+///     assert_eq!(row.get_value(2)?, None);
+/// }
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct DynamicRow<'stmt> {
+    stmt: &'stmt mut Statement,
+}
+
+impl<'stmt> DynamicRow<'stmt> {
+    /// Get the number of columns in the row.
+    ///
+    /// This is the same as [`Statement::column_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, DynamicRow};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1, 2, 3")?;
+    /// let row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    /// assert_eq!(row.len(), 3);
+    /// assert!(!row.is_empty());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.stmt.column_count().max(0) as usize
+    }
+
+    /// Test if the row has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, DynamicRow};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1")?;
+    /// let row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    /// assert!(!row.is_empty());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the value of the column at the given index, or `None` if it is
+    /// `NULL`.
+    ///
+    /// This is the same as calling [`Statement::column`] with
+    /// `Option<Value<'_>>`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::RANGE`] if the index is out of bounds.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection, DynamicRow};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1")?;
+    /// let mut row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    /// assert_eq!(row.get_value(1).unwrap_err().code(), Code::RANGE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, DynamicRow, Value};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 'Alice', 2.5, NULL")?;
+    /// let mut row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    /// assert_eq!(row.get_value(0)?, Some(Value::text("Alice")));
+    /// assert_eq!(row.get_value(1)?, Some(Value::float(2.5)));
+    /// assert_eq!(row.get_value(2)?, None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn get_value(&mut self, index: c_int) -> Result<Option<Value<'_>>> {
+        if !(0..self.stmt.column_count()).contains(&index) {
+            return Err(Error::new(
+                Code::RANGE,
+                format_args!("column index {index} out of range"),
+            ));
+        }
+
+        self.stmt.column(index)
+    }
+
+    /// Iterate over the values of all columns in the row, in order.
+    ///
+    /// `NULL` columns are yielded as `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, DynamicRow, Value};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 'Alice', 42, NULL, X'FF'")?;
+    /// let mut row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    ///
+    /// let values = row.iter_values().collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// let expected = [
+    ///     Some(Value::text("Alice")),
+    ///     Some(Value::integer(42)),
+    ///     None,
+    ///     Some(Value::blob(b"\xFF")),
+    /// ];
+    ///
+    /// assert_eq!(values, expected);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn iter_values(&mut self) -> Values<'_> {
+        let count = self.stmt.column_count().max(0);
+        let mut checked = Vec::with_capacity(count as usize);
+
+        // NB: Every column has to be checked before any of them are read,
+        // since checking requires exclusive access to the statement.
+        for index in 0..count {
+            checked.push(<Option<Value<'_>> as FromColumn<'_>>::Type::check(
+                self.stmt, index,
+            ));
+        }
+
+        Values {
+            stmt: self.stmt,
+            checked: checked.into_iter(),
+        }
+    }
+}
+
+/// [`Row`] implementation for [`DynamicRow`].
+///
+/// This doesn't read any columns by itself, but provides access to all of them
+/// through the returned handle.
+unsafe impl<'stmt> Row<'stmt> for DynamicRow<'stmt> {
+    #[inline]
+    fn from_row(stmt: &'stmt mut Statement) -> Result<Self> {
+        Ok(Self { stmt })
+    }
+}
+
+/// An iterator over the values in a [`DynamicRow`].
+///
+/// See [`DynamicRow::iter_values`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+pub struct Values<'a> {
+    stmt: &'a Statement,
+    checked: vec::IntoIter<Result<<Option<Value<'a>> as FromColumn<'a>>::Type>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> Iterator for Values<'a> {
+    type Item = Result<Option<Value<'a>>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let checked = self.checked.next()?;
+        Some(checked.and_then(|checked| Option::<Value<'a>>::from_column(self.stmt, checked)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.checked.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ExactSizeIterator for Values<'_> {}
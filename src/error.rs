@@ -18,6 +18,8 @@ pub struct Error {
     code: Code,
     #[cfg(feature = "alloc")]
     message: String,
+    #[cfg(feature = "bundled")]
+    offset: Option<usize>,
 }
 
 impl Error {
@@ -31,6 +33,8 @@ impl Error {
             code,
             #[cfg(feature = "alloc")]
             message: format!("{message}"),
+            #[cfg(feature = "bundled")]
+            offset: None,
         }
     }
 
@@ -41,10 +45,130 @@ impl Error {
     }
 
     /// The error code that caused this error.
+    ///
+    /// Note that an [`Error`] can also be compared directly to a [`Code`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT NOT NULL UNIQUE);
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
+    ///
+    /// let e = c.execute("INSERT INTO users VALUES ('Alice')").unwrap_err();
+    /// assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(e, Code::CONSTRAINT_UNIQUE);
+    /// assert_ne!(e, Code::CONSTRAINT);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
     #[inline]
     pub fn code(&self) -> Code {
         self.code
     }
+
+    /// The byte offset in the SQL where the error occured, if available.
+    ///
+    /// This is set when preparing a statement fails due to an error which can
+    /// be attributed to a particular position in the SQL, like a syntax error
+    /// or a reference to a missing column. When executing multiple statements
+    /// the offset is relative to the start of the whole batch.
+    ///
+    /// If the SQL was provided as UTF-16, such as through
+    /// [`Connection::prepare_utf16`], the offset is in UTF-16 units instead of
+    /// bytes.
+    ///
+    /// [`Connection::prepare_utf16`]: crate::Connection::prepare_utf16
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    /// "#)?;
+    ///
+    /// let sql = "SELECT name, age FROM users";
+    /// let e = c.prepare(sql).unwrap_err();
+    /// assert_eq!(e.code(), Code::ERROR);
+    /// assert_eq!(e.offset(), Some(13));
+    /// assert_eq!(&sql[13..], "age FROM users");
+    ///
+    /// let sql = "INSERT INTO users VALUES ('Alice'); SELECT nme FROM users";
+    /// let e = c.execute(sql).unwrap_err();
+    /// assert_eq!(e.offset(), sql.find("nme"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "bundled")]
+    #[cfg_attr(docsrs, cfg(feature = "bundled"))]
+    #[inline]
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Set the byte offset in the SQL where the error occured.
+    #[cfg(feature = "bundled")]
+    #[inline]
+    pub(crate) fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// The message associated with the error.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Compare the [`code`] of an error to a [`Code`].
+///
+/// This compares the exact code, so an extended code like
+/// [`Code::CONSTRAINT_UNIQUE`] is not equal to [`Code::CONSTRAINT`]. Use
+/// [`Code::base`] to compare against the primary code.
+///
+/// [`code`]: Error::code
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let e = c.execute("SELECT * FROM missing").unwrap_err();
+/// assert_eq!(e, Code::ERROR);
+/// assert_eq!(Code::ERROR, e);
+/// assert!(e != Code::MISUSE);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl PartialEq<Code> for Error {
+    #[inline]
+    fn eq(&self, other: &Code) -> bool {
+        self.code == *other
+    }
+}
+
+/// Compare a [`Code`] to the [`code`] of an error.
+///
+/// See the [`PartialEq<Code>`] implementation for [`Error`].
+///
+/// [`code`]: Error::code
+impl PartialEq<Error> for Code {
+    #[inline]
+    fn eq(&self, other: &Error) -> bool {
+        *self == other.code
+    }
 }
 
 impl fmt::Debug for Error {
@@ -56,23 +180,57 @@ impl fmt::Debug for Error {
         st.field("message", &self.message);
         #[cfg(not(feature = "alloc"))]
         st.field("message", &self.code.message());
+        #[cfg(feature = "bundled")]
+        st.field("offset", &self.offset);
         st.finish()
     }
 }
 
-#[cfg(feature = "alloc")]
+/// Display the error.
+///
+/// This includes the numeric code, its name, and for extended codes the name
+/// of the base family it belongs to.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT UNIQUE);
+///
+///     INSERT INTO users VALUES ('Alice');
+/// "#)?;
+///
+/// let e = c.execute("INSERT INTO users VALUES ('Alice')").unwrap_err();
+/// assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+/// assert_eq!(
+///     e.to_string(),
+///     "sqlite3 error 2067 (CONSTRAINT_UNIQUE / CONSTRAINT): UNIQUE constraint failed: users.name"
+/// );
+///
+/// let e = c.execute("SELECT * FROM missing").unwrap_err();
+/// assert_eq!(e.to_string(), "sqlite3 error 1 (ERROR): no such table: missing");
+/// # Ok::<_, sqll::Error>(())
+/// ```
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.message.fmt(f)
-    }
-}
+        let base = self.code.base();
 
-#[cfg(not(feature = "alloc"))]
-impl fmt::Display for Error {
-    #[inline]
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.code.message().fmt(f)
+        write!(f, "sqlite3 error {} ({}", self.code.as_i32(), self.code)?;
+
+        if base != self.code {
+            write!(f, " / {base}")?;
+        }
+
+        #[cfg(feature = "alloc")]
+        write!(f, "): {}", self.message)?;
+        #[cfg(not(feature = "alloc"))]
+        write!(f, "): {}", self.code.message())?;
+        Ok(())
     }
 }
 
@@ -196,3 +354,28 @@ impl fmt::Debug for CapacityError {
 }
 
 impl core::error::Error for CapacityError {}
+
+/// Error raised when failing to parse a [`ValueType`] from a string.
+///
+/// [`ValueType`]: crate::ValueType
+///
+/// # Examples
+///
+/// ```
+/// use sqll::ValueType;
+///
+/// let e = "VARCHAR".parse::<ValueType>().unwrap_err();
+/// assert_eq!(e.to_string(), "unknown value type");
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ParseValueTypeError;
+
+impl fmt::Display for ParseValueTypeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown value type")
+    }
+}
+
+impl core::error::Error for ParseValueTypeError {}
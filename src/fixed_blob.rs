@@ -49,7 +49,7 @@ impl<const N: usize> FixedBlob<N> {
     /// let blob = FixedBlob::<5>::new();
     /// assert_eq!(blob.len(), 0);
     /// assert!(blob.is_empty());
-    /// assert_eq!(blob.as_slice(), &[]);
+    /// assert_eq!(blob.as_slice(), &[0u8; 0]);
     /// ```
     pub const fn new() -> Self {
         Self {
@@ -163,6 +163,145 @@ impl<const N: usize> FixedBlob<N> {
         // bytes that have been initialized.
         unsafe { slice::from_raw_parts(self.data.as_ptr() as *const u8, self.init) }
     }
+
+    /// Return the number of bytes which can still be appended before the
+    /// buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedBlob;
+    ///
+    /// let mut blob = FixedBlob::<4>::new();
+    /// assert_eq!(blob.remaining_capacity(), 4);
+    ///
+    /// blob.try_extend_from_slice(b"abc")?;
+    /// assert_eq!(blob.remaining_capacity(), 1);
+    /// # Ok::<_, sqll::CapacityError>(())
+    /// ```
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.init
+    }
+
+    /// Clear the buffer, removing all bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedBlob;
+    ///
+    /// let mut blob = FixedBlob::from(*b"abcd");
+    /// blob.clear();
+    /// assert!(blob.is_empty());
+    /// assert_eq!(blob.remaining_capacity(), 4);
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.init = 0;
+    }
+
+    /// Append bytes to the end of the buffer.
+    ///
+    /// If the bytes do not fit in the remaining capacity, an error is returned
+    /// and the buffer is left unmodified.
+    ///
+    /// This makes it possible to assemble composite values on the stack before
+    /// binding them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, FixedBlob};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE keys (key BLOB);
+    /// "#)?;
+    ///
+    /// let mut key = FixedBlob::<8>::new();
+    /// key.try_extend_from_slice(&1u32.to_be_bytes())?;
+    /// key.try_extend_from_slice(&2u32.to_be_bytes())?;
+    /// assert!(key.try_extend_from_slice(&[3]).is_err());
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO keys (key) VALUES (?)")?;
+    /// stmt.execute(&key)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT key FROM keys")?;
+    /// assert_eq!(stmt.next::<FixedBlob<8>>()?, Some(key));
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    pub fn try_extend_from_slice(&mut self, bytes: &[u8]) -> Result<(), CapacityError> {
+        if bytes.len() > self.remaining_capacity() {
+            return Err(CapacityError::capacity(self.init + bytes.len(), N));
+        }
+
+        // SAFETY: We've checked that the bytes fit in the remaining capacity.
+        unsafe {
+            ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                self.as_mut_ptr().add(self.init),
+                bytes.len(),
+            );
+            self.set_len(self.init + bytes.len());
+        }
+
+        Ok(())
+    }
+
+    /// Borrow the underlying array if all bytes have been initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedBlob;
+    ///
+    /// let mut blob = FixedBlob::<4>::new();
+    /// blob.try_extend_from_slice(b"abc")?;
+    /// assert_eq!(blob.as_array(), None);
+    ///
+    /// blob.try_extend_from_slice(b"d")?;
+    /// assert_eq!(blob.as_array(), Some(b"abcd"));
+    /// # Ok::<_, sqll::CapacityError>(())
+    /// ```
+    #[inline]
+    pub fn as_array(&self) -> Option<&[u8; N]> {
+        if self.init != N {
+            return None;
+        }
+
+        // SAFETY: All of the bytes in the sequence have been initialized.
+        unsafe { Some(&*self.data.as_ptr().cast::<[u8; N]>()) }
+    }
+
+    /// Mutably borrow the underlying array if all bytes have been initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedBlob;
+    ///
+    /// let mut blob = FixedBlob::from(*b"abcd");
+    ///
+    /// if let Some(array) = blob.as_array_mut() {
+    ///     array[0] = b'x';
+    /// }
+    ///
+    /// assert_eq!(blob.as_slice(), b"xbcd");
+    ///
+    /// blob.clear();
+    /// assert_eq!(blob.as_array_mut(), None);
+    /// ```
+    #[inline]
+    pub fn as_array_mut(&mut self) -> Option<&mut [u8; N]> {
+        if self.init != N {
+            return None;
+        }
+
+        // SAFETY: All of the bytes in the sequence have been initialized.
+        unsafe { Some(&mut *self.data.as_mut_ptr().cast::<[u8; N]>()) }
+    }
 }
 
 /// Coerce into a byte slice.
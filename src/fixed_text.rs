@@ -93,6 +93,46 @@ impl<const N: usize> FixedText<N> {
     pub fn as_text(&self) -> &Text {
         Text::new(self.inner.as_slice())
     }
+
+    /// Clear the buffer, removing all text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedText;
+    ///
+    /// let mut s = FixedText::<5>::try_from("Hello")?;
+    /// s.clear();
+    /// assert_eq!(s.as_text(), "");
+    /// # Ok::<_, sqll::CapacityError>(())
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Append a string to the end of the buffer.
+    ///
+    /// If the string does not fit in the remaining capacity, an error is
+    /// returned and the buffer is left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::FixedText;
+    ///
+    /// let mut s = FixedText::<11>::new();
+    /// s.try_push_str("Hello")?;
+    /// s.try_push_str(" World")?;
+    /// assert_eq!(s.as_text(), "Hello World");
+    /// assert!(s.try_push_str("!").is_err());
+    /// assert_eq!(s.as_text(), "Hello World");
+    /// # Ok::<_, sqll::CapacityError>(())
+    /// ```
+    #[inline]
+    pub fn try_push_str(&mut self, string: &str) -> Result<(), CapacityError> {
+        self.inner.try_extend_from_slice(string.as_bytes())
+    }
 }
 
 /// Deref to `Text`.
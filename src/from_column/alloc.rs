@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 
@@ -117,3 +118,73 @@ impl FromColumn<'_> for Vec<u8> {
         Ok(buf)
     }
 }
+
+/// [`FromColumn`] implementation which returns a newly allocated [`Box<str>`].
+///
+/// Exactly as many bytes as the column contains are allocated, so unlike
+/// [`String`] the value never carries spare capacity. This is useful for values
+/// which are kept around for a long time.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT);
+///
+///     INSERT INTO users (name) VALUES ('Alice'), ('Bob');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name FROM users")?;
+///
+/// assert_eq!(stmt.next::<Box<str>>()?.as_deref(), Some("Alice"));
+/// assert_eq!(stmt.next::<Box<str>>()?.as_deref(), Some("Bob"));
+/// assert_eq!(stmt.next::<Box<str>>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl FromColumn<'_> for Box<str> {
+    type Type = ty::Text;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Text) -> Result<Self> {
+        Ok(String::from_column(stmt, index)?.into_boxed_str())
+    }
+}
+
+/// [`FromColumn`] implementation which returns a newly allocated [`Box<[u8]>`].
+///
+/// Exactly as many bytes as the column contains are allocated, so unlike
+/// [`Vec`] the value never carries spare capacity. This is useful for values
+/// which are kept around for a long time.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (blob BLOB);
+///
+///     INSERT INTO users (blob) VALUES (X'aabb'), (X'bbcc');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT blob FROM users")?;
+///
+/// assert_eq!(stmt.next::<Box<[u8]>>()?.as_deref(), Some(&[0xaa, 0xbb][..]));
+/// assert_eq!(stmt.next::<Box<[u8]>>()?.as_deref(), Some(&[0xbb, 0xcc][..]));
+/// assert_eq!(stmt.next::<Box<[u8]>>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl FromColumn<'_> for Box<[u8]> {
+    type Type = ty::Blob;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Blob) -> Result<Self> {
+        Ok(Vec::from_column(stmt, index)?.into_boxed_slice())
+    }
+}
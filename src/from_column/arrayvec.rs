@@ -0,0 +1,57 @@
+use arrayvec::ArrayVec;
+
+use crate::ty;
+use crate::{CapacityError, Code, Error, FromUnsizedColumn, Result, Statement};
+
+use super::FromColumn;
+
+/// [`FromColumn`] implementation for [`arrayvec::ArrayVec`] which reads at
+/// most `N` bytes from a `BLOB` column.
+///
+/// If the column contains more than `N` bytes, a [`Code::MISMATCH`] error is
+/// returned.
+///
+/// # Examples
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (id BLOB);
+///
+///     INSERT INTO users (id) VALUES (X'01020304'), (X'0506070809');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM users")?;
+///
+/// assert!(stmt.step()?.is_row());
+/// let bytes = stmt.column::<ArrayVec<u8, 4>>(0)?;
+/// assert_eq!(bytes.as_slice(), &[1, 2, 3, 4]);
+///
+/// assert!(stmt.step()?.is_row());
+/// let e = stmt.column::<ArrayVec<u8, 4>>(0).unwrap_err();
+/// assert_eq!(e, Code::MISMATCH);
+///
+/// let bytes = stmt.column::<ArrayVec<u8, 5>>(0)?;
+/// assert_eq!(bytes.as_slice(), &[5, 6, 7, 8, 9]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<const N: usize> FromColumn<'_> for ArrayVec<u8, N> {
+    type Type = ty::Blob;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Blob) -> Result<Self> {
+        let bytes = <[u8]>::from_unsized_column(stmt, index)?;
+
+        match ArrayVec::try_from(bytes) {
+            Ok(bytes) => Ok(bytes),
+            Err(..) => Err(Error::new(
+                Code::MISMATCH,
+                CapacityError::capacity(bytes.len(), N),
+            )),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use rust_decimal::Decimal;
+
+use crate::ty;
+use crate::{Code, Error, FromUnsizedColumn, Result, Statement};
+
+use super::FromColumn;
+
+/// [`FromColumn`] implementation for [`rust_decimal::Decimal`].
+///
+/// The decimal is parsed from a `TEXT` column, which is how it is stored when
+/// bound. Text which can't be represented exactly as a decimal results in a
+/// [`Code::MISMATCH`] error.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE prices (amount TEXT);
+///
+///     INSERT INTO prices (amount) VALUES ('0.1'), ('not a number');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT amount FROM prices")?;
+///
+/// assert_eq!(stmt.next::<Decimal>()?, Some(Decimal::new(1, 1)));
+///
+/// let e = stmt.next::<Decimal>().unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl FromColumn<'_> for Decimal {
+    type Type = ty::Text;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Text) -> Result<Self> {
+        let text = str::from_unsized_column(stmt, index)?;
+
+        match Decimal::from_str_exact(text) {
+            Ok(value) => Ok(value),
+            Err(error) => Err(Error::new(
+                Code::MISMATCH,
+                format_args!("text {text:?} cannot be parsed as a decimal: {error}"),
+            )),
+        }
+    }
+}
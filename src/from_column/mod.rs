@@ -1,5 +1,13 @@
 #[cfg(feature = "alloc")]
 mod alloc;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "std")]
+mod std;
 
 use crate::ffi;
 use crate::ty::{self, AnyKind, NotNull, Type};
@@ -0,0 +1,51 @@
+use smallvec::SmallVec;
+
+use crate::ty;
+use crate::{FromUnsizedColumn, Result, Statement};
+
+use super::FromColumn;
+
+/// [`FromColumn`] implementation for [`smallvec::SmallVec`] which reads a
+/// `BLOB` column.
+///
+/// Blobs of up to `N` bytes are stored inline, larger blobs spill over to the
+/// heap.
+///
+/// # Examples
+///
+/// ```
+/// use smallvec::SmallVec;
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (id BLOB);
+///
+///     INSERT INTO users (id) VALUES (X'01020304'), (X'0506070809'), (1);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM users")?;
+///
+/// let bytes = stmt.next::<SmallVec<[u8; 4]>>()?.unwrap();
+/// assert_eq!(bytes.as_slice(), &[1, 2, 3, 4]);
+/// assert!(!bytes.spilled());
+///
+/// let bytes = stmt.next::<SmallVec<[u8; 4]>>()?.unwrap();
+/// assert_eq!(bytes.as_slice(), &[5, 6, 7, 8, 9]);
+/// assert!(bytes.spilled());
+///
+/// let e = stmt.next::<SmallVec<[u8; 4]>>().unwrap_err();
+/// assert_eq!(e, Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<const N: usize> FromColumn<'_> for SmallVec<[u8; N]> {
+    type Type = ty::Blob;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Blob) -> Result<Self> {
+        Ok(SmallVec::from_slice(<[u8]>::from_unsized_column(
+            stmt, index,
+        )?))
+    }
+}
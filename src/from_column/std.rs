@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use crate::ty;
+use crate::{FromUnsizedColumn, Result, Statement};
+
+use super::FromColumn;
+
+/// [`FromColumn`] implementation which reads a text column into a newly
+/// allocated [`PathBuf`].
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (path TEXT);
+///
+///     INSERT INTO files (path) VALUES ('src/lib.rs'), ('README.md');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT path FROM files")?;
+///
+/// assert_eq!(stmt.next::<PathBuf>()?, Some(PathBuf::from("src/lib.rs")));
+/// assert_eq!(stmt.next::<PathBuf>()?, Some(PathBuf::from("README.md")));
+/// assert_eq!(stmt.next::<PathBuf>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Automatic conversion being denied:
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use sqll::{Connection, Code};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (id INTEGER);
+///
+///     INSERT INTO files (id) VALUES (1), (2);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT id FROM files")?;
+///
+/// let e = stmt.next::<PathBuf>().unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl FromColumn<'_> for PathBuf {
+    type Type = ty::Text;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Text) -> Result<Self> {
+        Ok(PathBuf::from(str::from_unsized_column(stmt, index)?))
+    }
+}
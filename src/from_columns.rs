@@ -0,0 +1,89 @@
+use core::ffi::c_int;
+
+use crate::ty::Type;
+use crate::utils::repeat;
+use crate::{Code, Error, FromColumn, Result, Statement};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A tuple of values which are read from consecutive columns.
+///
+/// This is implemented for tuples of [`FromColumn`] values, and allows a subset
+/// of a row to be read starting at an arbitrary column through
+/// [`Statement::get_tuple`].
+///
+/// Like [`Row`] for tuples, every column is checked before any values are
+/// loaded to ensure that borrowed values remain valid.
+///
+/// [`Row`]: crate::Row
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE points (name TEXT, x INTEGER, y INTEGER);
+///
+///     INSERT INTO points VALUES ('origin', 0, 0), ('unit', 1, 1);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name, x, y FROM points ORDER BY name")?;
+///
+/// assert!(stmt.step()?.is_row());
+/// assert_eq!(stmt.get_tuple::<(i64, i64)>(1)?, (0, 0));
+///
+/// assert!(stmt.step()?.is_row());
+/// assert_eq!(stmt.get_tuple::<(&str, i64)>(0)?, ("unit", 1));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub trait FromColumns<'stmt>
+where
+    Self: self::sealed::Sealed + Sized,
+{
+    /// Read the tuple from consecutive columns, starting at `start`.
+    fn from_columns(stmt: &'stmt mut Statement, start: c_int) -> Result<Self>;
+}
+
+macro_rules! implement_tuple {
+    ($ty0:ident $var0:ident $value0:literal $value1:literal $(, $ty:ident $var:ident $value0n:literal $value1n:literal)* $(,)? ) => {
+        impl<$ty0, $($ty,)*> self::sealed::Sealed for ($ty0, $($ty,)*) {}
+
+        /// [`FromColumns`] implementation for a tuple.
+        ///
+        /// A tuple reads elements one after another, starting at the given
+        /// index.
+        impl<'stmt, $ty0, $($ty,)*> FromColumns<'stmt> for ($ty0, $($ty,)*)
+        where
+            $ty0: FromColumn<'stmt>,
+            $($ty: FromColumn<'stmt>,)*
+        {
+            #[inline]
+            fn from_columns(stmt: &'stmt mut Statement, start: c_int) -> Result<Self> {
+                let $var0 = <$ty0>::Type::check(stmt, index(start, $value0)?)?;
+                $(let $var = <$ty>::Type::check(stmt, index(start, $value0n)?)?;)*
+                let $var0 = <$ty0>::from_column(stmt, $var0)?;
+                $(let $var = <$ty>::from_column(stmt, $var)?;)*
+                Ok(($var0, $($var,)*))
+            }
+        }
+    };
+}
+
+repeat!(implement_tuple);
+
+/// Compute the index of a column relative to `start`.
+#[inline]
+fn index(start: c_int, offset: c_int) -> Result<c_int> {
+    match start.checked_add(offset) {
+        Some(index) => Ok(index),
+        None => Err(Error::new(
+            Code::RANGE,
+            format_args!("column index {start} + {offset} is out of range"),
+        )),
+    }
+}
@@ -0,0 +1,796 @@
+use core::ffi::{c_int, c_void};
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::ops::BitOr;
+use core::ptr::NonNull;
+use core::slice;
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::bytes;
+use crate::ffi;
+use crate::value::Kind;
+use crate::{Code, Error, FixedBlob, FixedText, Null, Result, Text, Value, ValueType};
+
+/// A collection of flags used when registering a custom function.
+///
+/// Flags can be combined using the `|` operator.
+///
+/// See [`Connection::create_scalar_function`].
+///
+/// [`Connection::create_scalar_function`]: crate::Connection::create_scalar_function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FunctionFlags(c_int);
+
+impl FunctionFlags {
+    /// No flags.
+    ///
+    /// This provides the default behavior when registering a function.
+    pub const EMPTY: Self = Self(0);
+
+    /// The DETERMINISTIC flag means that the function always gives the same
+    /// output when the input parameters are the same.
+    ///
+    /// This allows the query planner to perform additional optimizations, and
+    /// is required for a function to be used in the expressions of indexes,
+    /// the WHERE clause of partial indexes, and generated columns.
+    pub const DETERMINISTIC: Self = Self(ffi::SQLITE_DETERMINISTIC);
+
+    /// The DIRECTONLY flag means that the function may only be invoked from
+    /// top-level SQL, and cannot be used in views, triggers, or schema
+    /// structures such as check constraints, default clauses, expression
+    /// indexes, partial indexes, or generated columns.
+    ///
+    /// This is recommended for any function which has side-effects or reveals
+    /// internal application state.
+    pub const DIRECTONLY: Self = Self(ffi::SQLITE_DIRECTONLY);
+
+    /// The INNOCUOUS flag means that the function is unlikely to cause
+    /// problems even if misused. An innocuous function should have no side
+    /// effects and should not depend on any values other than its input
+    /// parameters.
+    pub const INNOCUOUS: Self = Self(ffi::SQLITE_INNOCUOUS);
+
+    #[inline]
+    pub(crate) fn raw(self) -> c_int {
+        self.0
+    }
+}
+
+impl Default for FunctionFlags {
+    #[inline]
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl BitOr for FunctionFlags {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// The number of arguments accepted by a custom function.
+///
+/// See [`Connection::create_scalar_function`] and
+/// [`Connection::create_window_function`].
+///
+/// [`Connection::create_scalar_function`]: crate::Connection::create_scalar_function
+/// [`Connection::create_window_function`]: crate::Connection::create_window_function
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Arity, Connection, FunctionFlags};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.create_scalar_function(c"count_args", Arity::Variadic, FunctionFlags::EMPTY, |args| {
+///     Ok(args.len() as i64)
+/// })?;
+///
+/// let mut stmt = c.prepare("SELECT count_args(), count_args(1, 2, 3)")?;
+/// assert_eq!(stmt.next::<(i64, i64)>()?, Some((0, 3)));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Arity {
+    /// The function takes exactly the given number of arguments.
+    Exact(u16),
+    /// The function takes any number of arguments.
+    Variadic,
+}
+
+impl Arity {
+    #[inline]
+    pub(crate) fn raw(self) -> c_int {
+        match self {
+            Self::Exact(n) => c_int::from(n),
+            Self::Variadic => -1,
+        }
+    }
+}
+
+/// The context of a custom function invocation.
+///
+/// This is used by [`ReturnValue`] implementations to set the result of a
+/// function.
+pub struct Context {
+    raw: NonNull<ffi::sqlite3_context>,
+}
+
+impl Context {
+    /// Construct a context from a raw pointer.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the pointer is a valid context for the
+    /// duration of its use.
+    #[inline]
+    pub(crate) unsafe fn from_raw(raw: *mut ffi::sqlite3_context) -> Self {
+        Self {
+            raw: unsafe { NonNull::new_unchecked(raw) },
+        }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *mut ffi::sqlite3_context {
+        self.raw.as_ptr()
+    }
+
+    /// Report an error as the result of the function.
+    fn error(&mut self, error: &Error) {
+        let message = error.message();
+        let len = c_int::try_from(message.len()).unwrap_or(c_int::MAX);
+
+        unsafe {
+            ffi::sqlite3_result_error(self.as_ptr(), message.as_ptr().cast(), len);
+
+            if error.code() != Code::ERROR {
+                ffi::sqlite3_result_error_code(self.as_ptr(), error.code().as_i32());
+            }
+        }
+    }
+
+    /// Set the result of the function, or report an error.
+    fn result(&mut self, value: Result<impl ReturnValue>) {
+        let result = match value {
+            Ok(value) => value.return_value(self),
+            Err(error) => Err(error),
+        };
+
+        if let Err(error) = result {
+            self.error(&error);
+        }
+    }
+}
+
+/// The arguments passed to a custom function.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Arguments, Arity, Connection, FunctionFlags, Result, Value, WindowAggregate};
+///
+/// #[derive(Default)]
+/// struct Concat(String);
+///
+/// impl WindowAggregate for Concat {
+///     type Output = String;
+///
+///     fn step(&mut self, args: &Arguments<'_>) -> Result<()> {
+///         if let Some(text) = args.get(0).and_then(|v| v.as_text()) {
+///             self.0.push_str(&text.to_string());
+///         }
+///
+///         Ok(())
+///     }
+///
+///     fn inverse(&mut self, args: &Arguments<'_>) -> Result<()> {
+///         if let Some(text) = args.get(0).and_then(|v| v.as_text()) {
+///             self.0.drain(..text.as_bytes().len());
+///         }
+///
+///         Ok(())
+///     }
+///
+///     fn value(&self) -> Result<String> {
+///         Ok(self.0.clone())
+///     }
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+/// c.create_window_function(c"concat", Arity::Exact(1), FunctionFlags::EMPTY, Concat::default)?;
+///
+/// c.execute(r#"
+///     CREATE TABLE letters (value TEXT);
+///
+///     INSERT INTO letters VALUES ('a'), ('b'), ('c');
+/// "#)?;
+///
+/// let mut stmt = c.prepare(r#"
+///     SELECT concat(value) OVER (ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) FROM letters
+/// "#)?;
+///
+/// let values = stmt.iter::<String>().collect::<Result<Vec<_>>>()?;
+/// assert_eq!(values, ["a", "ab", "bc"]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub struct Arguments<'a> {
+    values: &'a [*mut ffi::sqlite3_value],
+}
+
+impl<'a> Arguments<'a> {
+    /// Construct arguments from raw values.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `argv` points to `argc` valid values which
+    /// are alive for the lifetime `'a`.
+    #[inline]
+    pub(crate) unsafe fn from_raw(argc: c_int, argv: *mut *mut ffi::sqlite3_value) -> Self {
+        let values = match usize::try_from(argc) {
+            Ok(len) if len > 0 && !argv.is_null() => unsafe {
+                slice::from_raw_parts(argv.cast_const(), len)
+            },
+            _ => &[],
+        };
+
+        Self { values }
+    }
+
+    /// Return the number of arguments.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Test if there are no arguments.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Return the [`ValueType`] of the argument at the given index.
+    ///
+    /// Arguments which are out of bounds are reported as [`ValueType::NULL`].
+    #[inline]
+    pub fn value_type(&self, index: usize) -> ValueType {
+        match self.values.get(index) {
+            Some(&value) => unsafe { ValueType::new(ffi::sqlite3_value_type(value)) },
+            None => ValueType::NULL,
+        }
+    }
+
+    /// Get the argument at the given index.
+    ///
+    /// This returns `None` if the argument is `NULL` or if the index is out of
+    /// bounds.
+    pub fn get(&self, index: usize) -> Option<Value<'a>> {
+        let value = *self.values.get(index)?;
+
+        unsafe {
+            match ffi::sqlite3_value_type(value) {
+                ffi::SQLITE_INTEGER => Some(Value::integer(ffi::sqlite3_value_int64(value))),
+                ffi::SQLITE_FLOAT => Some(Value::float(ffi::sqlite3_value_double(value))),
+                ffi::SQLITE_TEXT => {
+                    let ptr = ffi::sqlite3_value_text(value);
+                    let bytes = value_bytes(value, ptr.cast());
+                    Some(Value::text(Text::new(bytes)))
+                }
+                ffi::SQLITE_BLOB => {
+                    let ptr = ffi::sqlite3_value_blob(value);
+                    Some(Value::blob(value_bytes(value, ptr.cast())))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Read the bytes of a value, must be called after the pointer has been
+/// fetched since that might cause a conversion.
+unsafe fn value_bytes<'a>(value: *mut ffi::sqlite3_value, ptr: *const u8) -> &'a [u8] {
+    unsafe {
+        let len = ffi::sqlite3_value_bytes(value);
+
+        match usize::try_from(len) {
+            Ok(len) if len > 0 && !ptr.is_null() => slice::from_raw_parts(ptr, len),
+            _ => &[],
+        }
+    }
+}
+
+/// A type suitable for being returned from a custom function.
+///
+/// See [`Connection::create_window_function`].
+///
+/// [`Connection::create_window_function`]: crate::Connection::create_window_function
+pub trait ReturnValue {
+    /// Set the result of a function to this value.
+    ///
+    /// Custom implementations tend to delegate to the appropriate interior
+    /// type they need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Arguments, Arity, Connection, Context, FunctionFlags, Result, ReturnValue, WindowAggregate};
+    ///
+    /// struct Money(i64);
+    ///
+    /// impl ReturnValue for Money {
+    ///     #[inline]
+    ///     fn return_value(&self, ctx: &mut Context) -> Result<()> {
+    ///         self.0.return_value(ctx)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Default)]
+    /// struct Total(i64);
+    ///
+    /// impl WindowAggregate for Total {
+    ///     type Output = Money;
+    ///
+    ///     fn step(&mut self, args: &Arguments<'_>) -> Result<()> {
+    ///         self.0 += args.get(0).and_then(|v| v.as_integer()).unwrap_or(0);
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn inverse(&mut self, args: &Arguments<'_>) -> Result<()> {
+    ///         self.0 -= args.get(0).and_then(|v| v.as_integer()).unwrap_or(0);
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn value(&self) -> Result<Money> {
+    ///         Ok(Money(self.0))
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    /// c.create_window_function(c"total", Arity::Exact(1), FunctionFlags::EMPTY, Total::default)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT total(value) FROM (SELECT 40 AS value UNION SELECT 2)")?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(42));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    fn return_value(&self, ctx: &mut Context) -> Result<()>;
+}
+
+impl<T> ReturnValue for &T
+where
+    T: ?Sized + ReturnValue,
+{
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        (**self).return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`Null`].
+impl ReturnValue for Null {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        unsafe {
+            ffi::sqlite3_result_null(ctx.as_ptr());
+        }
+
+        Ok(())
+    }
+}
+
+/// [`ReturnValue`] implementation for [`i64`].
+impl ReturnValue for i64 {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        unsafe {
+            ffi::sqlite3_result_int64(ctx.as_ptr(), *self as ffi::sqlite3_int64);
+        }
+
+        Ok(())
+    }
+}
+
+/// [`ReturnValue`] implementation for [`bool`].
+impl ReturnValue for bool {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        i64::from(*self).return_value(ctx)
+    }
+}
+
+macro_rules! lossless {
+    ($($ty:ty),*) => {
+        $(
+            #[doc = concat!("[`ReturnValue`] implementation for `", stringify!($ty), "`.")]
+            impl ReturnValue for $ty {
+                #[inline]
+                fn return_value(&self, ctx: &mut Context) -> Result<()> {
+                    i64::from(*self).return_value(ctx)
+                }
+            }
+        )*
+    };
+}
+
+lossless!(i8, i16, i32, u8, u16, u32);
+
+/// [`ReturnValue`] implementation for [`f64`].
+impl ReturnValue for f64 {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        unsafe {
+            ffi::sqlite3_result_double(ctx.as_ptr(), *self);
+        }
+
+        Ok(())
+    }
+}
+
+/// [`ReturnValue`] implementation for [`f32`].
+impl ReturnValue for f32 {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        f64::from(*self).return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`Text`].
+impl ReturnValue for Text {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        let (ptr, len, dealloc) = bytes::alloc(self.as_bytes())?;
+
+        unsafe {
+            ffi::sqlite3_result_text(ctx.as_ptr(), ptr.cast(), len, dealloc);
+        }
+
+        Ok(())
+    }
+}
+
+/// [`ReturnValue`] implementation for [`str`].
+impl ReturnValue for str {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        Text::new(self).return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`String`].
+impl ReturnValue for String {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        self.as_str().return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`FixedText`].
+impl<const N: usize> ReturnValue for FixedText<N> {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        self.as_text().return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for byte slices.
+impl ReturnValue for [u8] {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        let (ptr, len, dealloc) = bytes::alloc(self)?;
+
+        unsafe {
+            ffi::sqlite3_result_blob(ctx.as_ptr(), ptr, len, dealloc);
+        }
+
+        Ok(())
+    }
+}
+
+/// [`ReturnValue`] implementation for byte arrays.
+impl<const N: usize> ReturnValue for [u8; N] {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        self.as_slice().return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`Vec<u8>`].
+impl ReturnValue for Vec<u8> {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        self.as_slice().return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for [`FixedBlob`].
+impl<const N: usize> ReturnValue for FixedBlob<N> {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        self.as_slice().return_value(ctx)
+    }
+}
+
+/// [`ReturnValue`] implementation for a dynamic [`Value`].
+impl ReturnValue for Value<'_> {
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        match *self.kind() {
+            Kind::Blob(value) => value.return_value(ctx),
+            Kind::Float(value) => value.return_value(ctx),
+            Kind::Integer(value) => value.return_value(ctx),
+            Kind::Text(value) => value.return_value(ctx),
+        }
+    }
+}
+
+/// [`ReturnValue`] implementation for [`Option`].
+///
+/// If the option is `None`, returns a `NULL` value.
+impl<T> ReturnValue for Option<T>
+where
+    T: ReturnValue,
+{
+    #[inline]
+    fn return_value(&self, ctx: &mut Context) -> Result<()> {
+        match self {
+            Some(value) => value.return_value(ctx),
+            None => Null.return_value(ctx),
+        }
+    }
+}
+
+/// An aggregate which can be used as a window function.
+///
+/// See [`Connection::create_window_function`].
+///
+/// [`Connection::create_window_function`]: crate::Connection::create_window_function
+pub trait WindowAggregate {
+    /// The output of the aggregate.
+    type Output: ReturnValue;
+
+    /// Add a row to the current window.
+    fn step(&mut self, args: &Arguments<'_>) -> Result<()>;
+
+    /// Remove the oldest row from the current window.
+    ///
+    /// The arguments are the same as those which were passed to [`step`] when
+    /// the row was added.
+    ///
+    /// [`step`]: Self::step
+    fn inverse(&mut self, args: &Arguments<'_>) -> Result<()>;
+
+    /// Return the current value of the aggregate.
+    fn value(&self) -> Result<Self::Output>;
+
+    /// Return the final value of the aggregate.
+    ///
+    /// By default this calls [`value`].
+    ///
+    /// [`value`]: Self::value
+    #[inline]
+    fn finalize(self) -> Result<Self::Output>
+    where
+        Self: Sized,
+    {
+        self.value()
+    }
+}
+
+/// The application data associated with a window function.
+struct Window<F, A> {
+    init: F,
+    _marker: PhantomData<fn() -> A>,
+}
+
+/// Access the aggregate state of the window function, initializing it if
+/// needed.
+///
+/// # Safety
+///
+/// The context must be a valid window function context where the user data is
+/// a `Window<F, A>`.
+unsafe fn aggregate<'a, F, A>(ctx: *mut ffi::sqlite3_context) -> Option<&'a mut A>
+where
+    F: Fn() -> A,
+{
+    unsafe {
+        let slot = ffi::sqlite3_aggregate_context(ctx, size_of::<*mut A>() as c_int);
+
+        if slot.is_null() {
+            ffi::sqlite3_result_error_nomem(ctx);
+            return None;
+        }
+
+        // NB: sqlite zero-initializes the aggregate context on first use.
+        let slot = slot.cast::<*mut A>();
+
+        if (*slot).is_null() {
+            let window = &*ffi::sqlite3_user_data(ctx).cast::<Window<F, A>>();
+            *slot = Box::into_raw(Box::new((window.init)()));
+        }
+
+        Some(&mut **slot)
+    }
+}
+
+/// Take the aggregate state of the window function, initializing a new one if
+/// none exists.
+///
+/// # Safety
+///
+/// The context must be a valid window function context where the user data is
+/// a `Window<F, A>`.
+unsafe fn take_aggregate<F, A>(ctx: *mut ffi::sqlite3_context) -> A
+where
+    F: Fn() -> A,
+{
+    unsafe {
+        let slot = ffi::sqlite3_aggregate_context(ctx, 0).cast::<*mut A>();
+
+        if !slot.is_null() && !(*slot).is_null() {
+            let state = Box::from_raw(*slot);
+            *slot = core::ptr::null_mut();
+            return *state;
+        }
+
+        let window = &*ffi::sqlite3_user_data(ctx).cast::<Window<F, A>>();
+        (window.init)()
+    }
+}
+
+pub(crate) unsafe fn create_scalar_function<F, T>(
+    db: *mut ffi::sqlite3,
+    name: *const core::ffi::c_char,
+    n_arg: c_int,
+    flags: c_int,
+    f: F,
+) -> c_int
+where
+    F: Fn(&Arguments<'_>) -> Result<T> + Send + 'static,
+    T: ReturnValue,
+{
+    unsafe extern "C" fn call<F, T>(
+        ctx: *mut ffi::sqlite3_context,
+        argc: c_int,
+        argv: *mut *mut ffi::sqlite3_value,
+    ) where
+        F: Fn(&Arguments<'_>) -> Result<T>,
+        T: ReturnValue,
+    {
+        unsafe {
+            let f = &*ffi::sqlite3_user_data(ctx).cast::<F>();
+            let value = f(&Arguments::from_raw(argc, argv));
+            Context::from_raw(ctx).result(value);
+        }
+    }
+
+    unsafe extern "C" fn destroy<F>(data: *mut c_void) {
+        unsafe {
+            drop(Box::from_raw(data.cast::<F>()));
+        }
+    }
+
+    let f = Box::new(f);
+
+    unsafe {
+        ffi::sqlite3_create_function_v2(
+            db,
+            name,
+            n_arg,
+            ffi::SQLITE_UTF8 | flags,
+            Box::into_raw(f).cast(),
+            Some(call::<F, T>),
+            None,
+            None,
+            Some(destroy::<F>),
+        )
+    }
+}
+
+pub(crate) unsafe fn create_window_function<F, A>(
+    db: *mut ffi::sqlite3,
+    name: *const core::ffi::c_char,
+    n_arg: c_int,
+    flags: c_int,
+    init: F,
+) -> c_int
+where
+    F: Fn() -> A + Send + 'static,
+    A: WindowAggregate,
+{
+    unsafe extern "C" fn step<F, A>(
+        ctx: *mut ffi::sqlite3_context,
+        argc: c_int,
+        argv: *mut *mut ffi::sqlite3_value,
+    ) where
+        F: Fn() -> A,
+        A: WindowAggregate,
+    {
+        unsafe {
+            let Some(state) = aggregate::<F, A>(ctx) else {
+                return;
+            };
+
+            if let Err(error) = state.step(&Arguments::from_raw(argc, argv)) {
+                Context::from_raw(ctx).error(&error);
+            }
+        }
+    }
+
+    unsafe extern "C" fn inverse<F, A>(
+        ctx: *mut ffi::sqlite3_context,
+        argc: c_int,
+        argv: *mut *mut ffi::sqlite3_value,
+    ) where
+        F: Fn() -> A,
+        A: WindowAggregate,
+    {
+        unsafe {
+            let Some(state) = aggregate::<F, A>(ctx) else {
+                return;
+            };
+
+            if let Err(error) = state.inverse(&Arguments::from_raw(argc, argv)) {
+                Context::from_raw(ctx).error(&error);
+            }
+        }
+    }
+
+    unsafe extern "C" fn value<F, A>(ctx: *mut ffi::sqlite3_context)
+    where
+        F: Fn() -> A,
+        A: WindowAggregate,
+    {
+        unsafe {
+            let Some(state) = aggregate::<F, A>(ctx) else {
+                return;
+            };
+
+            Context::from_raw(ctx).result(state.value());
+        }
+    }
+
+    unsafe extern "C" fn finalize<F, A>(ctx: *mut ffi::sqlite3_context)
+    where
+        F: Fn() -> A,
+        A: WindowAggregate,
+    {
+        unsafe {
+            let state = take_aggregate::<F, A>(ctx);
+            Context::from_raw(ctx).result(state.finalize());
+        }
+    }
+
+    unsafe extern "C" fn destroy<F, A>(data: *mut c_void) {
+        unsafe {
+            drop(Box::from_raw(data.cast::<Window<F, A>>()));
+        }
+    }
+
+    let window = Box::new(Window::<F, A> {
+        init,
+        _marker: PhantomData,
+    });
+
+    unsafe {
+        ffi::sqlite3_create_window_function(
+            db,
+            name,
+            n_arg,
+            ffi::SQLITE_UTF8 | flags,
+            Box::into_raw(window).cast(),
+            Some(step::<F, A>),
+            Some(finalize::<F, A>),
+            Some(value::<F, A>),
+            Some(inverse::<F, A>),
+            Some(destroy::<F, A>),
+        )
+    }
+}
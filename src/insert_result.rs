@@ -0,0 +1,58 @@
+/// The result of an insert.
+///
+/// See [`Connection::insert`].
+///
+/// [`Connection::insert`]: crate::Connection::insert
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);
+/// "#)?;
+///
+/// let result = c.insert("INSERT INTO users (name) VALUES (?), (?)", ("Alice", "Bob"))?;
+/// assert_eq!(result.rowid(), 2);
+/// assert_eq!(result.changes(), 2);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct InsertResult {
+    rowid: i64,
+    changes: usize,
+}
+
+impl InsertResult {
+    #[inline]
+    pub(crate) const fn new(rowid: i64, changes: usize) -> Self {
+        Self { rowid, changes }
+    }
+
+    /// The rowid of the most recently inserted row.
+    ///
+    /// This is the same as [`Connection::last_insert_rowid`] right after the
+    /// insert completed. If the statement didn't insert any rows, this is the
+    /// rowid of whatever row was previously inserted by the connection.
+    ///
+    /// [`Connection::last_insert_rowid`]: crate::Connection::last_insert_rowid
+    #[inline]
+    pub const fn rowid(&self) -> i64 {
+        self.rowid
+    }
+
+    /// The number of rows modified by the insert.
+    ///
+    /// This is the same as [`Connection::changes`] right after the insert
+    /// completed.
+    ///
+    /// [`Connection::changes`]: crate::Connection::changes
+    #[inline]
+    pub const fn changes(&self) -> usize {
+        self.changes
+    }
+}
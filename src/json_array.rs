@@ -0,0 +1,276 @@
+use core::ffi::c_int;
+use core::fmt::Write;
+
+use alloc::string::String;
+
+use crate::{BIND_INDEX, Bind, BindValue, Code, Error, Null, Result, Statement};
+
+/// A helper to bind a sequence of values as a single JSON array parameter.
+///
+/// Variadic `IN` clauses normally require constructing one placeholder for
+/// each element. With `JsonArray`, the sequence is instead serialized into a
+/// JSON array which is bound as text and expanded using the [`json_each`]
+/// table-valued function:
+///
+/// ```sql
+/// SELECT * FROM users WHERE id IN (SELECT value FROM json_each(?))
+/// ```
+///
+/// The wrapped value is iterated over every time it's bound, so it has to be
+/// [`Clone`]. Slices, arrays by reference, and ranges all work. Elements must
+/// implement [`JsonElement`].
+///
+/// This requires that sqlite is built with JSON support, which is the default
+/// since version 3.38.0 and is always the case for the bundled build.
+///
+/// [`json_each`]: https://sqlite.org/json1.html#jeach
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, JsonArray, Result};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (id INTEGER, name TEXT);
+///
+///     INSERT INTO users VALUES (1, 'Alice'), (2, 'Bob'), (3, 'Charlie');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name FROM users WHERE id IN (SELECT value FROM json_each(?)) ORDER BY id")?;
+///
+/// stmt.bind(JsonArray::new(&[1, 3]))?;
+/// let names = stmt.iter::<String>().collect::<Result<Vec<_>>>()?;
+/// assert_eq!(names, ["Alice", "Charlie"]);
+///
+/// let mut stmt = c.prepare("SELECT id FROM users WHERE name IN (SELECT value FROM json_each(?)) ORDER BY id")?;
+///
+/// stmt.bind(JsonArray::new(["Bob", "Charlie", "Dave"]))?;
+/// let ids = stmt.iter::<i64>().collect::<Result<Vec<_>>>()?;
+/// assert_eq!(ids, [2, 3]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct JsonArray<I> {
+    iter: I,
+}
+
+impl<I> JsonArray<I> {
+    /// Construct a new JSON array from something that can be iterated over.
+    #[inline]
+    pub const fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+/// [`BindValue`] implementation for [`JsonArray`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, JsonArray, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT ?")?;
+///
+/// stmt.bind_value(BIND_INDEX, JsonArray::new(0..3))?;
+/// assert_eq!(stmt.next::<String>()?.as_deref(), Some("[0,1,2]"));
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, JsonArray::new(["a\"b", "c\nd"]))?;
+/// assert_eq!(stmt.next::<String>()?.as_deref(), Some(r#"["a\"b","c\nd"]"#));
+///
+/// stmt.reset()?;
+/// stmt.bind_value(BIND_INDEX, JsonArray::new([Some(1.5), None]))?;
+/// assert_eq!(stmt.next::<String>()?.as_deref(), Some("[1.5,null]"));
+///
+/// stmt.reset()?;
+/// let e = stmt.bind_value(BIND_INDEX, JsonArray::new([f64::NAN])).unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<I> BindValue for JsonArray<I>
+where
+    I: Clone + IntoIterator,
+    I::Item: JsonElement,
+{
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        let mut out = String::new();
+        out.push('[');
+
+        for (n, element) in self.iter.clone().into_iter().enumerate() {
+            if n > 0 {
+                out.push(',');
+            }
+
+            element.write_json(&mut out)?;
+        }
+
+        out.push(']');
+        out.bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for [`JsonArray`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, JsonArray, Result};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT SUM(value) FROM json_each(?)")?;
+///
+/// stmt.bind(JsonArray::new(1..=100))?;
+/// assert_eq!(stmt.next::<i64>()?, Some(5050));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<I> Bind for JsonArray<I>
+where
+    I: Clone + IntoIterator,
+    I::Item: JsonElement,
+{
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
+
+/// A value which can be written as an element of a [`JsonArray`].
+pub trait JsonElement {
+    /// Write the value as JSON to the given output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, JsonArray, JsonElement, Result};
+    ///
+    /// struct Id(u32);
+    ///
+    /// impl JsonElement for Id {
+    ///     #[inline]
+    ///     fn write_json(&self, out: &mut String) -> Result<()> {
+    ///         self.0.write_json(out)
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT COUNT(*) FROM json_each(?)")?;
+    /// stmt.bind(JsonArray::new([Id(1), Id(2)].iter()))?;
+    /// assert_eq!(stmt.next::<i64>()?, Some(2));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    fn write_json(&self, out: &mut String) -> Result<()>;
+}
+
+impl<T> JsonElement for &T
+where
+    T: ?Sized + JsonElement,
+{
+    #[inline]
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        (**self).write_json(out)
+    }
+}
+
+impl JsonElement for Null {
+    #[inline]
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        out.push_str("null");
+        Ok(())
+    }
+}
+
+impl<T> JsonElement for Option<T>
+where
+    T: JsonElement,
+{
+    #[inline]
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        match self {
+            Some(value) => value.write_json(out),
+            None => Null.write_json(out),
+        }
+    }
+}
+
+impl JsonElement for bool {
+    #[inline]
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        out.push_str(if *self { "true" } else { "false" });
+        Ok(())
+    }
+}
+
+macro_rules! integer {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl JsonElement for $ty {
+                #[inline]
+                fn write_json(&self, out: &mut String) -> Result<()> {
+                    _ = write!(out, "{self}");
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl JsonElement for $ty {
+                #[inline]
+                fn write_json(&self, out: &mut String) -> Result<()> {
+                    if !self.is_finite() {
+                        return Err(Error::new(
+                            Code::MISMATCH,
+                            format_args!("{self} cannot be represented in JSON"),
+                        ));
+                    }
+
+                    _ = write!(out, "{self:?}");
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+float!(f32, f64);
+
+impl JsonElement for str {
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        out.push('"');
+
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c < ' ' => {
+                    _ = write!(out, "\\u{:04x}", c as u32);
+                }
+                c => out.push(c),
+            }
+        }
+
+        out.push('"');
+        Ok(())
+    }
+}
+
+impl JsonElement for String {
+    #[inline]
+    fn write_json(&self, out: &mut String) -> Result<()> {
+        self.as_str().write_json(out)
+    }
+}
@@ -0,0 +1,118 @@
+use crate::ty;
+use crate::{FromColumn, Result, Statement};
+
+/// A column adapter which captures the error of reading a value instead of
+/// failing.
+///
+/// Normally a single column which fails to convert, such as an integer which
+/// is out of range or a column which has an unexpected type, causes reading
+/// the whole row to fail. Wrapping the type in `Lenient` captures the error
+/// for that column, allowing the rest of the row to be read.
+///
+/// This is useful for things like data cleaning, where bad cells should be
+/// reported rather than aborting.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, Lenient};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE readings (sensor TEXT, value INTEGER);
+///
+///     INSERT INTO readings VALUES ('a', 10), ('b', 1000), ('c', 'broken');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT sensor, value FROM readings")?;
+///
+/// let mut good = Vec::new();
+/// let mut bad = Vec::new();
+///
+/// while let Some((sensor, value)) = stmt.next::<(String, Lenient<u8>)>()? {
+///     match value.into_result() {
+///         Ok(value) => good.push((sensor, value)),
+///         Err(e) => bad.push((sensor, e.code())),
+///     }
+/// }
+///
+/// assert_eq!(good, [(String::from("a"), 10)]);
+/// assert_eq!(bad, [(String::from("b"), Code::MISMATCH), (String::from("c"), Code::MISMATCH)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Lenient<T> {
+    result: Result<T>,
+}
+
+impl<T> Lenient<T> {
+    /// Get the result of reading the column.
+    #[inline]
+    pub fn into_result(self) -> Result<T> {
+        self.result
+    }
+
+    /// Borrow the result of reading the column.
+    #[inline]
+    pub fn as_result(&self) -> &Result<T> {
+        &self.result
+    }
+
+    /// Test if the column was successfully read.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Get the value if the column was successfully read, discarding the
+    /// error otherwise.
+    #[inline]
+    pub fn ok(self) -> Option<T> {
+        self.result.ok()
+    }
+}
+
+impl<T> From<Lenient<T>> for Result<T> {
+    #[inline]
+    fn from(value: Lenient<T>) -> Self {
+        value.result
+    }
+}
+
+/// [`FromColumn`] implementation for [`Lenient<T>`].
+///
+/// Errors from both checking the type of the column and converting its value
+/// are captured.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, Lenient};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT 'hello', 42, NULL")?;
+///
+/// let (a, b, c) = stmt.next::<(Lenient<i64>, Lenient<i64>, Lenient<Option<i64>>)>()?.unwrap();
+/// assert_eq!(a.into_result().unwrap_err().code(), Code::MISMATCH);
+/// assert_eq!(b.into_result()?, 42);
+/// assert_eq!(c.into_result()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl<'stmt, T> FromColumn<'stmt> for Lenient<T>
+where
+    T: FromColumn<'stmt>,
+{
+    type Type = ty::Fallible<T::Type>;
+
+    #[inline]
+    fn from_column(stmt: &'stmt Statement, index: ty::Fallible<T::Type>) -> Result<Self> {
+        let result = match index.into_result() {
+            Ok(index) => T::from_column(stmt, index),
+            Err(error) => Err(error),
+        };
+
+        Ok(Lenient { result })
+    }
+}
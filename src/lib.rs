@@ -176,6 +176,37 @@
 //! # Ok::<_, sqll::Error>(())
 //! ```
 //!
+//! When binding parameters individually with [`bind_value`],
+//! [`reset_and_clear`] can be used to reset the statement and clear any stale
+//! bindings in one go:
+//!
+//! ```
+//! use sqll::{Connection, Prepare};
+//!
+//! let c = Connection::open_in_memory()?;
+//!
+//! c.execute(r#"
+//!     CREATE TABLE users (name TEXT, age INTEGER);
+//! "#)?;
+//!
+//! let mut stmt = c.prepare_with("INSERT INTO users VALUES (?, ?)", Prepare::PERSISTENT)?;
+//!
+//! for (name, age) in [("Alice", Some(42)), ("Bob", None)] {
+//!     stmt.reset_and_clear()?;
+//!     stmt.bind_value(1, name)?;
+//!
+//!     if let Some(age) = age {
+//!         stmt.bind_value(2, age)?;
+//!     }
+//!
+//!     assert!(stmt.step()?.is_done());
+//! }
+//!
+//! let mut stmt = c.prepare("SELECT COUNT(*) FROM users WHERE age IS NULL")?;
+//! assert_eq!(stmt.next::<i64>()?, Some(1));
+//! # Ok::<_, sqll::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! ## Features
@@ -197,9 +228,35 @@
 //!   **may not be used by multiple threads at all** even if threads have
 //!   distinct connections. To disable mutexes which allows for efficient one
 //!   connection per thread the [`OpenOptions::no_mutex`] option should be used
-//!   instead[^sqll-sys].
+//!   instead[^sqll-sys]. This is an alias for `threadsafe-serialized`.
+//! * `threadsafe-serialized` - Like `threadsafe`, but when `bundled` is enabled
+//!   sqlite is built with the "serialized" threading mode as the default. This
+//!   means [`OpenOptions::full_mutex`] is the default for new
+//!   connections[^sqll-sys].
+//! * `threadsafe-multi` - Like `threadsafe`, but when `bundled` is enabled sqlite
+//!   is built with the "multi-thread" threading mode as the default. This means
+//!   [`OpenOptions::no_mutex`] is the default for new connections, but
+//!   [`OpenOptions::full_mutex`] can still be used. This has no effect if
+//!   `threadsafe-serialized` is enabled[^sqll-sys].
 //! * `strict` - Enable usage of sqlite with the strict compiler options
 //!   enabled[^sqll-sys].
+//! * `math` - Enable the built-in sqlite math functions such as `sqrt`, `pow`,
+//!   and `log`. This only has an effect if `bundled` is enabled[^sqll-sys].
+//! * `normalize` - Enable [`Statement::normalized_sql`]. This requires `bundled`
+//!   to be enabled[^sqll-sys].
+//! * `serde` - Implement `Serialize` for [`Value`] and add
+//!   [`Statement::serialize_row`] to serialize a whole row keyed by column
+//!   name.
+//! * `serde_json` - Implement [`Bind`] and [`BindValue`] for
+//!   `serde_json::Value`, allowing dynamic JSON documents to be used as
+//!   parameters. This enables `serde`.
+//! * `decimal` - Implement [`Bind`], [`BindValue`], and [`FromColumn`] for
+//!   `rust_decimal::Decimal`, which is stored as text to avoid losing
+//!   precision.
+//! * `smallvec` - Implement [`FromColumn`] for `smallvec::SmallVec<[u8; N]>`,
+//!   reading blobs inline when they fit.
+//! * `arrayvec` - Implement [`FromColumn`] for `arrayvec::ArrayVec<u8, N>`,
+//!   reading blobs of at most `N` bytes.
 //!
 //! [^sqll-sys]: This is a forwarded sqll-sys option, see <https://docs.rs/sqll-sys>.
 //!
@@ -239,21 +296,27 @@
 //! [`axum`]: https://docs.rs/axum
 //! [`Bind` derive]: https://docs.rs/sqll/latest/sqll/derive.Bind.html
 //! [`bind`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.bind
+//! [`bind_value`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.bind_value
 //! [`Bind`]: https://docs.rs/sqll/latest/sqll/trait.Bind.html
 //! [`Connection`]: https://docs.rs/sqll/latest/sqll/struct.Connection.html#thread-safety
 //! [`examples/axum.rs`]: https://github.com/udoprog/sqll/blob/main/examples/axum.rs
 //! [`examples/persons.rs`]: https://github.com/udoprog/sqll/blob/main/examples/persons.rs
 //! [`execute`]: https://docs.rs/sqll/latest/sqll/struct.Connection.html#method.execute
+//! [`reset_and_clear`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.reset_and_clear
 //! [`Row` derive]: https://docs.rs/sqll/latest/sqll/derive.Row.html
 //! [`Row`]: https://docs.rs/sqll/latest/sqll/trait.Row.html
 //! [`next`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.next
+//! [`OpenOptions::full_mutex`]: https://docs.rs/sqll/latest/sqll/struct.OpenOptions.html#method.full_mutex
 //! [`OpenOptions::no_mutex`]: https://docs.rs/sqll/latest/sqll/struct.OpenOptions.html#method.no_mutex
 //! [`prepare_with`]: https://docs.rs/sqll/latest/sqll/struct.Connection.html#method.prepare_with
 //! [`Prepare::PERSISTENT`]: https://docs.rs/sqll/latest/sqll/struct.Prepare.html#associatedconstant.PERSISTENT
 //! [`prepare`]: https://docs.rs/sqll/latest/sqll/struct.Connection.html#method.prepare
 //! [`sqlite` crate]: https://github.com/stainless-steel/sqlite
 //! [`sqll-sys`]: https://crates.io/crates/sqll-sys
+//! [`Statement::normalized_sql`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.normalized_sql
+//! [`Statement::serialize_row`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.serialize_row
 //! [`Statement`]: https://docs.rs/sqll/latest/sqll/struct.Statement.html
+//! [`Value`]: https://docs.rs/sqll/latest/sqll/struct.Value.html
 //! [binding query parameters]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.bind
 //! [calling `execute`]: https://docs.rs/sqll/latest/sqll/struct.Connection.html#method.execute
 //! [reading rows]: https://docs.rs/sqll/latest/sqll/struct.Statement.html#method.next
@@ -275,39 +338,93 @@ extern crate alloc;
 #[cfg(test)]
 mod tests;
 
+mod affinity;
+#[cfg(feature = "alloc")]
+mod authorizer;
+mod big_int;
 mod bind;
 mod bind_value;
+mod blob_display;
+#[cfg(feature = "std")]
+mod busy;
 mod bytes;
+mod cache_stats;
 mod code;
 mod connection;
+mod deserialize;
+mod dynamic_row;
 mod error;
 mod ffi;
 mod fixed_blob;
 mod fixed_text;
 mod from_column;
+mod from_columns;
 mod from_unsized_column;
+#[cfg(feature = "alloc")]
+mod function;
+mod insert_result;
+#[cfg(feature = "alloc")]
+mod json_array;
+mod lenient;
+#[cfg(feature = "alloc")]
+mod log;
+mod memory;
 mod open_options;
 #[cfg(feature = "alloc")]
 mod owned;
+mod pragma_snapshot;
 mod row;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sink;
+mod sql;
 mod statement;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod statement_cache;
 mod text;
+#[cfg(feature = "alloc")]
+mod text_buf;
+mod txn_state;
 pub mod ty;
 mod utils;
 mod value;
 mod value_type;
 mod version;
+#[cfg(feature = "alloc")]
+mod virtual_table;
 
+#[doc(inline)]
+pub use self::affinity::Affinity;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::authorizer::{AuthAction, AuthKind, AuthResult};
+#[doc(inline)]
+pub use self::big_int::BigInt;
 #[doc(inline)]
 pub use self::bind::{BIND_INDEX, Bind};
 #[doc(inline)]
 pub use self::bind_value::BindValue;
 #[doc(inline)]
+pub use self::blob_display::{BlobBase64, BlobHex};
+#[doc(inline)]
+pub use self::cache_stats::CacheStats;
+#[doc(inline)]
 pub use self::code::Code;
 #[doc(inline)]
 pub use self::connection::{Connection, Prepare, SendConnection};
 #[doc(inline)]
-pub use self::error::{CapacityError, DatabaseNotFound, Error, NotThreadSafe, Result};
+pub use self::deserialize::DeserializeOptions;
+#[doc(inline)]
+pub use self::dynamic_row::DynamicRow;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::dynamic_row::Values;
+#[doc(inline)]
+pub use self::error::{
+    CapacityError, DatabaseNotFound, Error, NotThreadSafe, ParseValueTypeError, Result,
+};
 #[doc(inline)]
 pub use self::fixed_blob::FixedBlob;
 #[doc(inline)]
@@ -315,21 +432,63 @@ pub use self::fixed_text::FixedText;
 #[doc(inline)]
 pub use self::from_column::FromColumn;
 #[doc(inline)]
+pub use self::from_columns::FromColumns;
+#[doc(inline)]
 pub use self::from_unsized_column::FromUnsizedColumn;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::function::{Arguments, Arity, Context, FunctionFlags, ReturnValue, WindowAggregate};
+#[doc(inline)]
+pub use self::insert_result::InsertResult;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::json_array::{JsonArray, JsonElement};
+#[doc(inline)]
+pub use self::lenient::Lenient;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::log::config_log;
+#[doc(inline)]
+pub use self::memory::{hard_heap_limit, memory_highwater, memory_used, soft_heap_limit};
+#[doc(inline)]
+pub use self::open_options::{OnOpen, OpenOptions};
 #[doc(inline)]
-pub use self::open_options::OpenOptions;
+pub use self::pragma_snapshot::PragmaSnapshot;
 #[doc(inline)]
 pub use self::row::Row;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, cfg(feature = "serde"))]
+#[doc(inline)]
+pub use self::serialize::{BlobEncoding, SerializeRow, SerializeValue};
+#[doc(inline)]
+pub use self::sink::Sink;
+#[doc(inline)]
+pub use self::sql::{Parameters, Sql};
 #[doc(inline)]
 pub use self::statement::{Null, SendStatement, State, Statement};
 #[doc(inline)]
 pub use self::text::Text;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::text_buf::TextBuf;
+#[doc(inline)]
+pub use self::txn_state::TxnState;
 #[doc(inline)]
 pub use self::value::Value;
 #[doc(inline)]
 pub use self::value_type::ValueType;
 #[doc(inline)]
-pub use self::version::{lib_version, lib_version_number};
+pub use self::version::{lib_source_id, lib_version, lib_version_number};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+#[doc(inline)]
+pub use self::virtual_table::{
+    ConstraintOp, ConstraintUsage, IndexConstraint, IndexInfo, VirtualCursor, VirtualTable,
+};
 
 /// Derive macro for [`Bind`].
 ///
@@ -414,6 +573,38 @@ pub use self::version::{lib_version, lib_version_number};
 ///
 /// <br>
 ///
+/// #### `#[sql(rename_all = "..")]`
+///
+/// This applies a case conversion to the names derived from fields when used
+/// with `#[sql(named)]`. Explicit names set through `#[sql(name = ..)]` are
+/// not affected.
+///
+/// The supported conversions are `"snake_case"`, `"camelCase"`,
+/// `"PascalCase"`, and `"SCREAMING_SNAKE_CASE"`.
+///
+/// ```
+/// use sqll::{Bind, Connection};
+///
+/// #[derive(Bind)]
+/// #[sql(named, rename_all = "camelCase")]
+/// struct Person<'stmt> {
+///     first_name: &'stmt str,
+///     birth_year: u32,
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///    CREATE TABLE persons (first_name TEXT, birth_year INTEGER);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("INSERT INTO persons VALUES (:firstName, :birthYear)")?;
+/// stmt.execute(Person { first_name: "Alice", birth_year: 1990 })?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// <br>
+///
 /// ## Field attributes
 ///
 /// <br>
@@ -488,6 +679,71 @@ pub use self::version::{lib_version, lib_version_number};
 /// # stmt.execute(PersonStr { name: "Alice", age: 30 })?;
 /// # Ok::<_, sqll::Error>(())
 /// ```
+///
+/// <br>
+///
+/// #### `#[sql(with = ..)]`
+///
+/// This allows for specifying a module containing a custom conversion function
+/// for a field, which is useful for types which do not implement
+/// [`BindValue`]. The module must contain a function with the following
+/// signature, where `T` is the type of the field:
+///
+/// ```text
+/// fn bind_value(value: &T, stmt: &mut Statement, index: c_int) -> Result<()>;
+/// ```
+///
+/// This is the same attribute as used by the [`Row` derive], so the same
+/// module can implement conversions in both directions.
+///
+/// [`Row` derive]: derive@crate::Row
+///
+/// ```
+/// use sqll::{Bind, Connection};
+///
+/// use status::Status;
+///
+/// mod status {
+///     use core::ffi::c_int;
+///
+///     use sqll::{BindValue, Result, Statement};
+///
+///     #[derive(Debug, PartialEq)]
+///     pub(crate) enum Status {
+///         Active,
+///         Banned,
+///     }
+///
+///     pub(crate) fn bind_value(value: &Status, stmt: &mut Statement, index: c_int) -> Result<()> {
+///         let value = match value {
+///             Status::Active => "active",
+///             Status::Banned => "banned",
+///         };
+///
+///         value.bind_value(stmt, index)
+///     }
+/// }
+///
+/// #[derive(Bind)]
+/// struct User<'stmt> {
+///     name: &'stmt str,
+///     #[sql(with = status)]
+///     status: Status,
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///    CREATE TABLE users (name TEXT, status TEXT);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("INSERT INTO users (name, status) VALUES (?, ?)")?;
+/// stmt.execute(User { name: "Alice", status: Status::Banned })?;
+///
+/// let mut stmt = c.prepare("SELECT status FROM users")?;
+/// assert_eq!(stmt.next::<String>()?.as_deref(), Some("banned"));
+/// # Ok::<_, sqll::Error>(())
+/// ```
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use sqll_macros::Bind;
@@ -536,6 +792,47 @@ pub use sqll_macros::Bind;
 ///
 /// <br>
 ///
+/// ## Column names
+///
+/// For structs with named fields, the derive also generates an associated
+/// `COLUMNS` constant listing the names of the fields in the order of the
+/// columns they are read from. This can be used to build queries or to check
+/// that a query returns the expected columns.
+///
+/// ```
+/// use sqll::{Connection, Row, Text};
+///
+/// #[derive(Row)]
+/// struct Person<'stmt> {
+///     #[sql(index = 1)]
+///     name: &'stmt str,
+///     #[sql(index = 0)]
+///     age: u32,
+/// }
+///
+/// assert_eq!(Person::COLUMNS, ["age", "name"]);
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users VALUES ('Alice', 42);
+/// "#)?;
+///
+/// let query = format!("SELECT {} FROM users", Person::COLUMNS.join(", "));
+/// let mut stmt = c.prepare(query)?;
+///
+/// assert!(stmt.column_names().eq(Person::COLUMNS.iter().map(Text::new)));
+///
+/// let person = stmt.next::<Person<'_>>()?.expect("missing row");
+/// assert_eq!(person.name, "Alice");
+/// assert_eq!(person.age, 42);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// <br>
+///
 /// ## Container attributes
 ///
 /// <br>
@@ -560,6 +857,29 @@ pub use sqll_macros::Bind;
 ///
 /// <br>
 ///
+/// #### `#[sql(rename_all = "..")]`
+///
+/// This applies a case conversion to the field names listed in the generated
+/// `COLUMNS` constant.
+///
+/// The supported conversions are `"snake_case"`, `"camelCase"`,
+/// `"PascalCase"`, and `"SCREAMING_SNAKE_CASE"`.
+///
+/// ```
+/// use sqll::Row;
+///
+/// #[derive(Row)]
+/// #[sql(rename_all = "PascalCase")]
+/// struct Person<'stmt> {
+///     first_name: &'stmt str,
+///     age: u32,
+/// }
+///
+/// assert_eq!(Person::COLUMNS, ["FirstName", "Age"]);
+/// ```
+///
+/// <br>
+///
 /// ## Field attributes
 ///
 /// <br>
@@ -582,6 +902,83 @@ pub use sqll_macros::Bind;
 ///
 /// <br>
 ///
+/// #### `#[sql(with = ..)]`
+///
+/// This allows for specifying a module containing a custom conversion function
+/// for a field, which is useful for types which do not implement
+/// [`FromColumn`]. The module must contain a function with the following
+/// signature, where `T` is the type of the field:
+///
+/// ```text
+/// fn from_column(stmt: &mut Statement, index: c_int) -> Result<T>;
+/// ```
+///
+/// Since the function has mutable access to the statement, the produced value
+/// cannot borrow from it. Custom conversions are performed before any other
+/// fields are read.
+///
+/// This is the same attribute as used by the [`Bind` derive], so the same
+/// module can implement conversions in both directions.
+///
+/// [`Bind` derive]: derive@crate::Bind
+///
+/// ```
+/// use sqll::{Code, Connection, Row};
+///
+/// use status::Status;
+///
+/// mod status {
+///     use core::ffi::c_int;
+///
+///     use sqll::{Code, Error, Result, Statement};
+///
+///     #[derive(Debug, PartialEq)]
+///     pub(crate) enum Status {
+///         Active,
+///         Banned,
+///     }
+///
+///     pub(crate) fn from_column(stmt: &mut Statement, index: c_int) -> Result<Status> {
+///         match stmt.unsized_column::<str>(index)? {
+///             "active" => Ok(Status::Active),
+///             "banned" => Ok(Status::Banned),
+///             other => Err(Error::new(Code::MISMATCH, format_args!("bad status {other:?}"))),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Row)]
+/// struct User<'stmt> {
+///     name: &'stmt str,
+///     #[sql(with = status)]
+///     status: Status,
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///    CREATE TABLE users (name TEXT, status TEXT);
+///
+///    INSERT INTO users VALUES ('Alice', 'active'), ('Bob', 'banned'), ('Eve', 'unknown');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name, status FROM users")?;
+///
+/// let user = stmt.next::<User<'_>>()?.expect("missing row");
+/// assert_eq!(user.name, "Alice");
+/// assert_eq!(user.status, Status::Active);
+///
+/// let user = stmt.next::<User<'_>>()?.expect("missing row");
+/// assert_eq!(user.name, "Bob");
+/// assert_eq!(user.status, Status::Banned);
+///
+/// let e = stmt.next::<User<'_>>().unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// <br>
+///
 /// #### Missing `#[sql(name)]`?
 ///
 /// Unlike the `Bind` derive, there is no `#[sql(name = ..)]` attribute for the
@@ -614,3 +1011,54 @@ pub use sqll_macros::Bind;
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use sqll_macros::Row;
+
+/// Construct an [`Sql`] from a string literal, counting its parameters at
+/// compile time.
+///
+/// The SQL is tokenized enough to count parameters the same way as sqlite
+/// does, skipping over string literals, quoted identifiers, and comments. The
+/// count is available through [`Sql::parameter_count`] and is checked when
+/// binding parameters through [`Connection::execute_sql`].
+///
+/// Full validation of the SQL isn't possible without a connection, so the SQL
+/// can still fail to prepare at runtime.
+///
+/// If the `sqll` crate is renamed, the path to it can be specified with
+/// `sql!(crate = path, "...")`.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{sql, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+/// "#)?;
+///
+/// let insert = sql!("INSERT INTO users (name, age) VALUES (?, ?) -- Comments with ? are skipped");
+/// assert_eq!(insert.parameter_count(), 2);
+///
+/// c.execute_sql(insert, ("Alice", 42))?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Binding a mismatched number of parameters fails to compile:
+///
+/// ```compile_fail
+/// use sqll::{sql, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+/// c.execute_sql(sql!("INSERT INTO users (name, age) VALUES (?, ?)"), ("Alice", 42, "extra"))?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// So does SQL which can't be tokenized:
+///
+/// ```compile_fail
+/// let insert = sqll::sql!("INSERT INTO users (name) VALUES ('Alice)");
+/// ```
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use sqll_macros::sql;
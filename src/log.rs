@@ -0,0 +1,104 @@
+use core::ffi::{c_char, c_int, c_void};
+
+use alloc::boxed::Box;
+
+use crate::ffi;
+use crate::utils::c_to_text;
+use crate::{Code, Error, Result, Text};
+
+/// Install a process-wide callback which receives messages logged by sqlite.
+///
+/// The callback is called with the [`Code`] of the message and its text. This
+/// includes warnings which are otherwise not surfaced, such as
+/// [`Code::WARNING_AUTOINDEX`] when the query planner creates an automatic
+/// index, or [`Code::NOTICE_RECOVER_WAL`] when a database is recovered.
+///
+/// This wraps [`sqlite3_config`] with the [`SQLITE_CONFIG_LOG`] option. The
+/// configuration is global to the process and replaces any previously
+/// installed callback, including ones installed by other libraries which use
+/// sqlite. It should therefore be done once, during the setup of an
+/// application.
+///
+/// Since sqlite might still be calling a previous callback when it is
+/// replaced, callbacks are never freed. Repeatedly installing new callbacks
+/// leaks them.
+///
+/// The callback may be called concurrently from any thread which uses
+/// sqlite, and must not call into sqlite itself.
+///
+/// [`sqlite3_config`]: https://www.sqlite.org/c3ref/config.html
+/// [`SQLITE_CONFIG_LOG`]: https://www.sqlite.org/c3ref/c_config_covering_index_scan.html#sqliteconfiglog
+///
+/// # Safety
+///
+/// The `sqlite3_config` function is not threadsafe. The caller must ensure
+/// that no other thread calls [`config_log`] at the same time.
+///
+/// # Errors
+///
+/// Errors with [`Code::MISUSE`] if the linked sqlite is older than 3.42.0 and
+/// has already been initialized, such as by opening a connection. Older
+/// versions only permit configuring logging before that.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Mutex;
+///
+/// use sqll::{Code, Connection};
+///
+/// static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+///
+/// unsafe {
+///     sqll::config_log(|code, message| {
+///         if code == Code::WARNING_AUTOINDEX {
+///             WARNINGS.lock().unwrap().push(message.to_string());
+///         }
+///     })?;
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (id INTEGER, name TEXT);
+///     CREATE TABLE posts (user_id INTEGER, title TEXT);
+/// "#)?;
+///
+/// c.prepare("SELECT name, title FROM users, posts WHERE users.id = posts.user_id")?;
+/// assert!(!WARNINGS.lock().unwrap().is_empty());
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub unsafe fn config_log<F>(callback: F) -> Result<()>
+where
+    F: Fn(Code, &Text) + Send + Sync + 'static,
+{
+    extern "C" fn glue<F>(data: *mut c_void, code: c_int, message: *const c_char)
+    where
+        F: Fn(Code, &Text),
+    {
+        unsafe {
+            let callback = &*(data as *const F);
+            let message = c_to_text(message.cast()).unwrap_or(Text::new(b""));
+            callback(Code::new(code), message);
+        }
+    }
+
+    let callback = Box::into_raw(Box::new(callback));
+
+    unsafe {
+        let code = ffi::sqlite3_config(
+            ffi::SQLITE_CONFIG_LOG,
+            glue::<F> as extern "C" fn(*mut c_void, c_int, *const c_char),
+            callback.cast::<c_void>(),
+        );
+
+        if code != ffi::SQLITE_OK {
+            // NB: The callback was not installed, so it can be freed.
+            drop(Box::from_raw(callback));
+            let code = Code::new(code);
+            return Err(Error::new(code, code.message()));
+        }
+    }
+
+    Ok(())
+}
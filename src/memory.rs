@@ -0,0 +1,127 @@
+use core::ffi::c_int;
+
+use crate::ffi;
+
+/// Return the number of bytes of memory currently allocated by sqlite.
+///
+/// This is process-wide and covers every connection and statement.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+/// let before = sqll::memory_used();
+/// assert!(before > 0);
+///
+/// c.execute(r#"
+///     CREATE TABLE files (data BLOB);
+///
+///     INSERT INTO files VALUES (zeroblob(1000000)), (randomblob(1000000));
+/// "#)?;
+///
+/// assert!(sqll::memory_used() > before);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[inline]
+pub fn memory_used() -> i64 {
+    unsafe { ffi::sqlite3_memory_used() }
+}
+
+/// Return the largest value [`memory_used`] has reached since the high-water
+/// mark was last reset.
+///
+/// If `reset` is `true`, the high-water mark is reset to the current value of
+/// [`memory_used`] after it has been read.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (data BLOB);
+///
+///     INSERT INTO files VALUES (randomblob(1000000));
+///     DELETE FROM files;
+/// "#)?;
+///
+/// let highwater = sqll::memory_highwater(true);
+/// assert!(highwater >= sqll::memory_used());
+/// assert!(highwater > 1000000);
+/// assert!(sqll::memory_highwater(false) < highwater);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[inline]
+pub fn memory_highwater(reset: bool) -> i64 {
+    unsafe { ffi::sqlite3_memory_highwater(c_int::from(reset)) }
+}
+
+/// Set the soft heap limit in bytes for the process, returning the previous
+/// limit.
+///
+/// When the limit is exceeded, sqlite tries to free up memory, such as from
+/// page caches, before allocating more. Allocations still succeed if it's not
+/// possible to stay under the limit.
+///
+/// A limit of zero disables it, and a negative value doesn't modify it and
+/// can be used to query the current limit.
+///
+/// See the [sqlite documentation] for more information.
+///
+/// [sqlite documentation]: https://www.sqlite.org/c3ref/hard_heap_limit64.html
+///
+/// # Examples
+///
+/// ```
+/// let previous = sqll::soft_heap_limit(8 * 1024 * 1024);
+/// assert_eq!(sqll::soft_heap_limit(-1), 8 * 1024 * 1024);
+/// sqll::soft_heap_limit(previous);
+/// ```
+#[inline]
+pub fn soft_heap_limit(n: i64) -> i64 {
+    unsafe { ffi::sqlite3_soft_heap_limit64(n) }
+}
+
+/// Set the hard heap limit in bytes for the process, returning the previous
+/// limit.
+///
+/// Unlike [`soft_heap_limit`], allocations which would exceed this limit fail
+/// and cause the operation performing them to fail with [`Code::NOMEM`].
+///
+/// A limit of zero disables it, and a negative value doesn't modify it and
+/// can be used to query the current limit. If a soft heap limit is also set,
+/// it's capped to the hard heap limit.
+///
+/// See the [sqlite documentation] for more information.
+///
+/// [`Code::NOMEM`]: crate::Code::NOMEM
+/// [sqlite documentation]: https://www.sqlite.org/c3ref/hard_heap_limit64.html
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (data BLOB);
+/// "#)?;
+///
+/// let previous = sqll::hard_heap_limit(sqll::memory_used() + 1000000);
+///
+/// let e = c.execute("INSERT INTO files VALUES (randomblob(10000000))").unwrap_err();
+/// assert_eq!(e.code(), Code::NOMEM);
+///
+/// sqll::hard_heap_limit(previous);
+/// c.execute("INSERT INTO files VALUES (randomblob(10000000))")?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[inline]
+pub fn hard_heap_limit(n: i64) -> i64 {
+    unsafe { ffi::sqlite3_hard_heap_limit64(n) }
+}
@@ -3,8 +3,10 @@ use core::ffi::c_int;
 use core::mem::MaybeUninit;
 use core::ptr::{self, NonNull};
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::ffi::CString;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use std::path::Path;
@@ -85,6 +87,30 @@ use crate::{Code, Connection, Error, Result};
 /// # Ok::<_, sqll::Error>(())
 /// ```
 ///
+/// # Reusing options
+///
+/// Builder methods take `&mut self` to allow for convenient chaining. Since
+/// `OpenOptions` is [`Copy`], a configured value can be stored by
+/// dereferencing the result of the chain, and then be used to open any number
+/// of connections with identical flags. This is useful for things like
+/// connection pools.
+///
+/// ```
+/// use sqll::OpenOptions;
+///
+/// let options = *OpenOptions::new()
+///     .read_write()
+///     .create()
+///     .no_mutex();
+///
+/// let a = options.open_in_memory()?;
+/// let b = options.open_in_memory()?;
+///
+/// a.execute("CREATE TABLE test (id INTEGER)")?;
+/// b.execute("CREATE TABLE test (id INTEGER)")?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
 /// # Using `sqlite3_config` is not supported
 ///
 /// The [`sqlite3_config` function] is a way that allows for users of sqlite to
@@ -101,6 +127,9 @@ use crate::{Code, Connection, Error, Result};
 #[derive(Clone, Copy, Debug)]
 pub struct OpenOptions {
     raw: c_int,
+    busy_timeout: c_int,
+    #[cfg(feature = "std")]
+    create_dirs: bool,
 }
 
 impl OpenOptions {
@@ -122,6 +151,9 @@ impl OpenOptions {
     pub fn new() -> Self {
         Self {
             raw: ffi::SQLITE_OPEN_EXRESCODE,
+            busy_timeout: 0,
+            #[cfg(feature = "std")]
+            create_dirs: false,
         }
     }
 
@@ -145,7 +177,12 @@ impl OpenOptions {
     /// ```
     #[inline]
     pub fn empty() -> Self {
-        Self { raw: 0 }
+        Self {
+            raw: 0,
+            busy_timeout: 0,
+            #[cfg(feature = "std")]
+            create_dirs: false,
+        }
     }
 
     /// The database is opened in read-only mode. If the database does not
@@ -405,6 +442,273 @@ impl OpenOptions {
         self
     }
 
+    /// Set a busy timeout in milliseconds which is applied to every connection
+    /// immediately after it has been opened.
+    ///
+    /// This is the same as calling [`Connection::busy_timeout`] on the opened
+    /// connection, which is recommended for any setup where multiple
+    /// connections access the same database. A timeout of zero or less leaves
+    /// the connection without a busy timeout, which is the default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let c = OpenOptions::new()
+    ///     .read_write()
+    ///     .create()
+    ///     .busy_timeout(5000)
+    ///     .open_in_memory()?;
+    ///
+    /// assert_eq!(c.get_busy_timeout(), 5000);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn busy_timeout(&mut self, ms: c_int) -> &mut Self {
+        self.busy_timeout = ms.max(0);
+        self
+    }
+
+    /// Create any missing parent directories of the database path when
+    /// opening it with [`open`].
+    ///
+    /// This only has an effect in combination with [`create`], and is ignored
+    /// for paths which are interpreted as [`uri`]s and for in-memory
+    /// databases.
+    ///
+    /// [`open`]: Self::open
+    /// [`create`]: Self::create
+    /// [`uri`]: Self::uri
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::CANTOPEN`] if the directories could not be created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("data").join("app.db");
+    ///
+    /// let c = OpenOptions::new()
+    ///     .read_write()
+    ///     .create()
+    ///     .create_dirs()
+    ///     .open(&path)?;
+    ///
+    /// assert!(path.is_file());
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    #[inline]
+    pub fn create_dirs(&mut self) -> &mut Self {
+        self.create_dirs = true;
+        self
+    }
+
+    /// Get the raw flags which will be passed to [`sqlite3_open_v2`].
+    ///
+    /// [`sqlite3_open_v2`]: https://sqlite.org/c3ref/open.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// assert_eq!(OpenOptions::empty().flags(), 0);
+    /// assert_ne!(OpenOptions::new().flags(), 0);
+    ///
+    /// let a = OpenOptions::new().read_write().create().flags();
+    /// let b = OpenOptions::new().create().read_write().flags();
+    /// assert_eq!(a, b);
+    /// ```
+    #[inline]
+    pub fn flags(&self) -> c_int {
+        self.raw
+    }
+
+    /// Test if the database will be opened in read-only mode.
+    ///
+    /// See [`read_only`].
+    ///
+    /// [`read_only`]: Self::read_only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let mut options = OpenOptions::new();
+    /// assert!(!options.is_read_only());
+    /// assert!(!options.is_read_write());
+    ///
+    /// options.read_only();
+    /// assert!(options.is_read_only());
+    /// assert!(!options.is_read_write());
+    /// ```
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_READONLY)
+    }
+
+    /// Test if the database will be opened for reading and writing.
+    ///
+    /// See [`read_write`].
+    ///
+    /// [`read_write`]: Self::read_write
+    #[inline]
+    pub fn is_read_write(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_READWRITE)
+    }
+
+    /// Test if the database will be created if it does not already exist.
+    ///
+    /// See [`create`].
+    ///
+    /// [`create`]: Self::create
+    #[inline]
+    pub fn is_create(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_CREATE)
+    }
+
+    /// Test if the filename will be interpreted as a URI.
+    ///
+    /// See [`uri`].
+    ///
+    /// [`uri`]: Self::uri
+    #[inline]
+    pub fn is_uri(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_URI)
+    }
+
+    /// Test if the database will be opened as an in-memory database.
+    ///
+    /// See [`memory`].
+    ///
+    /// [`memory`]: Self::memory
+    #[inline]
+    pub fn is_memory(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_MEMORY)
+    }
+
+    /// Test if the connection will be opened in multi-thread mode.
+    ///
+    /// See [`no_mutex`].
+    ///
+    /// [`no_mutex`]: Self::no_mutex
+    #[inline]
+    pub fn has_no_mutex(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_NOMUTEX)
+    }
+
+    /// Test if the connection will be opened in serialized mode.
+    ///
+    /// See [`full_mutex`].
+    ///
+    /// [`full_mutex`]: Self::full_mutex
+    #[inline]
+    pub fn has_full_mutex(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_FULLMUTEX)
+    }
+
+    /// Test if the database will be opened with shared cache enabled.
+    ///
+    /// See [`shared_cache`].
+    ///
+    /// [`shared_cache`]: Self::shared_cache
+    #[inline]
+    pub fn has_shared_cache(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_SHAREDCACHE)
+    }
+
+    /// Test if the database will be opened with shared cache disabled.
+    ///
+    /// See [`private_cache`].
+    ///
+    /// [`private_cache`]: Self::private_cache
+    #[inline]
+    pub fn has_private_cache(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_PRIVATECACHE)
+    }
+
+    /// Test if the database filename is not allowed to contain a symbolic link.
+    ///
+    /// See [`no_follow`].
+    ///
+    /// [`no_follow`]: Self::no_follow
+    #[inline]
+    pub fn has_no_follow(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_NOFOLLOW)
+    }
+
+    /// Test if the connection will come up in extended result code mode.
+    ///
+    /// See [`extended_result_codes`].
+    ///
+    /// [`extended_result_codes`]: Self::extended_result_codes
+    #[inline]
+    pub fn has_extended_result_codes(&self) -> bool {
+        self.has(ffi::SQLITE_OPEN_EXRESCODE)
+    }
+
+    #[inline]
+    fn has(&self, flag: c_int) -> bool {
+        self.raw & flag == flag
+    }
+
+    /// Run the given initializer on every connection opened with these
+    /// options.
+    ///
+    /// The initializer runs exactly once, immediately after the connection has
+    /// been opened and before it's returned. If it fails, the connection is
+    /// closed and the error is returned instead. This is the way to ensure that
+    /// every connection, such as the ones created by a pool, is configured
+    /// identically with things like PRAGMAs and busy timeouts.
+    ///
+    /// Since `OpenOptions` is [`Copy`] the initializer is stored in the
+    /// separate [`OnOpen`] builder, which can be reused to open any number of
+    /// connections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, OpenOptions};
+    ///
+    /// let options = OpenOptions::new()
+    ///     .read_write()
+    ///     .create()
+    ///     .on_open(|c| {
+    ///         c.execute("PRAGMA foreign_keys = ON")?;
+    ///         c.busy_timeout(5000)?;
+    ///         Ok(())
+    ///     });
+    ///
+    /// let c = options.open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (id INTEGER PRIMARY KEY);
+    ///     CREATE TABLE posts (user_id INTEGER REFERENCES users (id));
+    /// "#)?;
+    ///
+    /// let e = c.execute("INSERT INTO posts VALUES (1)").unwrap_err();
+    /// assert_eq!(e.code(), Code::CONSTRAINT_FOREIGNKEY);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn on_open<F>(&self, init: F) -> OnOpen<F>
+    where
+        F: Fn(&mut Connection) -> Result<()>,
+    {
+        OnOpen {
+            options: *self,
+            init,
+        }
+    }
+
     /// Open a database to the given path.
     ///
     /// Note that it is possible to open an in-memory database by passing
@@ -419,7 +723,13 @@ impl OpenOptions {
     #[cfg_attr(docsrs, cfg(feature = "std"))]
     #[inline]
     pub fn open(&self, path: impl AsRef<Path>) -> Result<Connection> {
-        let path = path_to_cstring(path.as_ref())?;
+        let path = path.as_ref();
+
+        if self.create_dirs && self.is_create() && !self.is_uri() && !self.is_memory() {
+            create_parent_dirs(path)?;
+        }
+
+        let path = path_to_cstring(path)?;
         self._open(&path)
     }
 
@@ -439,6 +749,97 @@ impl OpenOptions {
         self._open(c":memory:")
     }
 
+    /// Open a private temporary on-disk database.
+    ///
+    /// The database is stored in a temporary file which is deleted once the
+    /// connection is closed. Unlike [`open_in_memory`], pages of the database
+    /// are written to disk as needed, so it can hold large datasets which
+    /// don't fit in memory. Small databases might never be written to disk.
+    ///
+    /// Every temporary database is private to the connection that opened it.
+    ///
+    /// [`open_in_memory`]: Self::open_in_memory
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let c = OpenOptions::new()
+    ///     .read_write()
+    ///     .create()
+    ///     .open_temporary()?;
+    ///
+    /// c.execute("CREATE TABLE users (name TEXT)")?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn open_temporary(&self) -> Result<Connection> {
+        self._open(c"")
+    }
+
+    /// Open a named in-memory database which can be shared between
+    /// connections.
+    ///
+    /// This opens the URI `file:<name>?mode=memory&cache=shared`, which
+    /// requires the [`uri`] option that is enabled internally. Every connection
+    /// opened with the same name in the same process sees the same database,
+    /// which lives for as long as at least one connection to it is open.
+    ///
+    /// Characters in the name which have a special meaning in URIs are
+    /// escaped.
+    ///
+    /// [`uri`]: Self::uri
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let options = *OpenOptions::new()
+    ///     .extended_result_codes()
+    ///     .read_write()
+    ///     .create();
+    ///
+    /// let a = options.open_in_memory_named(c"open_options_shared")?;
+    /// let b = options.open_in_memory_named(c"open_options_shared")?;
+    /// let other = options.open_in_memory_named(c"open_options_other")?;
+    ///
+    /// a.execute("CREATE TABLE users (name TEXT); INSERT INTO users VALUES ('Alice')")?;
+    ///
+    /// let mut stmt = b.prepare("SELECT name FROM users")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    ///
+    /// assert!(other.prepare("SELECT name FROM users").is_err());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn open_in_memory_named(&self, name: &CStr) -> Result<Connection> {
+        let name = name.to_bytes();
+
+        let mut uri = Vec::with_capacity(name.len() + 32);
+        uri.extend_from_slice(b"file:");
+
+        for &b in name {
+            match b {
+                b'%' => uri.extend_from_slice(b"%25"),
+                b'?' => uri.extend_from_slice(b"%3F"),
+                b'#' => uri.extend_from_slice(b"%23"),
+                b => uri.push(b),
+            }
+        }
+
+        uri.extend_from_slice(b"?mode=memory&cache=shared");
+
+        // SAFETY: The name came from a c-string, so it has no interior nulls.
+        let uri = unsafe { CString::from_vec_unchecked(uri) };
+
+        let mut options = *self;
+        options.uri();
+        options._open(&uri)
+    }
+
     fn _open(&self, name: &CStr) -> Result<Connection> {
         unsafe {
             let mut raw = MaybeUninit::uninit();
@@ -455,14 +856,118 @@ impl OpenOptions {
             let is_thread_safe = ffi::sqlite3_threadsafe() != 0
                 && (self.raw & (ffi::SQLITE_OPEN_NOMUTEX | ffi::SQLITE_OPEN_FULLMUTEX)) != 0;
 
-            Ok(Connection::from_raw(
-                NonNull::new_unchecked(raw),
-                is_thread_safe,
-            ))
+            let mut c = Connection::from_raw(NonNull::new_unchecked(raw), is_thread_safe);
+
+            if self.busy_timeout > 0 {
+                c.busy_timeout(self.busy_timeout)?;
+            }
+
+            Ok(c)
         }
     }
 }
 
+/// Options for opening a connection which runs an initializer on every opened
+/// connection.
+///
+/// See [`OpenOptions::on_open`].
+#[derive(Clone, Copy, Debug)]
+pub struct OnOpen<F> {
+    options: OpenOptions,
+    init: F,
+}
+
+impl<F> OnOpen<F>
+where
+    F: Fn(&mut Connection) -> Result<()>,
+{
+    /// Open a database to the given path and run the initializer on it.
+    ///
+    /// See [`OpenOptions::open`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::OpenOptions;
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("database.sqlite3");
+    ///
+    /// let options = OpenOptions::new()
+    ///     .read_write()
+    ///     .create()
+    ///     .on_open(|c| c.execute("PRAGMA journal_mode = WAL"));
+    ///
+    /// let c = options.open(&path)?;
+    /// let mut stmt = c.prepare("PRAGMA journal_mode")?;
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("wal"));
+    /// # Ok::<_, Box<dyn core::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    #[inline]
+    pub fn open(&self, path: impl AsRef<Path>) -> Result<Connection> {
+        self.init(self.options.open(path)?)
+    }
+
+    /// Open a database connection with a raw c-string and run the initializer
+    /// on it.
+    ///
+    /// See [`OpenOptions::open_c_str`].
+    #[inline]
+    pub fn open_c_str(&self, name: &CStr) -> Result<Connection> {
+        self.init(self.options.open_c_str(name)?)
+    }
+
+    /// Open an in-memory database and run the initializer on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Error, OpenOptions};
+    ///
+    /// let options = OpenOptions::new()
+    ///     .read_write()
+    ///     .on_open(|_| Err(Error::new(Code::ERROR, "not allowed")));
+    ///
+    /// let e = options.open_in_memory().unwrap_err();
+    /// assert_eq!(e.code(), Code::ERROR);
+    /// ```
+    #[inline]
+    pub fn open_in_memory(&self) -> Result<Connection> {
+        self.init(self.options.open_in_memory()?)
+    }
+
+    fn init(&self, mut c: Connection) -> Result<Connection> {
+        (self.init)(&mut c)?;
+        Ok(c)
+    }
+}
+
+/// Create the missing parent directories of a database path.
+#[cfg(feature = "std")]
+fn create_parent_dirs(path: &Path) -> Result<()> {
+    if path.as_os_str() == ":memory:" {
+        return Ok(());
+    }
+
+    let Some(parent) = path.parent() else {
+        return Ok(());
+    };
+
+    if parent.as_os_str().is_empty() {
+        return Ok(());
+    }
+
+    match std::fs::create_dir_all(parent) {
+        Ok(()) => Ok(()),
+        Err(e) => Err(Error::new(
+            Code::CANTOPEN,
+            format_args!("could not create directory {}: {e}", parent.display()),
+        )),
+    }
+}
+
 /// Convert a filesystem path to a c-string.
 ///
 /// This used to have a platform-specific implementation, particularly unix is
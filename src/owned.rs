@@ -15,13 +15,33 @@ impl Owned {
     pub(crate) fn new<T>(value: T) -> Result<Self> {
         unsafe fn drop_glue<F>(ptr: NonNull<()>) {
             unsafe {
+                ptr.cast::<F>().drop_in_place();
+
                 let layout = Layout::new::<F>();
-                alloc::alloc::dealloc(ptr.as_ptr().cast(), layout);
+
+                if layout.size() != 0 {
+                    alloc::alloc::dealloc(ptr.as_ptr().cast(), layout);
+                }
             }
         }
 
         let layout = Layout::new::<T>();
 
+        // NB: Zero-sized values such as closures which capture nothing must
+        // not be passed to the allocator.
+        if layout.size() == 0 {
+            let ptr = NonNull::<T>::dangling();
+
+            unsafe {
+                ptr.write(value);
+            }
+
+            return Ok(Self {
+                ptr: ptr.cast(),
+                drop: drop_glue::<T>,
+            });
+        }
+
         let ptr = unsafe {
             let ptr = alloc::alloc::alloc(layout);
 
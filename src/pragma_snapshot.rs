@@ -0,0 +1,76 @@
+use core::ffi::c_int;
+
+/// The journal modes supported by sqlite, in the order used by
+/// [`PragmaSnapshot`].
+pub(crate) const JOURNAL_MODES: &[&str] =
+    &["delete", "truncate", "persist", "memory", "wal", "off"];
+
+/// A snapshot of mutable connection settings which can be restored later.
+///
+/// See [`Connection::pragma_snapshot`].
+///
+/// [`Connection::pragma_snapshot`]: crate::Connection::pragma_snapshot
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let snapshot = c.pragma_snapshot()?;
+/// assert!(!snapshot.foreign_keys());
+/// assert!(!snapshot.recursive_triggers());
+/// assert_eq!(snapshot.busy_timeout(), 0);
+/// assert_eq!(snapshot.journal_mode(), "memory");
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PragmaSnapshot {
+    pub(crate) foreign_keys: bool,
+    pub(crate) recursive_triggers: bool,
+    pub(crate) busy_timeout: c_int,
+    pub(crate) journal_mode: usize,
+}
+
+impl PragmaSnapshot {
+    /// Whether foreign key constraints are enforced.
+    ///
+    /// This corresponds to the [`foreign_keys` pragma].
+    ///
+    /// [`foreign_keys` pragma]: https://sqlite.org/pragma.html#pragma_foreign_keys
+    #[inline]
+    pub fn foreign_keys(&self) -> bool {
+        self.foreign_keys
+    }
+
+    /// Whether recursive triggers are enabled.
+    ///
+    /// This corresponds to the [`recursive_triggers` pragma].
+    ///
+    /// [`recursive_triggers` pragma]: https://sqlite.org/pragma.html#pragma_recursive_triggers
+    #[inline]
+    pub fn recursive_triggers(&self) -> bool {
+        self.recursive_triggers
+    }
+
+    /// The busy timeout in milliseconds, or `0` if there is none.
+    ///
+    /// This corresponds to the [`busy_timeout` pragma].
+    ///
+    /// [`busy_timeout` pragma]: https://sqlite.org/pragma.html#pragma_busy_timeout
+    #[inline]
+    pub fn busy_timeout(&self) -> c_int {
+        self.busy_timeout
+    }
+
+    /// The journal mode of the main database, such as `"delete"` or `"wal"`.
+    ///
+    /// This corresponds to the [`journal_mode` pragma].
+    ///
+    /// [`journal_mode` pragma]: https://sqlite.org/pragma.html#pragma_journal_mode
+    #[inline]
+    pub fn journal_mode(&self) -> &'static str {
+        JOURNAL_MODES[self.journal_mode]
+    }
+}
@@ -1,6 +1,6 @@
 use crate::ty::Type;
 use crate::utils::repeat;
-use crate::{Error, FromColumn, Statement};
+use crate::{Error, FromColumn, FromColumns, Statement};
 
 /// This allows a type to be constructed from a [`Statement`] using [`next`],
 /// [`iter`], or [`row`].
@@ -177,11 +177,7 @@ macro_rules! implement_tuple {
         {
             #[inline]
             fn from_row(stmt: &'stmt mut Statement) -> Result<Self, Error> {
-                let $var0 = <$ty0>::Type::check(stmt, $value0)?;
-                $(let $var = <$ty>::Type::check(stmt, $value0n)?;)*
-                let $var0 = <$ty0>::from_column(stmt, $var0)?;
-                $(let $var = <$ty>::from_column(stmt, $var)?;)*
-                Ok(($var0, $($var,)*))
+                FromColumns::from_columns(stmt, 0)
             }
         }
     };
@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+
+use serde::ser::{Error as _, Serialize, SerializeMap, Serializer};
+
+use crate::value::Kind;
+use crate::{BlobBase64, BlobHex, Value};
+
+/// How blobs are encoded when a [`Value`] is serialized.
+///
+/// Most serialization formats lack a native representation of binary data, so
+/// by default blobs are serialized as a [base64] string using the standard
+/// alphabet with padding.
+///
+/// See [`Value::serialize_with`] and [`SerializeRow::blob_encoding`].
+///
+/// [base64]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{BlobEncoding, Value};
+///
+/// let value = Value::blob(b"\xde\xad");
+///
+/// let json = serde_json::to_string(&value)?;
+/// assert_eq!(json, r#""3q0=""#);
+///
+/// let json = serde_json::to_string(&value.serialize_with(BlobEncoding::Hex))?;
+/// assert_eq!(json, r#""dead""#);
+///
+/// let json = serde_json::to_string(&value.serialize_with(BlobEncoding::Bytes))?;
+/// assert_eq!(json, "[222,173]");
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlobEncoding {
+    /// Encode blobs as a base64 string with padding.
+    #[default]
+    Base64,
+    /// Encode blobs as a lowercase hexadecimal string.
+    Hex,
+    /// Serialize blobs as bytes, leaving the representation up to the
+    /// serializer. In JSON this is an array of numbers.
+    Bytes,
+}
+
+/// A [`Value`] which is serialized with a custom [`BlobEncoding`].
+///
+/// See [`Value::serialize_with`].
+#[derive(Debug, Clone, Copy)]
+pub struct SerializeValue<'a, 'stmt> {
+    value: &'a Value<'stmt>,
+    encoding: BlobEncoding,
+}
+
+impl<'a, 'stmt> SerializeValue<'a, 'stmt> {
+    #[inline]
+    pub(crate) fn new(value: &'a Value<'stmt>, encoding: BlobEncoding) -> Self {
+        Self { value, encoding }
+    }
+}
+
+impl Serialize for SerializeValue<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self.value.kind() {
+            Kind::Integer(value) => serializer.serialize_i64(value),
+            Kind::Float(value) => serializer.serialize_f64(value),
+            Kind::Text(value) => match value.to_str() {
+                Ok(value) => serializer.serialize_str(value),
+                Err(..) => Err(S::Error::custom("text is not valid UTF-8")),
+            },
+            Kind::Blob(value) => match self.encoding {
+                BlobEncoding::Base64 => serializer.collect_str(&BlobBase64::new(value)),
+                BlobEncoding::Hex => serializer.collect_str(&BlobHex::new(value)),
+                BlobEncoding::Bytes => serializer.serialize_bytes(value),
+            },
+        }
+    }
+}
+
+/// [`Serialize`] implementation for [`Value`].
+///
+/// Integers and floats are serialized as numbers, text as strings, and blobs
+/// as base64 strings. `NULL` is not a [`Value`], but `Option<Value>` serializes
+/// it as the unit value, which is `null` in JSON.
+///
+/// To use a different encoding for blobs, see [`Value::serialize_with`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, Value};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// let mut stmt = c.prepare("SELECT 42, 1.5, 'Alice', X'01', NULL")?;
+/// assert!(stmt.step()?.is_row());
+///
+/// let row = stmt.row::<(Value<'_>, Value<'_>, Value<'_>, Value<'_>, Option<Value<'_>>)>()?;
+/// let json = serde_json::to_string(&row).unwrap();
+/// assert_eq!(json, r#"[42,1.5,"Alice","AQ==",null]"#);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Serialize for Value<'_> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializeValue::new(self, BlobEncoding::default()).serialize(serializer)
+    }
+}
+
+/// A row which is serialized as a map from column name to value.
+///
+/// See [`Statement::serialize_row`].
+///
+/// [`Statement::serialize_row`]: crate::Statement::serialize_row
+#[derive(Debug, Clone)]
+pub struct SerializeRow<'stmt> {
+    columns: Vec<(&'stmt str, Option<Value<'stmt>>)>,
+    encoding: BlobEncoding,
+}
+
+impl<'stmt> SerializeRow<'stmt> {
+    #[inline]
+    pub(crate) fn new(columns: Vec<(&'stmt str, Option<Value<'stmt>>)>) -> Self {
+        Self {
+            columns,
+            encoding: BlobEncoding::default(),
+        }
+    }
+
+    /// Set the encoding used for blobs in the row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{BlobEncoding, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT X'CAFE' AS data")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// let row = stmt.serialize_row()?.blob_encoding(BlobEncoding::Hex);
+    /// assert_eq!(serde_json::to_string(&row).unwrap(), r#"{"data":"cafe"}"#);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn blob_encoding(mut self, encoding: BlobEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+}
+
+impl Serialize for SerializeRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+
+        for (name, value) in &self.columns {
+            let value = value
+                .as_ref()
+                .map(|value| SerializeValue::new(value, self.encoding));
+            map.serialize_entry(name, &value)?;
+        }
+
+        map.end()
+    }
+}
@@ -0,0 +1,214 @@
+use core::ffi::c_int;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::FromUnsizedColumn;
+#[cfg(feature = "alloc")]
+use crate::ty::{self, Type};
+use crate::utils::repeat;
+use crate::{Result, Statement};
+
+/// A reusable buffer which column data can be written into.
+///
+/// Unlike [`FromColumn`], which constructs a new value for every row, a sink
+/// replaces its existing contents while retaining its allocation. This allows
+/// a single buffer to be recycled across many rows without allocating.
+///
+/// The implementations for [`String`] and [`Vec<u8>`] reserve exactly the
+/// length of the column being read, so a buffer only grows when a value larger
+/// than any previously read is encountered and never shrinks.
+///
+/// [`String`]: alloc::string::String
+/// [`Vec<u8>`]: alloc::vec::Vec
+///
+/// Tuples of sinks write consecutive columns, starting at the first index.
+///
+/// See [`Statement::read_into_sink`] and [`Statement::sink_iter`].
+///
+/// [`FromColumn`]: crate::FromColumn
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, photo BLOB);
+///
+///     INSERT INTO users VALUES ('Alice', X'0102'), ('Bob', X'03');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name, photo FROM users")?;
+/// let mut buf = (String::new(), Vec::new());
+///
+/// assert!(stmt.step()?.is_row());
+/// stmt.read_into_sink(0, &mut buf)?;
+/// assert_eq!(buf, (String::from("Alice"), vec![1, 2]));
+///
+/// assert!(stmt.step()?.is_row());
+/// stmt.read_into_sink(0, &mut buf)?;
+/// assert_eq!(buf, (String::from("Bob"), vec![3]));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub trait Sink {
+    /// Write the column at the given index into the sink, replacing its
+    /// previous contents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ffi::c_int;
+    ///
+    /// use sqll::{Connection, Result, Sink, Statement};
+    ///
+    /// struct Name(String);
+    ///
+    /// impl Sink for Name {
+    ///     #[inline]
+    ///     fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()> {
+    ///         self.0.write_column(stmt, index)
+    ///     }
+    /// }
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// let mut name = Name(String::new());
+    ///
+    /// assert!(stmt.step()?.is_row());
+    /// stmt.read_into_sink(0, &mut name)?;
+    /// assert_eq!(name.0, "Alice");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()>;
+}
+
+impl<T> Sink for &mut T
+where
+    T: ?Sized + Sink,
+{
+    #[inline]
+    fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        (**self).write_column(stmt, index)
+    }
+}
+
+/// [`Sink`] implementation for [`String`].
+///
+/// The column must be of type `TEXT`.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name);
+///
+///     INSERT INTO users VALUES ('Alice'), (42);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name FROM users")?;
+/// let mut name = String::new();
+///
+/// assert!(stmt.step()?.is_row());
+/// stmt.read_into_sink(0, &mut name)?;
+/// assert_eq!(name, "Alice");
+///
+/// assert!(stmt.step()?.is_row());
+/// let e = stmt.read_into_sink(0, &mut name).unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+impl Sink for String {
+    #[inline]
+    fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        let index = ty::Text::check(stmt, index)?;
+        self.clear();
+        self.reserve_exact(index.len());
+        self.push_str(<_>::from_unsized_column(stmt, index)?);
+        Ok(())
+    }
+}
+
+/// [`Sink`] implementation for [`Vec<u8>`].
+///
+/// The column must be of type `BLOB`.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Connection;
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE files (data BLOB);
+///
+///     INSERT INTO files VALUES (X'010203'), (X'');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT data FROM files")?;
+/// let mut data = Vec::new();
+///
+/// assert!(stmt.step()?.is_row());
+/// stmt.read_into_sink(0, &mut data)?;
+/// assert_eq!(data, [1, 2, 3]);
+///
+/// assert!(stmt.step()?.is_row());
+/// stmt.read_into_sink(0, &mut data)?;
+/// assert!(data.is_empty());
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, cfg(feature = "alloc"))]
+impl Sink for Vec<u8> {
+    #[inline]
+    fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        let index = ty::Blob::check(stmt, index)?;
+        self.clear();
+        self.reserve_exact(index.len());
+        self.extend_from_slice(<_>::from_unsized_column(stmt, index)?);
+        Ok(())
+    }
+}
+
+macro_rules! implement_tuple {
+    ($ty0:ident $var0:ident $value0:literal $value1:literal $(, $ty:ident $var:ident $value0n:literal $value1n:literal)* $(,)? ) => {
+        /// [`Sink`] implementation for a tuple.
+        ///
+        /// A tuple writes elements one after another, starting at the first
+        /// index.
+        impl<$ty0, $($ty,)*> Sink for ($ty0, $($ty,)*)
+        where
+            $ty0: Sink,
+            $($ty: Sink,)*
+        {
+            #[inline]
+            fn write_column(&mut self, stmt: &mut Statement, index: c_int) -> Result<()> {
+                let ($var0, $($var,)*) = self;
+                $var0.write_column(stmt, index + $value0)?;
+                $($var.write_column(stmt, index + $value0n)?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+repeat!(implement_tuple);
@@ -0,0 +1,234 @@
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
+use crate::utils::repeat;
+use crate::{BigInt, Bind, BindValue, FixedBlob, FixedText, Null, Text, Value};
+#[cfg(feature = "alloc")]
+use crate::{JsonArray, JsonElement, TextBuf};
+
+/// A piece of SQL where the number of parameters `N` is known at compile time.
+///
+/// This is constructed using the [`sql!`] macro, which counts the parameters
+/// in a string literal when it is compiled. It can be used anywhere SQL is
+/// accepted as a string, and with [`Connection::execute_sql`] to check that
+/// the number of parameters being bound matches.
+///
+/// [`sql!`]: crate::sql!
+/// [`Connection::execute_sql`]: crate::Connection::execute_sql
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{sql, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(sql!("CREATE TABLE users (name TEXT, age INTEGER)"))?;
+///
+/// let insert = sql!("INSERT INTO users (name, age) VALUES (?, ?)");
+/// assert_eq!(insert.parameter_count(), 2);
+///
+/// c.execute_sql(insert, ("Alice", 42))?;
+///
+/// let mut stmt = c.prepare(sql!("SELECT name FROM users WHERE age > :age"))?;
+/// stmt.bind_by_name(c":age", 30)?;
+/// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sql<const N: usize> {
+    sql: &'static str,
+}
+
+impl<const N: usize> Sql<N> {
+    /// Construct a new piece of SQL.
+    ///
+    /// This is used by the [`sql!`] macro and is not part of the public API,
+    /// since the number of parameters `N` must match the SQL.
+    ///
+    /// [`sql!`]: crate::sql!
+    #[doc(hidden)]
+    #[inline]
+    pub const fn __new(sql: &'static str) -> Self {
+        Self { sql }
+    }
+
+    /// Get the SQL as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::sql;
+    ///
+    /// let select = sql!("SELECT ?");
+    /// assert_eq!(select.as_str(), "SELECT ?");
+    /// ```
+    #[inline]
+    pub const fn as_str(&self) -> &'static str {
+        self.sql
+    }
+
+    /// Get the number of parameters in the SQL.
+    ///
+    /// This is the same as what [`Statement::bind_parameter_count`] returns
+    /// for a prepared statement, which is the largest parameter index.
+    ///
+    /// [`Statement::bind_parameter_count`]: crate::Statement::bind_parameter_count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::sql;
+    ///
+    /// assert_eq!(sql!("SELECT 1").parameter_count(), 0);
+    /// assert_eq!(sql!("SELECT ?, ?").parameter_count(), 2);
+    /// assert_eq!(sql!("SELECT :a, :b, :a").parameter_count(), 2);
+    /// assert_eq!(sql!("SELECT ?3, '?'").parameter_count(), 3);
+    /// ```
+    #[inline]
+    pub const fn parameter_count(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> AsRef<str> for Sql<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.sql
+    }
+}
+
+impl<const N: usize> fmt::Display for Sql<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sql.fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Sql<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.sql.fmt(f)
+    }
+}
+
+/// A [`Bind`] implementation which binds exactly `N` parameters.
+///
+/// This is used by [`Connection::execute_sql`] to check that the number of
+/// parameters being bound matches the [`Sql`] at compile time. It is
+/// implemented for `()`, for the single values provided by this crate which
+/// implement [`BindValue`], and for tuples of values implementing
+/// [`BindValue`]. A single value of a custom type can be bound as a one-element
+/// tuple.
+///
+/// [`Connection::execute_sql`]: crate::Connection::execute_sql
+///
+/// # Examples
+///
+/// Binding nothing to a statement which has parameters does not compile:
+///
+/// ```compile_fail
+/// use sqll::{sql, Connection};
+///
+/// let c = Connection::open_in_memory()?;
+/// c.execute(sql!("CREATE TABLE users (name TEXT NOT NULL)"))?;
+/// c.execute_sql(sql!("INSERT INTO users (name) VALUES (?)"), ())?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// A custom value is bound as a one-element tuple:
+///
+/// ```
+/// use sqll::{sql, Connection};
+///
+/// # #[derive(Clone, Copy)] struct Id(i64);
+/// # impl sqll::BindValue for Id {
+/// #     fn bind_value(&self, stmt: &mut sqll::Statement, index: core::ffi::c_int) -> sqll::Result<()> {
+/// #         self.0.bind_value(stmt, index)
+/// #     }
+/// # }
+/// let c = Connection::open_in_memory()?;
+/// c.execute(sql!("CREATE TABLE users (id INTEGER)"))?;
+/// c.execute_sql(sql!("INSERT INTO users (id) VALUES (?)"), (Id(1),))?;
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub trait Parameters<const N: usize>: Bind {}
+
+impl Parameters<0> for () {}
+
+macro_rules! single {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Parameters<1> for $ty {})*
+    };
+}
+
+single! {
+    Null, Value<'_>, [u8], str, Text, f32, f64, bool,
+    i8, i16, i32, i64, i128, u8, u16, u32, u64, u128,
+    BigInt<i64>, BigInt<u64>, BigInt<i128>, BigInt<u128>,
+}
+
+impl<const N: usize> Parameters<1> for FixedBlob<N> {}
+
+impl<const N: usize> Parameters<1> for [u8; N] {}
+
+impl<const N: usize> Parameters<1> for FixedText<N> {}
+
+impl<T> Parameters<1> for &T where T: ?Sized + Parameters<1> {}
+
+impl<T> Parameters<1> for Option<T> where T: BindValue {}
+
+#[cfg(feature = "alloc")]
+single!(Vec<u8>, String, TextBuf);
+
+#[cfg(feature = "alloc")]
+impl<T> Parameters<1> for Box<T> where T: ?Sized + BindValue {}
+
+#[cfg(feature = "alloc")]
+impl<T> Parameters<1> for Rc<T> where T: ?Sized + BindValue {}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> Parameters<1> for Arc<T> where T: ?Sized + BindValue {}
+
+#[cfg(feature = "alloc")]
+impl<I> Parameters<1> for JsonArray<I>
+where
+    I: Clone + IntoIterator,
+    I::Item: JsonElement,
+{
+}
+
+#[cfg(feature = "std")]
+single!(Path, PathBuf);
+
+#[cfg(feature = "decimal")]
+single!(rust_decimal::Decimal);
+
+#[cfg(feature = "serde_json")]
+single!(serde_json::Value);
+
+macro_rules! implement_tuple {
+    ($ty0:ident $var0:ident $v0:literal $v1:literal $(, $ty:ident $var:ident $v0n:literal $v1n:literal)* $(,)?) => {
+        impl<$ty0, $($ty,)*> Parameters<{ [$v1 $(, $v1n)*].len() }> for ($ty0, $($ty,)*)
+        where
+            $ty0: BindValue,
+            $($ty: BindValue,)*
+        {
+        }
+    };
+}
+
+repeat!(implement_tuple);
@@ -1,15 +1,37 @@
+#[cfg(feature = "std")]
+use core::borrow::Borrow;
 use core::ffi::{CStr, c_int};
 use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
 use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut, Range};
 use core::ptr::NonNull;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use crate::SerializeRow;
+#[cfg(feature = "alloc")]
+use crate::Value;
 use crate::ffi;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use crate::statement_cache::StatementCache;
+#[cfg(feature = "std")]
+use crate::ty;
 use crate::ty::Type;
-use crate::utils::{c_to_error_text, c_to_text};
+use crate::utils::{c_to_error_text, c_to_text, sqlite3_try};
 use crate::{
-    Bind, BindValue, Code, Error, FromColumn, FromUnsizedColumn, NotThreadSafe, Result, Row, Text,
-    ValueType,
+    Affinity, Bind, BindValue, Code, Error, FromColumn, FromColumns, FromUnsizedColumn,
+    NotThreadSafe, Result, Row, Sink, Text, ValueType,
 };
 
 /// A marker type representing NULL.
@@ -218,6 +240,14 @@ impl State {
 pub struct Statement {
     raw: NonNull<ffi::sqlite3_stmt>,
     is_thread_safe: bool,
+    max_column_size: usize,
+    /// The number of rows stepped since the statement was last reset.
+    rows_stepped: usize,
+    /// Whether the statement has run to completion or failed, in which case
+    /// the next step will automatically reset it.
+    halted: bool,
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    cache: Option<Arc<StatementCache>>,
 }
 
 impl fmt::Debug for Statement {
@@ -236,9 +266,23 @@ impl Statement {
         Statement {
             raw,
             is_thread_safe,
+            max_column_size: usize::MAX,
+            rows_stepped: 0,
+            halted: false,
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            cache: None,
         }
     }
 
+    /// Return the statement to the given cache when it is dropped instead of
+    /// finalizing it.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[inline]
+    pub(crate) fn with_cache(mut self, cache: Arc<StatementCache>) -> Statement {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Return the raw pointer.
     #[inline]
     pub(super) fn as_ptr(&self) -> *mut ffi::sqlite3_stmt {
@@ -251,6 +295,11 @@ impl Statement {
         self.raw.as_ptr()
     }
 
+    #[cold]
+    pub(crate) fn error(&self, code: c_int) -> Error {
+        Error::new(Code::new(code), self.error_message())
+    }
+
     #[inline]
     pub(crate) fn error_message(&self) -> &Text {
         unsafe {
@@ -260,6 +309,29 @@ impl Statement {
         }
     }
 
+    #[cold]
+    fn step_error(&self, code: c_int) -> Error {
+        #[cfg(feature = "std")]
+        {
+            // SAFETY: We own the raw handle to this statement.
+            let db = unsafe { ffi::sqlite3_db_handle(self.raw.as_ptr()) };
+
+            if let Some(error) = crate::busy::take_aborted(db, code) {
+                return error;
+            }
+        }
+
+        let code = Code::new(code);
+
+        // Misuse is detected before the statement runs, and doesn't update
+        // the error message of the connection which would otherwise be stale.
+        if code == Code::MISUSE {
+            return Error::new(code, code.message());
+        }
+
+        Error::new(code, self.error_message())
+    }
+
     /// Coerce this statement into a [`SendStatement`] which can be sent across
     /// threads.
     ///
@@ -411,6 +483,33 @@ impl Statement {
     ///
     /// Returns `None` when there are no more rows.
     ///
+    /// This is equivalent to calling [`Statement::step`] once, and if it
+    /// returns [`State::Row`] reading the row using [`Statement::row`].
+    ///
+    /// Like [`Statement::step`], calling this again after `None` has been
+    /// returned implicitly resets the statement, so the next call starts over
+    /// from the first row. To stop after the first `None`, use
+    /// [`Statement::iter`] together with [`Iterator::fuse`].
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    ///
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// assert_eq!(stmt.next::<String>()?, None);
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
     /// This is a higher level API than `step` and is less prone to misuse. Note
     /// however that misuse never leads to corrupted data or undefined behavior,
     /// only surprising behavior such as NULL values being auto-converted (see
@@ -512,6 +611,38 @@ impl Statement {
     ///
     /// For a less error-prone alternative, consider using [`Statement::next`].
     ///
+    /// If stepping fails, the statement has to be reset before it's used
+    /// again. Stepping it again without calling [`reset`] automatically resets
+    /// it and runs it from the beginning, which typically results in the same
+    /// error. Builds of SQLite with `SQLITE_OMIT_AUTORESET` instead error with
+    /// [`Code::MISUSE`]. Calling [`reset`] makes the statement ready to be
+    /// stepped again with new bindings.
+    ///
+    /// [`reset`]: Self::reset
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT UNIQUE);
+    ///
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?)")?;
+    ///
+    /// stmt.bind("Alice")?;
+    /// assert_eq!(stmt.step().unwrap_err(), Code::CONSTRAINT_UNIQUE);
+    /// assert_eq!(stmt.step().unwrap_err(), Code::CONSTRAINT_UNIQUE);
+    ///
+    /// stmt.reset()?;
+    /// stmt.bind("Bob")?;
+    /// assert!(stmt.step()?.is_done());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
     /// Trying to read data from a statement which has not been stepped will
     /// always result in a NULL value being read which will always result in an
     /// error.
@@ -582,16 +713,80 @@ impl Statement {
     /// ```
     #[inline]
     pub fn step(&mut self) -> Result<State> {
+        // NB: sqlite automatically resets a statement which is stepped after
+        // it has completed or failed.
+        if self.halted {
+            self.rows_stepped = 0;
+            self.halted = false;
+        }
+
         // SAFETY: We own the raw handle to this statement.
         unsafe {
             match ffi::sqlite3_step(self.raw.as_ptr()) {
-                ffi::SQLITE_ROW => Ok(State::Row),
-                ffi::SQLITE_DONE => Ok(State::Done),
-                code => Err(Error::new(Code::new(code), self.error_message())),
+                ffi::SQLITE_ROW => {
+                    self.rows_stepped += 1;
+                    Ok(State::Row)
+                }
+                ffi::SQLITE_DONE => {
+                    self.halted = true;
+                    Ok(State::Done)
+                }
+                code => {
+                    self.halted = true;
+                    Err(self.step_error(code))
+                }
             }
         }
     }
 
+    /// Get the number of rows which have been stepped since the statement was
+    /// last reset.
+    ///
+    /// This counts every call to [`step`] which produced a row, including
+    /// those made through [`next`] or [`iter`]. The count is cleared by
+    /// [`reset`] and the other methods which reset the statement, like
+    /// [`bind`]. A statement which has run to completion is also reset
+    /// automatically the next time it is stepped.
+    ///
+    /// [`step`]: Self::step
+    /// [`next`]: Self::next
+    /// [`iter`]: Self::iter
+    /// [`reset`]: Self::reset
+    /// [`bind`]: Self::bind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 72);
+    ///     INSERT INTO users VALUES ('Bob', 40);
+    ///     INSERT INTO users VALUES ('Carol', 30);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users WHERE age > ?")?;
+    /// stmt.bind(35)?;
+    ///
+    /// assert!(stmt.step()?.is_row());
+    /// assert_eq!(stmt.rows_stepped(), 1);
+    ///
+    /// while stmt.step()?.is_row() {}
+    /// assert_eq!(stmt.rows_stepped(), 2);
+    ///
+    /// stmt.reset()?;
+    /// assert_eq!(stmt.rows_stepped(), 0);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn rows_stepped(&self) -> usize {
+        self.rows_stepped
+    }
+
     /// In one call [`bind`] the specified values, and [`step`] until the
     /// current statement reports [`State::is_done`].
     ///
@@ -742,6 +937,74 @@ impl Statement {
         }
     }
 
+    /// Append all remaining rows produced by this statement to `out`,
+    /// returning the number of rows appended.
+    ///
+    /// Unlike collecting from [`iter`], this reuses the existing allocation of
+    /// `out`, which allows a buffer to be reused across queries. Like [`iter`]
+    /// each row has to be owned.
+    ///
+    /// [`iter`]: Self::iter
+    ///
+    /// # Errors
+    ///
+    /// If stepping the statement or reading a row fails, any rows appended by
+    /// this call are removed from `out` again before the error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 72);
+    ///     INSERT INTO users VALUES ('Bob', 40);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users WHERE age > ?")?;
+    /// let mut names = Vec::<String>::new();
+    ///
+    /// for age in [50, 30] {
+    ///     names.clear();
+    ///     stmt.bind(age)?;
+    ///     let n = stmt.fetch_all_into(&mut names)?;
+    ///     assert_eq!(n, names.len());
+    /// }
+    ///
+    /// assert_eq!(names, ["Alice", "Bob"]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn fetch_all_into<T>(&mut self, out: &mut Vec<T>) -> Result<usize>
+    where
+        for<'stmt> T: Row<'stmt>,
+    {
+        let len = out.len();
+
+        loop {
+            let row = match self.step() {
+                Ok(State::Row) => T::from_row(self),
+                Ok(State::Done) => break,
+                Err(e) => Err(e),
+            };
+
+            match row {
+                Ok(row) => out.push(row),
+                Err(e) => {
+                    out.truncate(len);
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(out.len() - len)
+    }
+
     /// Reset the statement allowing it to be re-executed.
     ///
     /// The next call to [`Statement::step`] will start over from the first
@@ -749,7 +1012,7 @@ impl Statement {
     ///
     /// Note that resetting a statement doesn't unset bindings set by
     /// [`Statement::bind_value`]. To do this, use
-    /// [`Statement::clear_bindings`].
+    /// [`Statement::clear_bindings`] or [`Statement::reset_and_clear`].
     ///
     /// # Examples
     ///
@@ -789,6 +1052,58 @@ impl Statement {
     #[inline]
     pub fn reset(&mut self) -> Result<()> {
         unsafe { ffi::sqlite3_reset(self.raw.as_ptr()) };
+        self.rows_stepped = 0;
+        self.halted = false;
+        Ok(())
+    }
+
+    /// Finish using the statement, returning the error from its most recent
+    /// step if it failed.
+    ///
+    /// Dropping a statement finalizes it and silently discards the result of
+    /// the most recent step. This can be used instead by callers who want to
+    /// make sure that a failure is never missed, such as when the result of
+    /// [`step`] has been ignored.
+    ///
+    /// Note that errors are also returned by [`step`] as they happen, so any
+    /// error returned here has already been reported once if it was checked.
+    ///
+    /// [`step`]: Self::step
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT UNIQUE);
+    ///
+    ///     INSERT INTO users VALUES ('Alice');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES ('Alice')")?;
+    /// _ = stmt.step();
+    ///
+    /// let e = stmt.finish().unwrap_err();
+    /// assert_eq!(e, Code::CONSTRAINT_UNIQUE);
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES ('Bob')")?;
+    /// _ = stmt.step();
+    /// stmt.finish()?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn finish(self) -> Result<()> {
+        // NB: Resetting reports the same error as finalizing would, but leaves
+        // the statement and its connection alive so that the error can be
+        // read. The statement is finalized once it is dropped.
+        let code = unsafe { ffi::sqlite3_reset(self.raw.as_ptr()) };
+
+        if code != ffi::SQLITE_OK {
+            return Err(Error::new(Code::new(code), self.error_message()));
+        }
+
         Ok(())
     }
 
@@ -806,27 +1121,36 @@ impl Statement {
     ///
     /// c.execute(r#"
     ///     CREATE TABLE users (name TEXT, age INTEGER);
-    ///
-    ///     INSERT INTO users VALUES ('Alice', 72);
-    ///     INSERT INTO users VALUES ('Bob', 40);
     /// "#)?;
     ///
-    /// let mut stmt = c.prepare("SELECT * FROM users WHERE age > ?")?;
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?, ?)")?;
     ///
-    /// let mut results = Vec::new();
+    /// stmt.bind_value(1, "Alice")?;
+    /// stmt.bind_value(2, 42)?;
+    /// assert!(stmt.step()?.is_done());
     ///
-    /// for age in [30, 50] {
-    ///     stmt.bind(age)?;
+    /// // Bindings are preserved across resets.
+    /// stmt.reset()?;
+    /// stmt.bind_value(1, "Bob")?;
+    /// assert!(stmt.step()?.is_done());
     ///
-    ///     while let Some(row) = stmt.next::<(String, i64)>()? {
-    ///         results.push(row);
-    ///     }
+    /// stmt.reset()?;
+    /// stmt.clear_bindings()?;
+    /// stmt.bind_value(1, "Carol")?;
+    /// assert!(stmt.step()?.is_done());
+    ///
+    /// let mut stmt = c.prepare("SELECT name, age FROM users")?;
+    ///
+    /// let mut results = Vec::new();
+    ///
+    /// while let Some(row) = stmt.next::<(String, Option<i64>)>()? {
+    ///     results.push(row);
     /// }
     ///
     /// let expected = [
-    ///     (String::from("Alice"), 72),
-    ///     (String::from("Bob"), 40),
-    ///     (String::from("Alice"), 72),
+    ///     (String::from("Alice"), Some(42)),
+    ///     (String::from("Bob"), Some(42)),
+    ///     (String::from("Carol"), None),
     /// ];
     ///
     /// assert_eq!(results, expected);
@@ -838,6 +1162,80 @@ impl Statement {
         Ok(())
     }
 
+    /// Reset the statement and clear all of its bindings.
+    ///
+    /// This is the same as calling [`reset`] followed by [`clear_bindings`],
+    /// which is what you usually want to do when re-using a statement where
+    /// parameters are bound individually using [`bind_value`]. Stale bindings
+    /// from the previous execution would otherwise be used.
+    ///
+    /// [`reset`]: Self::reset
+    /// [`clear_bindings`]: Self::clear_bindings
+    /// [`bind_value`]: Self::bind_value
+    ///
+    /// # Errors
+    ///
+    /// Unlike [`reset`], this surfaces any error which was raised by the most
+    /// recent step of the statement. The bindings are cleared regardless.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT NOT NULL);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?)")?;
+    ///
+    /// assert_eq!(stmt.step().unwrap_err(), Code::CONSTRAINT_NOTNULL);
+    /// assert_eq!(stmt.reset_and_clear().unwrap_err(), Code::CONSTRAINT_NOTNULL);
+    ///
+    /// // The error is only reported once.
+    /// stmt.reset_and_clear()?;
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Null};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("INSERT INTO users VALUES (?, ?)")?;
+    ///
+    /// stmt.bind_value(1, "Alice")?;
+    /// stmt.bind_value(2, 42)?;
+    /// assert!(stmt.step()?.is_done());
+    ///
+    /// stmt.reset_and_clear()?;
+    /// stmt.bind_value(1, "Bob")?;
+    /// assert!(stmt.step()?.is_done());
+    ///
+    /// let mut stmt = c.prepare("SELECT name, age FROM users")?;
+    /// assert_eq!(stmt.next::<(String, Option<i64>)>()?, Some((String::from("Alice"), Some(42))));
+    /// assert_eq!(stmt.next::<(String, Option<i64>)>()?, Some((String::from("Bob"), None)));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn reset_and_clear(&mut self) -> Result<()> {
+        let code = unsafe { ffi::sqlite3_reset(self.raw.as_ptr()) };
+        self.rows_stepped = 0;
+        self.halted = false;
+        self.clear_bindings()?;
+
+        if code != ffi::SQLITE_OK {
+            return Err(Error::new(Code::new(code), self.error_message()));
+        }
+
+        Ok(())
+    }
+
     /// Reset the statement and bind values to parameters.
     ///
     /// Note that this does not clear the bindings for any previous parameters
@@ -942,11 +1340,15 @@ impl Statement {
         value.bind_value(self, index)
     }
 
-    /// Return the index for a named parameter if exists.
+    /// Bind `NULL` to the parameter at the given index.
     ///
-    /// Note that this takes a c-string as the parameter name since that is what
-    /// the underlying API expects. To accomodate this, you can make use of the
-    /// `c"string"` syntax.
+    /// This is the same as binding [`Null`] through [`bind_value`].
+    ///
+    /// [`bind_value`]: Self::bind_value
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::RANGE`] if the index is out of range.
     ///
     /// # Examples
     ///
@@ -956,40 +1358,365 @@ impl Statement {
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
-    ///     CREATE TABLE users (name STRING)
-    /// "#);
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
     ///
-    /// let stmt = c.prepare("SELECT * FROM users WHERE name = :name")?;
-    /// assert_eq!(stmt.bind_parameter_index(c":name"), Some(1));
-    /// assert_eq!(stmt.bind_parameter_index(c":asdf"), None);
+    /// let mut stmt = c.prepare("INSERT INTO users (name, age) VALUES (?, ?)")?;
+    /// stmt.bind_value(1, "Alice")?;
+    /// stmt.bind_null(2)?;
+    /// assert!(stmt.step()?.is_done());
+    ///
+    /// let mut stmt = c.prepare("SELECT name, age FROM users")?;
+    /// assert_eq!(stmt.next::<(&str, Option<i64>)>()?, Some(("Alice", None)));
     /// # Ok::<_, sqll::Error>(())
     /// ```
     #[inline]
-    pub fn bind_parameter_index(&self, parameter: impl AsRef<CStr>) -> Option<c_int> {
-        let index = unsafe {
-            ffi::sqlite3_bind_parameter_index(self.raw.as_ptr(), parameter.as_ref().as_ptr())
-        };
-
-        match index {
-            0 => None,
-            _ => Some(index),
+    pub fn bind_null(&mut self, index: c_int) -> Result<()> {
+        unsafe {
+            sqlite3_try! {
+                self, ffi::sqlite3_bind_null(self.as_ptr_mut(), index)
+            };
         }
+
+        Ok(())
     }
 
-    /// Return the number of columns in the result set returned by the
-    /// [`Statement`]. If this routine returns 0, that means the [`Statement`]
-    /// returns no data (for example an `UPDATE`).
+    /// Bind a value to a named parameter.
     ///
-    /// However, just because this routine returns a positive number does not
-    /// mean that one or more rows of data will be returned.
+    /// This takes a c-string as the parameter name, see
+    /// [`bind_parameter_index`] for details. If the parameter name is only
+    /// available as a regular string, use [`bind_by_name_str`] instead.
     ///
-    /// A `SELECT` statement will always have a positive
-    /// [`column_count`] but depending on the `WHERE` clause constraints
-    /// and the table content, it might return no rows.
+    /// [`bind_parameter_index`]: Self::bind_parameter_index
+    /// [`bind_by_name_str`]: Self::bind_by_name_str
     ///
-    /// [`column_count`]: Self::column_count
+    /// # Errors
     ///
-    /// # Examples
+    /// Errors with [`Code::RANGE`] if the named parameter does not exist.
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING)
+    /// "#);
+    ///
+    /// let mut stmt = c.prepare("SELECT * FROM users WHERE name = :name")?;
+    /// let e = stmt.bind_by_name(c":asdf", "Bob").unwrap_err();
+    /// assert_eq!(e.code(), Code::RANGE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    /// "#);
+    ///
+    /// let mut stmt = c.prepare("SELECT age FROM users WHERE name = :name")?;
+    /// stmt.bind_by_name(c":name", "Bob")?;
+    ///
+    /// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(72)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn bind_by_name(&mut self, name: impl AsRef<CStr>, value: impl BindValue) -> Result<()> {
+        let Some(index) = self.bind_parameter_index(name) else {
+            return Err(Error::new(Code::RANGE, "no such parameter"));
+        };
+
+        value.bind_value(self, index)
+    }
+
+    /// Bind a value to a named parameter using a regular string slice as the
+    /// parameter name.
+    ///
+    /// This looks up the parameter using [`bind_parameter_index_str`] which
+    /// does not allocate.
+    ///
+    /// [`bind_parameter_index_str`]: Self::bind_parameter_index_str
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::RANGE`] if the named parameter does not exist.
+    ///
+    /// ```
+    /// use sqll::{Connection, Code};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING)
+    /// "#);
+    ///
+    /// let mut stmt = c.prepare("SELECT * FROM users WHERE name = :name")?;
+    /// let e = stmt.bind_by_name_str(":asdf", "Bob").unwrap_err();
+    /// assert_eq!(e.code(), Code::RANGE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    /// "#);
+    ///
+    /// let parameter = String::from(":name");
+    ///
+    /// let mut stmt = c.prepare("SELECT age FROM users WHERE name = :name")?;
+    /// stmt.bind_by_name_str(&parameter, "Alice")?;
+    ///
+    /// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn bind_by_name_str(&mut self, name: &str, value: impl BindValue) -> Result<()> {
+        let Some(index) = self.bind_parameter_index_str(name) else {
+            return Err(Error::new(Code::RANGE, "no such parameter"));
+        };
+
+        value.bind_value(self, index)
+    }
+
+    /// Bind every entry in a map to the named parameter with the same name.
+    ///
+    /// The keys of the map are parameter names including their prefix, such
+    /// as `:name`, which are looked up using [`bind_parameter_index_str`]. This
+    /// is useful for query layers which assemble named parameters at runtime.
+    ///
+    /// All parameters are checked before anything is bound, so if an error is
+    /// returned the statement is left unmodified.
+    ///
+    /// [`bind_parameter_index_str`]: Self::bind_parameter_index_str
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::RANGE`] if a key in the map does not correspond to
+    /// a parameter in the statement, and with [`Code::MISUSE`] if a named
+    /// parameter in the statement does not have a corresponding key in the
+    /// map.
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use sqll::{Code, Connection, Value};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT :a + :b")?;
+    ///
+    /// let params = HashMap::from([(":a", Value::integer(1))]);
+    /// let e = stmt.bind_named_map(&params).unwrap_err();
+    /// assert_eq!(e.code(), Code::MISUSE);
+    ///
+    /// let params = HashMap::from([(":a", Value::integer(1)), (":b", Value::integer(2)), (":c", Value::integer(3))]);
+    /// let e = stmt.bind_named_map(&params).unwrap_err();
+    /// assert_eq!(e.code(), Code::RANGE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use sqll::{Connection, Value};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+    /// "#)?;
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert(":name", Value::text("Bob"));
+    /// params.insert(":age", Value::integer(50));
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users WHERE name = :name AND age > :age")?;
+    /// stmt.bind_named_map(&params)?;
+    ///
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Bob"));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    pub fn bind_named_map<K, V, S>(&mut self, params: &HashMap<K, V, S>) -> Result<()>
+    where
+        K: Borrow<str> + Hash + Eq,
+        V: BindValue,
+        S: BuildHasher,
+    {
+        for name in params.keys() {
+            let name = name.borrow();
+
+            if self.bind_parameter_index_str(name).is_none() {
+                return Err(Error::new(
+                    Code::RANGE,
+                    format_args!("no such parameter {name}"),
+                ));
+            }
+        }
+
+        for index in 1..=self.bind_parameter_count() {
+            let Some(name) = self.bind_parameter_name(index) else {
+                continue;
+            };
+
+            let is_bound = match name.to_str() {
+                Ok(name) => params.contains_key(name),
+                Err(..) => false,
+            };
+
+            if !is_bound {
+                return Err(Error::new(
+                    Code::MISUSE,
+                    format_args!("parameter {name} is not bound"),
+                ));
+            }
+        }
+
+        for (name, value) in params {
+            self.bind_by_name_str(name.borrow(), value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Return the index for a named parameter if exists.
+    ///
+    /// Note that this takes a c-string as the parameter name since that is what
+    /// the underlying API expects. To accomodate this, you can make use of the
+    /// `c"string"` syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING)
+    /// "#);
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users WHERE name = :name")?;
+    /// assert_eq!(stmt.bind_parameter_index(c":name"), Some(1));
+    /// assert_eq!(stmt.bind_parameter_index(c":asdf"), None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn bind_parameter_index(&self, parameter: impl AsRef<CStr>) -> Option<c_int> {
+        let index = unsafe {
+            ffi::sqlite3_bind_parameter_index(self.raw.as_ptr(), parameter.as_ref().as_ptr())
+        };
+
+        match index {
+            0 => None,
+            _ => Some(index),
+        }
+    }
+
+    /// Return the index for a named parameter if exists.
+    ///
+    /// Unlike [`bind_parameter_index`], this accepts a regular string slice
+    /// which is convenient when the parameter name is only known at runtime.
+    /// The lookup is performed without allocating by comparing against each
+    /// [`bind_parameter_name`] in the statement.
+    ///
+    /// [`bind_parameter_index`]: Self::bind_parameter_index
+    /// [`bind_parameter_name`]: Self::bind_parameter_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING, age INTEGER)
+    /// "#);
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users WHERE name = :name AND age = :age")?;
+    /// assert_eq!(stmt.bind_parameter_index_str(":name"), Some(1));
+    /// assert_eq!(stmt.bind_parameter_index_str(":age"), Some(2));
+    /// assert_eq!(stmt.bind_parameter_index_str(":asdf"), None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn bind_parameter_index_str(&self, parameter: &str) -> Option<c_int> {
+        for index in 1..=self.bind_parameter_count() {
+            let Some(name) = self.bind_parameter_name(index) else {
+                continue;
+            };
+
+            if name.as_bytes() == parameter.as_bytes() {
+                return Some(index);
+            }
+        }
+
+        None
+    }
+
+    /// Return the number of parameters in the statement.
+    ///
+    /// This is the largest parameter index, which is not necessarily the
+    /// number of unique parameters since parameters of the form `?NNN` or
+    /// named parameters which are reused might leave gaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING, age INTEGER)
+    /// "#);
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users WHERE name = :name AND age = :age")?;
+    /// assert_eq!(stmt.bind_parameter_count(), 2);
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users")?;
+    /// assert_eq!(stmt.bind_parameter_count(), 0);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn bind_parameter_count(&self) -> c_int {
+        unsafe { ffi::sqlite3_bind_parameter_count(self.raw.as_ptr()) }
+    }
+
+    /// Return the number of columns in the result set returned by the
+    /// [`Statement`]. If this routine returns 0, that means the [`Statement`]
+    /// returns no data (for example an `UPDATE`).
+    ///
+    /// However, just because this routine returns a positive number does not
+    /// mean that one or more rows of data will be returned.
+    ///
+    /// A `SELECT` statement will always have a positive
+    /// [`column_count`] but depending on the `WHERE` clause constraints
+    /// and the table content, it might return no rows.
+    ///
+    /// [`column_count`]: Self::column_count
+    ///
+    /// # Examples
     ///
     /// ```
     /// use sqll::Connection;
@@ -1021,6 +1748,41 @@ impl Statement {
         unsafe { ffi::sqlite3_column_count(self.raw.as_ptr()) }
     }
 
+    /// Test if the statement produces result columns.
+    ///
+    /// This is the same as checking if [`column_count`] is positive, and can
+    /// be used to distinguish statements which return data, such as `SELECT`,
+    /// from ones that don't, such as `INSERT` or `UPDATE`. Note that
+    /// statements which have results might still not produce any rows.
+    ///
+    /// The column count is not cached, since it might change if the statement
+    /// is automatically re-prepared after a schema change.
+    ///
+    /// [`column_count`]: Self::column_count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users")?;
+    /// assert!(stmt.has_results());
+    ///
+    /// let stmt = c.prepare("INSERT INTO users VALUES ('Alice', 42)")?;
+    /// assert!(!stmt.has_results());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn has_results(&self) -> bool {
+        self.column_count() > 0
+    }
+
     /// Return the name of a column.
     ///
     /// Note that column names might internally undergo some normalization by
@@ -1078,6 +1840,38 @@ impl Statement {
         unsafe { c_to_text(ffi::sqlite3_column_name(self.raw.as_ptr(), index)) }
     }
 
+    /// Return the index of the first column with the given name if it exists.
+    ///
+    /// The name is compared exactly against [`column_name`], which for
+    /// columns with an `AS` clause is the name given in it.
+    ///
+    /// [`column_name`]: Self::column_name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let stmt = c.prepare("SELECT age, name AS username FROM users")?;
+    ///
+    /// assert_eq!(stmt.column_index("age"), Some(0));
+    /// assert_eq!(stmt.column_index("username"), Some(1));
+    /// assert_eq!(stmt.column_index("name"), None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn column_index(&self, name: &str) -> Option<c_int> {
+        self.columns().find(|&index| {
+            self.column_name(index)
+                .is_some_and(|n| n.as_bytes() == name.as_bytes())
+        })
+    }
+
     /// Return an iterator of column indexes.
     ///
     /// Column names are visible even when a prepared statement has not been
@@ -1206,9 +2000,15 @@ impl Statement {
         unsafe { ValueType::new(ffi::sqlite3_column_type(self.raw.as_ptr(), index)) }
     }
 
-    /// Return the name for a bind parameter if it exists.
+    /// Return the declared type of a column.
+    ///
+    /// If the column is taken directly from a table, this is the type that was
+    /// used when the column was declared in `CREATE TABLE`. Otherwise, such as
+    /// if the column is the result of an expression or the column was declared
+    /// without a type, `None` is returned.
     ///
-    /// If it does not exit, `None` is returned.
+    /// Unlike [`Statement::column_type`], the declared type is available
+    /// before the statement has been stepped.
     ///
     /// # Examples
     ///
@@ -1218,12 +2018,174 @@ impl Statement {
     /// let c = Connection::open_in_memory()?;
     ///
     /// c.execute(r#"
-    ///     CREATE TABLE users (name STRING)
-    /// "#);
+    ///     CREATE TABLE users (name VARCHAR(255), age INTEGER, extra);
+    /// "#)?;
+    ///
+    /// let stmt = c.prepare("SELECT name, age, extra, age + 1 FROM users")?;
+    ///
+    /// assert_eq!(stmt.column_decltype(0), Some(Text::new("VARCHAR(255)")));
+    /// assert_eq!(stmt.column_decltype(1), Some(Text::new("INTEGER")));
+    /// assert_eq!(stmt.column_decltype(2), None);
+    /// assert_eq!(stmt.column_decltype(3), None);
+    /// assert_eq!(stmt.column_decltype(4), None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn column_decltype(&self, index: c_int) -> Option<&Text> {
+        unsafe { c_to_text(ffi::sqlite3_column_decltype(self.raw.as_ptr(), index)) }
+    }
+
+    /// Get the normalized SQL text of the statement.
+    ///
+    /// Normalization replaces literals with `?` placeholders and canonicalizes
+    /// whitespace and keywords, so queries which only differ in the literal
+    /// values they use have the same normalized form. This makes it useful as
+    /// a key when aggregating metrics per query.
+    ///
+    /// Returns `None` if the normalized text could not be produced, such as if
+    /// memory allocation failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let a = c.prepare("SELECT name FROM users WHERE age > 30")?;
+    /// let b = c.prepare("select name from users where age > 72")?;
+    ///
+    /// assert_eq!(a.normalized_sql(), b.normalized_sql());
+    /// assert!(a.normalized_sql().is_some());
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(all(feature = "bundled", feature = "normalize"))]
+    #[cfg_attr(docsrs, cfg(all(feature = "bundled", feature = "normalize")))]
+    #[inline]
+    pub fn normalized_sql(&self) -> Option<&Text> {
+        unsafe { c_to_text(ffi::sqlite3_normalized_sql(self.raw.as_ptr())) }
+    }
+
+    /// Verify that the columns produced by this statement match an expected
+    /// shape.
+    ///
+    /// Each expected column is specified as a name and the [`Affinity`] of its
+    /// declared type as determined by [`Affinity::of`]. Columns which have no
+    /// declared type, such as expressions, have the [`Affinity::BLOB`]
+    /// affinity.
+    ///
+    /// This can be used before stepping the statement to catch cases where the
+    /// columns of a query no longer match what it is decoded into, such as
+    /// after a schema migration.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::SCHEMA`] if the number of columns, the name of any
+    /// column, or the affinity of any column differs from what is expected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Affinity, Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    /// "#)?;
+    ///
+    /// let stmt = c.prepare("SELECT name, age FROM users")?;
+    /// stmt.verify_schema(&[("name", Affinity::TEXT), ("age", Affinity::INTEGER)])?;
+    ///
+    /// let e = stmt.verify_schema(&[("name", Affinity::TEXT)]).unwrap_err();
+    /// assert_eq!(e.code(), Code::SCHEMA);
+    ///
+    /// let e = stmt.verify_schema(&[("name", Affinity::TEXT), ("age", Affinity::REAL)]).unwrap_err();
+    /// assert_eq!(e.code(), Code::SCHEMA);
+    ///
+    /// c.execute("ALTER TABLE users RENAME COLUMN age TO years")?;
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users")?;
+    /// let e = stmt.verify_schema(&[("name", Affinity::TEXT), ("age", Affinity::INTEGER)]).unwrap_err();
+    /// assert_eq!(e.code(), Code::SCHEMA);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn verify_schema(&self, expected: &[(&str, Affinity)]) -> Result<()> {
+        let count = self.column_count();
+
+        if usize::try_from(count).ok() != Some(expected.len()) {
+            return Err(Error::new(
+                Code::SCHEMA,
+                format_args!("expected {} columns but found {count}", expected.len()),
+            ));
+        }
+
+        for (index, &(name, affinity)) in (0..count).zip(expected) {
+            let Some(actual) = self.column_name(index) else {
+                return Err(Error::new(
+                    Code::SCHEMA,
+                    format_args!("missing name for column {index}"),
+                ));
+            };
+
+            if actual.as_bytes() != name.as_bytes() {
+                return Err(Error::new(
+                    Code::SCHEMA,
+                    format_args!(
+                        "expected column {index} to be named `{name}` but found `{actual}`"
+                    ),
+                ));
+            }
+
+            let decltype = self.column_decltype(index).map(Text::as_bytes);
+            let actual = Affinity::of_bytes(decltype.unwrap_or_default());
+
+            if actual != affinity {
+                return Err(Error::new(
+                    Code::SCHEMA,
+                    format_args!(
+                        "expected column `{name}` to have affinity {affinity} but found {actual}"
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return the name for a bind parameter if it exists.
+    ///
+    /// This is the reverse of [`bind_parameter_index`]. The returned name
+    /// includes its prefix, such as `:`, `@`, or `$`. Parameters of the form
+    /// `?NNN` are named `"?NNN"`.
+    ///
+    /// If the parameter does not exist or is an anonymous `?` parameter,
+    /// `None` is returned.
+    ///
+    /// [`bind_parameter_index`]: Self::bind_parameter_index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Text};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name STRING, age INTEGER)
+    /// "#);
     ///
     /// let stmt = c.prepare("SELECT * FROM users WHERE name = :name")?;
     /// assert_eq!(stmt.bind_parameter_name(1), Some(Text::new(":name")));
     /// assert_eq!(stmt.bind_parameter_name(2), None);
+    ///
+    /// let stmt = c.prepare("SELECT * FROM users WHERE name = ? AND age = :age")?;
+    /// assert_eq!(stmt.bind_parameter_name(1), None);
+    /// assert_eq!(stmt.bind_parameter_name(2), Some(Text::new(":age")));
     /// # Ok::<_, sqll::Error>(())
     /// ```
     #[inline]
@@ -1231,6 +2193,61 @@ impl Statement {
         unsafe { c_to_text(ffi::sqlite3_bind_parameter_name(self.raw.as_ptr(), index)) }
     }
 
+    /// Set the maximum size in bytes of text and blob columns which can be
+    /// read from this statement.
+    ///
+    /// Reading a text or blob column which is larger than this fails with
+    /// [`Code::TOOBIG`] before anything is allocated or copied. This applies to
+    /// every way of reading a column, including borrowed reads and sinks. It's
+    /// useful to bound the amount of memory used when reading untrusted
+    /// databases.
+    ///
+    /// By default there is no limit other than the ones imposed by sqlite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT);
+    ///
+    ///     INSERT INTO users VALUES ('Alice'), ('Bartholomew');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users")?;
+    /// stmt.set_max_column_size(8);
+    ///
+    /// assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+    ///
+    /// let e = stmt.next::<String>().unwrap_err();
+    /// assert_eq!(e.code(), Code::TOOBIG);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn set_max_column_size(&mut self, size: usize) {
+        self.max_column_size = size;
+    }
+
+    /// Check that a column of the given size doesn't exceed the size set
+    /// through [`Statement::set_max_column_size`].
+    #[inline]
+    pub(crate) fn check_column_size(&self, len: usize) -> Result<()> {
+        if len > self.max_column_size {
+            return Err(Error::new(
+                Code::TOOBIG,
+                format_args!(
+                    "column size {len} exceeds maximum of {}",
+                    self.max_column_size
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Read a value from the entire row using the [`Row`] trait.
     ///
     /// This is usually implemented using the [`Row` derive].
@@ -1321,6 +2338,279 @@ impl Statement {
         T::from_column(self, prepare)
     }
 
+    /// Get a tuple of values from consecutive columns through
+    /// [`FromColumns`], starting at the given index.
+    ///
+    /// This is useful when only part of a row should be read into a tuple,
+    /// such as when using a pair of columns as a composite key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE cells (x INTEGER, y INTEGER, value TEXT);
+    ///
+    ///     INSERT INTO cells VALUES (0, 0, 'a'), (0, 1, 'b'), (1, 0, 'c');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT x, y, value FROM cells")?;
+    /// let mut cells = HashMap::new();
+    ///
+    /// while stmt.step()?.is_row() {
+    ///     let key = stmt.get_tuple::<(i64, i64)>(0)?;
+    ///     cells.insert(key, stmt.column::<String>(2)?);
+    /// }
+    ///
+    /// assert_eq!(cells[&(0, 1)], "b");
+    /// assert_eq!(cells[&(1, 0)], "c");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn get_tuple<'stmt, T>(&'stmt mut self, start: c_int) -> Result<T>
+    where
+        T: FromColumns<'stmt>,
+    {
+        T::from_columns(self, start)
+    }
+
+    /// Get a single value from the column with the given name through
+    /// [`FromColumn`].
+    ///
+    /// The column is looked up using [`column_index`], so if several columns
+    /// share the same name the first one is used. This makes reading robust to
+    /// columns being reordered, at the cost of a lookup for every call.
+    ///
+    /// [`column_index`]: Self::column_index
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::RANGE`] if there is no column with the given name.
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1 AS one")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// let e = stmt.get_by_name::<i64>("two").unwrap_err();
+    /// assert_eq!(e.code(), Code::RANGE);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 72);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT age, name FROM users")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// assert_eq!(stmt.get_by_name::<String>("name")?, "Alice");
+    /// assert_eq!(stmt.get_by_name::<i64>("age")?, 72);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    pub fn get_by_name<'stmt, T>(&'stmt mut self, name: &str) -> Result<T>
+    where
+        T: FromColumn<'stmt>,
+    {
+        let Some(index) = self.column_index(name) else {
+            return Err(Error::new(
+                Code::RANGE,
+                format_args!("no such column {name}"),
+            ));
+        };
+
+        self.column(index)
+    }
+
+    /// Decode the current row into a map from column name to [`Value`].
+    ///
+    /// This is intended for dynamic consumers which do not know the shape of
+    /// the rows ahead of time. `NULL` columns are represented as `None`. If
+    /// several columns share the same name, the last one wins. Use
+    /// [`Statement::columns_as_unique_map`] to error instead.
+    ///
+    /// Since this allocates a map for every row and decodes every column into
+    /// a dynamically typed value, it is considerably slower than decoding into
+    /// a typed [`Row`]. Prefer typed decoding where the columns are known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, Value};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER, photo BLOB);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42, NULL);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name, age, photo, age * 2 AS age FROM users")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// let map = stmt.columns_as_map()?;
+    /// assert_eq!(map.len(), 3);
+    /// assert_eq!(map["name"], Some(Value::text("Alice")));
+    /// assert_eq!(map["age"], Some(Value::integer(84)));
+    /// assert_eq!(map["photo"], None);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn columns_as_map(&mut self) -> Result<BTreeMap<&str, Option<Value<'_>>>> {
+        self.columns_into_map(false)
+    }
+
+    /// Decode the current row into a map from column name to [`Value`],
+    /// erroring if several columns share the same name.
+    ///
+    /// See [`Statement::columns_as_map`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Code, Connection};
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1 AS a, 2 AS b")?;
+    /// assert!(stmt.step()?.is_row());
+    /// assert_eq!(stmt.columns_as_unique_map()?.len(), 2);
+    ///
+    /// let mut stmt = c.prepare("SELECT 1 AS a, 2 AS a")?;
+    /// assert!(stmt.step()?.is_row());
+    /// let e = stmt.columns_as_unique_map().unwrap_err();
+    /// assert_eq!(e.code(), Code::ERROR);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, cfg(feature = "alloc"))]
+    pub fn columns_as_unique_map(&mut self) -> Result<BTreeMap<&str, Option<Value<'_>>>> {
+        self.columns_into_map(true)
+    }
+
+    /// Serialize the current row as a map from column name to value.
+    ///
+    /// Columns are serialized in order, and `NULL` columns are serialized as
+    /// the unit value which is `null` in JSON. See the [`Serialize`
+    /// implementation] of [`Value`] for how values are represented. If several
+    /// columns share the same name, the key is repeated.
+    ///
+    /// Like [`Statement::columns_as_map`] this allocates, so prefer a typed
+    /// [`Row`] where the columns are known.
+    ///
+    /// [`Serialize` implementation]: Value#impl-Serialize-for-Value<'_>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER, photo BLOB);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 42, X'0102'), ('Bob', NULL, NULL);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT * FROM users")?;
+    /// let mut rows = Vec::new();
+    ///
+    /// while stmt.step()?.is_row() {
+    ///     rows.push(serde_json::to_string(&stmt.serialize_row()?).unwrap());
+    /// }
+    ///
+    /// assert_eq!(rows, [
+    ///     r#"{"name":"Alice","age":42,"photo":"AQI="}"#,
+    ///     r#"{"name":"Bob","age":null,"photo":null}"#,
+    /// ]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, cfg(feature = "serde"))]
+    pub fn serialize_row(&mut self) -> Result<SerializeRow<'_>> {
+        let mut columns = Vec::with_capacity(self.column_count().max(0) as usize);
+
+        self.dynamic_columns(|name, value| {
+            columns.push((name, value));
+            Ok(())
+        })?;
+
+        Ok(SerializeRow::new(columns))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn columns_into_map(&mut self, unique: bool) -> Result<BTreeMap<&str, Option<Value<'_>>>> {
+        let mut map = BTreeMap::new();
+
+        self.dynamic_columns(|name, value| {
+            if map.insert(name, value).is_some() && unique {
+                return Err(Error::new(
+                    Code::ERROR,
+                    format_args!("duplicate column `{name}`"),
+                ));
+            }
+
+            Ok(())
+        })?;
+
+        Ok(map)
+    }
+
+    /// Decode every column in the current row into a name and a dynamic value.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn dynamic_columns<'stmt>(
+        &'stmt mut self,
+        mut f: impl FnMut(&'stmt str, Option<Value<'stmt>>) -> Result<()>,
+    ) -> Result<()> {
+        let count = self.column_count().max(0);
+        let mut checked = Vec::with_capacity(count as usize);
+
+        for index in 0..count {
+            checked.push(<Option<Value<'_>> as FromColumn<'_>>::Type::check(
+                self, index,
+            )?);
+        }
+
+        let this = &*self;
+
+        for (index, prepare) in (0..count).zip(checked) {
+            let Some(name) = this.column_name(index) else {
+                return Err(Error::new(
+                    Code::ERROR,
+                    format_args!("missing name for column {index}"),
+                ));
+            };
+
+            let Ok(name) = name.to_str() else {
+                return Err(Error::new(Code::MISMATCH, "column name is not valid UTF-8"));
+            };
+
+            f(name, Option::<Value<'_>>::from_column(this, prepare)?)?;
+        }
+
+        Ok(())
+    }
+
     /// Borrow a value from a column using the [`FromUnsizedColumn`] trait.
     ///
     /// The first column has index 0. The same column can be read multiple
@@ -1360,11 +2650,170 @@ impl Statement {
         let index = T::Type::check(self, index)?;
         T::from_unsized_column(self, index)
     }
+
+    /// Copy the bytes of a `TEXT` or `BLOB` column into a writer.
+    ///
+    /// This writes the column data directly from the statement without an
+    /// intermediate buffer, which is useful when piping large values into
+    /// something like a file, a socket, or a hasher. Returns the number of
+    /// bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Code::MISMATCH`] if the column is not of type `TEXT` or
+    /// `BLOB`, and with [`Code::IOERR`] if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    ///
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE documents (title TEXT, body BLOB);
+    ///
+    ///     INSERT INTO documents VALUES ('Greeting', X'48656C6C6F');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT title, body FROM documents")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// let mut out = Vec::new();
+    /// assert_eq!(stmt.copy_column_to(0, &mut out)?, 8);
+    /// out.write_all(b": ").unwrap();
+    /// assert_eq!(stmt.copy_column_to(1, &mut out)?, 5);
+    ///
+    /// assert_eq!(out, b"Greeting: Hello");
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, cfg(feature = "std"))]
+    pub fn copy_column_to<W>(&mut self, index: c_int, mut writer: W) -> Result<usize>
+    where
+        W: std::io::Write,
+    {
+        let bytes = match self.column_type(index) {
+            ValueType::TEXT => {
+                let index = ty::Text::check(self, index)?;
+                Text::from_unsized_column(self, index)?.as_bytes()
+            }
+            ValueType::BLOB => {
+                let index = ty::Blob::check(self, index)?;
+                <[u8]>::from_unsized_column(self, index)?
+            }
+            ty => {
+                return Err(Error::new(
+                    Code::MISMATCH,
+                    format_args!("column type {ty} cannot be copied, expected TEXT or BLOB"),
+                ));
+            }
+        };
+
+        match writer.write_all(bytes) {
+            Ok(()) => Ok(bytes.len()),
+            Err(e) => Err(Error::new(Code::IOERR, e)),
+        }
+    }
+
+    /// Write the column at the given index into a [`Sink`].
+    ///
+    /// Unlike [`column`], this replaces the contents of an existing buffer
+    /// which allows its allocation to be reused across rows.
+    ///
+    /// [`column`]: Self::column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, age INTEGER);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', 72);
+    ///     INSERT INTO users VALUES ('Bob', 40);
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name FROM users ORDER BY age")?;
+    /// let mut name = String::new();
+    /// let mut names = Vec::new();
+    ///
+    /// while stmt.step()?.is_row() {
+    ///     stmt.read_into_sink(0, &mut name)?;
+    ///     names.push(name.clone());
+    /// }
+    ///
+    /// assert_eq!(names, ["Bob", "Alice"]);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn read_into_sink<S>(&mut self, index: c_int, sink: &mut S) -> Result<()>
+    where
+        S: ?Sized + Sink,
+    {
+        sink.write_column(self, index)
+    }
+
+    /// Construct an iterator which writes each row produced by this statement
+    /// into the given [`Sink`], starting at the first column.
+    ///
+    /// Since the sink is reused for every row, this is a lending iterator which
+    /// doesn't implement [`Iterator`]. Instead rows are accessed through
+    /// its `next` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Connection;
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// c.execute(r#"
+    ///     CREATE TABLE users (name TEXT, photo BLOB);
+    ///
+    ///     INSERT INTO users VALUES ('Alice', X'0102');
+    ///     INSERT INTO users VALUES ('Bob', X'03');
+    /// "#)?;
+    ///
+    /// let mut stmt = c.prepare("SELECT name, photo FROM users")?;
+    /// let mut it = stmt.sink_iter((String::new(), Vec::new()));
+    ///
+    /// let mut total = 0;
+    ///
+    /// while let Some((name, photo)) = it.next()? {
+    ///     assert!(matches!(name.as_str(), "Alice" | "Bob"));
+    ///     total += photo.len();
+    /// }
+    ///
+    /// let (name, _) = it.into_sink();
+    /// assert_eq!(name, "Bob");
+    /// assert_eq!(total, 3);
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub fn sink_iter<S>(&mut self, sink: S) -> SinkIter<'_, S>
+    where
+        S: Sink,
+    {
+        SinkIter { stmt: self, sink }
+    }
 }
 
 impl Drop for Statement {
     #[inline]
     fn drop(&mut self) {
+        #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+        if let Some(cache) = &self.cache {
+            // SAFETY: The statement is not used after this point.
+            unsafe { cache.store(self.raw) };
+            return;
+        }
+
         unsafe { ffi::sqlite3_finalize(self.raw.as_ptr()) };
     }
 }
@@ -1417,6 +2866,38 @@ where
     }
 }
 
+/// A lending iterator which writes rows into a reusable [`Sink`].
+///
+/// See [`Statement::sink_iter`].
+pub struct SinkIter<'stmt, S> {
+    stmt: &'stmt mut Statement,
+    sink: S,
+}
+
+impl<S> SinkIter<'_, S>
+where
+    S: Sink,
+{
+    /// Step the statement and write the next row into the sink, returning a
+    /// reference to it or `None` if there are no more rows.
+    #[inline]
+    pub fn next(&mut self) -> Result<Option<&mut S>> {
+        match self.stmt.step()? {
+            State::Row => {
+                self.sink.write_column(self.stmt, 0)?;
+                Ok(Some(&mut self.sink))
+            }
+            State::Done => Ok(None),
+        }
+    }
+
+    /// Coerce the iterator into the underlying sink.
+    #[inline]
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+}
+
 /// An iterator over the column names of a statement.
 ///
 /// See [`Statement::column_names`].
@@ -0,0 +1,82 @@
+use core::ffi::CStr;
+use core::ptr::{NonNull, null_mut};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::ffi;
+
+/// A cache which holds on to a single statement.
+///
+/// Statements which are prepared while the cache is enabled hold a reference to
+/// it, and rather than being finalized when dropped they are reset and stored
+/// here. Storing a statement finalizes any statement which was previously
+/// cached.
+///
+/// The cache is shared between the connection and its statements since a
+/// statement is allowed to outlive the connection it was prepared from.
+pub(crate) struct StatementCache {
+    raw: AtomicPtr<ffi::sqlite3_stmt>,
+}
+
+impl StatementCache {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            raw: AtomicPtr::new(null_mut()),
+        }
+    }
+
+    /// Take the cached statement if it was prepared from the given SQL.
+    ///
+    /// A cached statement which does not match is finalized.
+    pub(crate) fn take(&self, sql: &str) -> Option<NonNull<ffi::sqlite3_stmt>> {
+        let raw = NonNull::new(self.raw.swap(null_mut(), Ordering::AcqRel))?;
+
+        unsafe {
+            let cached = ffi::sqlite3_sql(raw.as_ptr());
+
+            if !cached.is_null() && CStr::from_ptr(cached).to_bytes() == sql.as_bytes() {
+                return Some(raw);
+            }
+
+            ffi::sqlite3_finalize(raw.as_ptr());
+        }
+
+        None
+    }
+
+    /// Store a statement in the cache.
+    ///
+    /// # Safety
+    ///
+    /// The caller must transfer ownership of the statement to the cache.
+    pub(crate) unsafe fn store(&self, raw: NonNull<ffi::sqlite3_stmt>) {
+        unsafe {
+            ffi::sqlite3_reset(raw.as_ptr());
+            ffi::sqlite3_clear_bindings(raw.as_ptr());
+
+            let old = self.raw.swap(raw.as_ptr(), Ordering::AcqRel);
+
+            if !old.is_null() {
+                ffi::sqlite3_finalize(old);
+            }
+        }
+    }
+
+    /// Finalize the cached statement if there is one.
+    pub(crate) fn clear(&self) {
+        let old = self.raw.swap(null_mut(), Ordering::AcqRel);
+
+        if !old.is_null() {
+            unsafe {
+                ffi::sqlite3_finalize(old);
+            }
+        }
+    }
+}
+
+impl Drop for StatementCache {
+    #[inline]
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use arrayvec::ArrayVec;
+
+use crate::{Code, Connection};
+
+#[test]
+fn arrayvec_blobs() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE blobs (value BLOB);
+
+        INSERT INTO blobs VALUES (X''), (X'01020304'), (X'0102030405');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM blobs")?;
+
+    let empty = stmt.next::<ArrayVec<u8, 4>>()?.unwrap();
+    assert!(empty.is_empty());
+
+    let at_capacity = stmt.next::<ArrayVec<u8, 4>>()?.unwrap();
+    assert_eq!(at_capacity.as_slice(), &[1, 2, 3, 4]);
+
+    assert!(stmt.step()?.is_row());
+    let e = stmt.column::<ArrayVec<u8, 4>>(0).unwrap_err();
+    assert_eq!(e, Code::MISMATCH);
+
+    let beyond_capacity = stmt.column::<ArrayVec<u8, 5>>(0)?;
+    assert_eq!(beyond_capacity.as_slice(), &[1, 2, 3, 4, 5]);
+
+    let mut stmt = c.prepare("SELECT 'text'")?;
+    let e = stmt.next::<ArrayVec<u8, 4>>().unwrap_err();
+    assert_eq!(e, Code::MISMATCH);
+    Ok(())
+}
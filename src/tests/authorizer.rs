@@ -0,0 +1,154 @@
+use std::string::{String, ToString};
+use std::sync::{Arc, Mutex};
+use std::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{AuthKind, AuthResult, Code, Connection};
+
+#[test]
+fn authorizer_records_actions() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER);
+        CREATE VIEW adults AS SELECT name FROM users WHERE age >= 18;
+
+        INSERT INTO users VALUES ('Alice', 42), ('Bob', 12);
+        "#,
+    )?;
+
+    let reads = Arc::new(Mutex::new(
+        Vec::<(String, String, String, Option<String>)>::new(),
+    ));
+
+    c.set_authorizer({
+        let reads = reads.clone();
+
+        move |action| {
+            if let AuthKind::Read { table, column } = action.kind() {
+                reads.lock().unwrap().push((
+                    action.database().map(|d| d.to_string()).unwrap_or_default(),
+                    table.to_string(),
+                    column.to_string(),
+                    action.accessor().map(|a| a.to_string()),
+                ));
+            }
+
+            AuthResult::Ok
+        }
+    })?;
+
+    let mut stmt = c.prepare("SELECT name FROM adults")?;
+    assert_eq!(
+        stmt.iter::<String>().collect::<Vec<_>>(),
+        [Ok(String::from("Alice"))]
+    );
+
+    let reads = reads.lock().unwrap();
+
+    let expected = [
+        (
+            String::from("main"),
+            String::from("users"),
+            String::from("name"),
+            Some(String::from("adults")),
+        ),
+        (
+            String::from("main"),
+            String::from("users"),
+            String::from("age"),
+            Some(String::from("adults")),
+        ),
+    ];
+
+    for entry in &expected {
+        assert!(reads.contains(entry), "missing {entry:?} in {reads:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn authorizer_deny_and_ignore() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, password TEXT);
+        CREATE TABLE audit (message TEXT);
+
+        INSERT INTO users VALUES ('Alice', 'hunter2');
+        "#,
+    )?;
+
+    c.set_authorizer(|action| match action.kind() {
+        AuthKind::Read { column, .. } if column == "password" => AuthResult::Ignore,
+        AuthKind::Insert { table } if table == "audit" => AuthResult::Deny,
+        AuthKind::Pragma { name, argument } if name == "journal_mode" && argument.is_some() => {
+            AuthResult::Deny
+        }
+        AuthKind::Function { name } if name == "random" => AuthResult::Deny,
+        _ => AuthResult::Ok,
+    })?;
+
+    let mut stmt = c.prepare("SELECT name, password FROM users")?;
+    assert_eq!(
+        stmt.next::<(String, Option<String>)>()?,
+        Some((String::from("Alice"), None))
+    );
+
+    let e = c.execute("INSERT INTO audit VALUES ('hello')").unwrap_err();
+    assert_eq!(e.code(), Code::AUTH);
+    assert_eq!(c.error_message(), "not authorized");
+
+    c.execute("INSERT INTO users VALUES ('Bob', 'password')")?;
+
+    let e = c.execute("PRAGMA journal_mode = OFF").unwrap_err();
+    assert_eq!(e.code(), Code::AUTH);
+
+    let mut stmt = c.prepare("PRAGMA journal_mode")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("memory"));
+
+    // Denied functions are reported as regular errors.
+    let e = c.prepare("SELECT random()").unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(c.error_message(), "not authorized to use function: random");
+
+    c.clear_authorizer()?;
+
+    let mut stmt = c.prepare("SELECT password FROM users WHERE name = 'Alice'")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("hunter2"));
+    Ok(())
+}
+
+#[test]
+fn authorizer_replaced_and_dropped() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    let calls = Arc::new(Mutex::new(0usize));
+
+    c.set_authorizer(|_| AuthResult::Deny)?;
+    assert_eq!(c.prepare("SELECT 1").unwrap_err().code(), Code::AUTH);
+
+    c.set_authorizer({
+        let calls = calls.clone();
+
+        move |_| {
+            *calls.lock().unwrap() += 1;
+            AuthResult::Ok
+        }
+    })?;
+
+    c.execute("CREATE TABLE test (value INTEGER)")?;
+    let mut stmt = c.prepare("SELECT value FROM test")?;
+    assert!(*calls.lock().unwrap() > 0);
+
+    // The statement outlives the connection, and the authorizer has to be
+    // unregistered before its callback is freed.
+    drop(c);
+    assert_eq!(Arc::strong_count(&calls), 1);
+    assert!(stmt.step()?.is_done());
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+#[cfg(feature = "std")]
+use crate::Value;
+use crate::{Code, Connection, Null, Text};
+
+use super::data;
+
+#[test]
+fn statement_bind_by_name_str() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let statement = "INSERT INTO users VALUES (:id, :name, :age, :photo, :email)";
+    let mut stmt = c.prepare(statement)?;
+
+    let names = [":id", ":name", ":age", ":photo", ":email"].map(String::from);
+
+    stmt.bind_by_name_str(&names[0], 2)?;
+    stmt.bind_by_name_str(&names[1], "Bob")?;
+    stmt.bind_by_name_str(&names[2], 69.42)?;
+    stmt.bind_by_name_str(&names[3], &[0x69u8, 0x42u8][..])?;
+    stmt.bind_by_name_str(&names[4], Null)?;
+    assert!(stmt.bind_by_name_str(":missing", Null).is_err());
+    assert!(stmt.step()?.is_done());
+
+    let mut stmt = c.prepare("SELECT name FROM users WHERE id = :id")?;
+    stmt.bind_by_name_str(&names[0], 2)?;
+    assert_eq!(stmt.next::<String>()?, Some(String::from("Bob")));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn statement_bind_named_map() -> Result<()> {
+    use std::collections::HashMap;
+
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let mut stmt = c.prepare("SELECT name FROM users WHERE id = :id AND name = :name")?;
+
+    let mut params = HashMap::new();
+    params.insert(String::from(":id"), Value::integer(1));
+
+    // A missing parameter leaves the statement unbound.
+    let e = stmt.bind_named_map(&params).unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+    assert_eq!(stmt.bind_parameter_index_str(":id"), Some(1));
+    assert_eq!(stmt.next::<String>()?, None);
+
+    params.insert(String::from(":name"), Value::text("Alice"));
+    params.insert(String::from(":extra"), Value::integer(0));
+
+    let e = stmt.bind_named_map(&params).unwrap_err();
+    assert_eq!(e.code(), Code::RANGE);
+
+    params.remove(":extra");
+    stmt.reset()?;
+    stmt.bind_named_map(&params)?;
+    assert_eq!(stmt.next::<String>()?, Some(String::from("Alice")));
+
+    // Anonymous parameters can't be bound by name and are ignored.
+    let mut stmt = c.prepare("SELECT ?, :value")?;
+    stmt.bind_named_map(&HashMap::from([(":value", 42)]))?;
+    assert_eq!(stmt.next::<(Option<i64>, i64)>()?, Some((None, 42)));
+    Ok(())
+}
+
+#[test]
+fn statement_bind_parameter_name() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let stmt = c.prepare("SELECT ?, :name, ?, @age, ?5, $city")?;
+    assert_eq!(stmt.bind_parameter_count(), 6);
+
+    let names = (1..=stmt.bind_parameter_count())
+        .map(|index| stmt.bind_parameter_name(index))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        names,
+        [
+            None,
+            Some(Text::new(":name")),
+            None,
+            Some(Text::new("@age")),
+            Some(Text::new("?5")),
+            Some(Text::new("$city")),
+        ]
+    );
+
+    for index in 1..=stmt.bind_parameter_count() {
+        if let Some(name) = stmt.bind_parameter_name(index) {
+            assert_eq!(stmt.bind_parameter_index_str(name.to_str()?), Some(index));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn statement_bind_null() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let mut stmt = c.prepare("UPDATE users SET age = ?, photo = ? WHERE id = 1")?;
+    stmt.bind_value(1, 10)?;
+    stmt.bind_null(2)?;
+    assert!(stmt.step()?.is_done());
+
+    stmt.reset()?;
+    let e = stmt.bind_null(3).unwrap_err();
+    assert_eq!(e.code(), Code::RANGE);
+
+    let mut stmt = c.prepare("SELECT age, photo FROM users WHERE id = 1")?;
+    assert_eq!(stmt.next::<(f64, Option<&[u8]>)>()?, Some((10.0, None)));
+    Ok(())
+}
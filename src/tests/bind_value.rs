@@ -0,0 +1,251 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{BIND_INDEX, BigInt, Code, Connection, Value};
+
+use super::data;
+
+#[test]
+fn bind_smart_pointers() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT typeof(?), ?")?;
+
+    macro_rules! test {
+        ($value:expr, $ty:literal, $expected:expr) => {
+            let value = $value;
+            stmt.bind_value(BIND_INDEX, &value)?;
+            stmt.bind_value(BIND_INDEX + 1, &value)?;
+            assert!(stmt.step()?.is_row());
+            assert_eq!(stmt.column::<&str>(0)?, $ty);
+            assert_eq!(stmt.column::<Value<'_>>(1)?, $expected);
+            stmt.reset()?;
+        };
+    }
+
+    test!(Arc::<str>::from("Hello"), "text", Value::text("Hello"));
+    test!(Arc::<[u8]>::from(*b"Hello"), "blob", Value::blob(b"Hello"));
+    test!(Rc::<str>::from("Hello"), "text", Value::text("Hello"));
+    test!(Rc::<[u8]>::from(*b"Hello"), "blob", Value::blob(b"Hello"));
+    test!(Box::<str>::from("Hello"), "text", Value::text("Hello"));
+    test!(Box::<[u8]>::from(*b"Hello"), "blob", Value::blob(b"Hello"));
+    Ok(())
+}
+
+#[test]
+fn bind_value_references() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT ?, ?, ?")?;
+
+    let value = Value::text("Alice");
+    let values = [Value::integer(1), Value::float(2.5), Value::blob(b"\x03")];
+
+    stmt.bind_value(BIND_INDEX, &value)?;
+    assert_eq!(stmt.next::<Value<'_>>()?, Some(Value::text("Alice")));
+
+    stmt.bind(&values[..])?;
+    assert!(stmt.step()?.is_row());
+    let [a, b, c] = values.clone();
+    assert_eq!(stmt.row::<(Value<'_>, Value<'_>, Value<'_>)>()?, (a, b, c));
+
+    let refs: &[Value<'_>] = &values[..2];
+    stmt.clear_bindings()?;
+    stmt.bind(refs)?;
+    assert!(stmt.step()?.is_row());
+    assert_eq!(stmt.column::<Value<'_>>(1)?, Value::float(2.5));
+    assert_eq!(stmt.column::<Option<Value<'_>>>(2)?, None);
+
+    let values = values.to_vec();
+    stmt.bind(values.as_slice())?;
+    assert!(stmt.step()?.is_row());
+    assert_eq!(stmt.column::<Value<'_>>(2)?, Value::blob(b"\x03"));
+    Ok(())
+}
+
+#[test]
+fn reset_preserves_bindings() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT ?, ?")?;
+
+    stmt.bind((1, 2))?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((1, 2)));
+
+    // Both `reset` and `bind` preserve bindings which aren't overriden.
+    stmt.reset()?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((1, 2)));
+
+    stmt.bind(3)?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((3, 2)));
+
+    stmt.reset()?;
+    stmt.bind_value(BIND_INDEX + 1, 4)?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((3, 4)));
+
+    // Clearing bindings resets all parameters to `NULL`.
+    stmt.reset_and_clear()?;
+    stmt.bind_value(BIND_INDEX, 5)?;
+    assert_eq!(stmt.next::<(i64, Option<i64>)>()?, Some((5, None)));
+
+    stmt.reset()?;
+    stmt.clear_bindings()?;
+    assert_eq!(
+        stmt.next::<(Option<i64>, Option<i64>)>()?,
+        Some((None, None))
+    );
+    Ok(())
+}
+
+#[test]
+fn big_int_round_trip() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (text_value TEXT, any_value);
+        "#,
+    )?;
+
+    let mut insert = c.prepare("INSERT INTO numbers (text_value, any_value) VALUES (?, ?)")?;
+    let mut select = c.prepare("SELECT typeof(text_value), text_value, any_value FROM numbers")?;
+
+    macro_rules! test {
+        ($ty:ty, $($value:expr),* $(,)?) => {
+            $(
+                let value = BigInt::<$ty>::new($value);
+                insert.execute((value, value))?;
+
+                assert!(select.step()?.is_row());
+                assert_eq!(select.column::<&str>(0)?, "text");
+                assert_eq!(select.column::<BigInt<$ty>>(1)?, value);
+                assert_eq!(select.column::<BigInt<$ty>>(2)?, value);
+                assert!(select.step()?.is_done());
+
+                c.execute("DELETE FROM numbers")?;
+                select.reset()?;
+            )*
+        };
+    }
+
+    test!(u128, u128::MAX, u128::MIN, 42);
+    test!(i128, i128::MAX, i128::MIN, -42);
+    test!(u64, u64::MAX, u64::MIN);
+    test!(i64, i64::MAX, i64::MIN);
+
+    c.execute("CREATE TABLE integers (value INTEGER)")?;
+
+    let mut insert = c.prepare("INSERT INTO integers (value) VALUES (?)")?;
+    insert.execute(BigInt::new(42u128))?;
+    insert.execute(BigInt::new(u128::MAX))?;
+
+    let mut select = c.prepare("SELECT typeof(value), value FROM integers")?;
+    assert!(select.step()?.is_row());
+    assert_eq!(select.column::<&str>(0)?, "integer");
+    assert_eq!(select.column::<BigInt<u128>>(1)?, BigInt::new(42));
+    assert!(select.step()?.is_row());
+    assert_eq!(select.column::<&str>(0)?, "real");
+    let e = select.column::<BigInt<u128>>(1).unwrap_err();
+    assert_eq!(e.code(), Code::MISMATCH);
+
+    let mut stmt = c.prepare("SELECT ?")?;
+    stmt.bind(BigInt::new(u128::MAX))?;
+    assert!(stmt.step()?.is_row());
+    assert_eq!(
+        stmt.column::<&str>(0)?,
+        "340282366920938463463374607431768211455"
+    );
+    Ok(())
+}
+
+#[test]
+fn big_int_errors() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT 'not a number', -1, 3.14, X'00'")?;
+    assert!(stmt.step()?.is_row());
+
+    for index in 0..4 {
+        let e = stmt.column::<BigInt<u128>>(index).unwrap_err();
+        assert_eq!(e.code(), Code::MISMATCH);
+    }
+
+    assert_eq!(stmt.column::<BigInt<i128>>(1)?, BigInt::new(-1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn bind_path() -> Result<()> {
+    use std::path::{Path, PathBuf};
+
+    let c = Connection::open_in_memory()?;
+
+    c.execute("CREATE TABLE files (path TEXT)")?;
+
+    let path = Path::new("src").join("tests").join("data.rs");
+
+    let mut stmt = c.prepare("INSERT INTO files (path) VALUES (?)")?;
+    stmt.execute(&path)?;
+    stmt.execute(Path::new("/tmp/ünïcödé.txt"))?;
+
+    let mut stmt = c.prepare("SELECT path FROM files")?;
+    assert_eq!(stmt.next::<PathBuf>()?, Some(path));
+    assert_eq!(
+        stmt.next::<PathBuf>()?,
+        Some(PathBuf::from("/tmp/ünïcödé.txt"))
+    );
+    assert_eq!(stmt.next::<PathBuf>()?, None);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "std", unix))]
+fn bind_path_non_utf8() -> Result<()> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    let c = Connection::open_in_memory()?;
+
+    let path = Path::new(OsStr::from_bytes(b"invalid\xff.txt"));
+
+    let mut stmt = c.prepare("SELECT ?")?;
+    let e = stmt.bind(path).unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+    Ok(())
+}
+
+#[test]
+fn statement_bind_unit() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let mut stmt = c.prepare("INSERT INTO users VALUES (?, ?, ?, ?, ?)")?;
+
+    stmt.bind_value(1, 2i64)?;
+    stmt.bind_value(2, "Bob")?;
+    stmt.bind_value(3, ())?;
+    stmt.bind_value(4, ())?;
+    stmt.bind_value(5, ())?;
+    assert!(stmt.step()?.is_done());
+
+    stmt.reset()?;
+    stmt.bind((3i64, "Charlie", 20.0, (), ()))?;
+    assert!(stmt.step()?.is_done());
+
+    let mut stmt =
+        c.prepare("SELECT name FROM users WHERE age IS NULL AND photo IS NULL AND email IS NULL")?;
+    assert_eq!(stmt.next::<&str>()?, Some("Bob"));
+    assert_eq!(stmt.next::<&str>()?, None);
+
+    let mut stmt =
+        c.prepare("SELECT name FROM users WHERE photo IS NULL AND email IS NULL ORDER BY id")?;
+    assert_eq!(stmt.next::<&str>()?, Some("Bob"));
+    assert_eq!(stmt.next::<&str>()?, Some("Charlie"));
+    assert_eq!(stmt.next::<&str>()?, None);
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use core::ffi::c_int;
+
+use anyhow::Result;
+
+use crate::{Connection, Statement, ffi};
+
+use super::allocator::allocations;
+
+fn column_text(stmt: &Statement, index: c_int) -> *const u8 {
+    unsafe { ffi::sqlite3_column_text(stmt.as_ptr(), index) }
+}
+
+fn column_blob(stmt: &Statement, index: c_int) -> *const u8 {
+    unsafe { ffi::sqlite3_column_blob(stmt.as_ptr(), index).cast() }
+}
+
+#[test]
+fn option_borrows_from_statement() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, photo BLOB);
+
+        INSERT INTO users VALUES ('Alice', X'0102'), (NULL, NULL);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, photo FROM users")?;
+
+    assert!(stmt.step()?.is_row());
+
+    let before = allocations();
+    let (name, photo) = stmt.row::<(Option<&str>, Option<&[u8]>)>()?;
+    assert_eq!(allocations(), before);
+
+    let (name, photo) = (name.unwrap(), photo.unwrap());
+    assert_eq!(name, "Alice");
+    assert_eq!(photo, [1, 2]);
+
+    // The borrowed values point directly into the statement buffers.
+    let (name, photo) = (name.as_ptr(), photo.as_ptr());
+    assert_eq!(name, column_text(&stmt, 0));
+    assert_eq!(photo, column_blob(&stmt, 1));
+
+    assert!(stmt.step()?.is_row());
+
+    let before = allocations();
+    let row = stmt.row::<(Option<&str>, Option<&[u8]>)>()?;
+    assert_eq!(allocations(), before);
+    assert_eq!(row, (None, None));
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::Connection;
+
+use super::allocator::allocations;
+
+#[test]
+fn boxed_columns_have_no_spare_capacity() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, photo BLOB);
+
+        INSERT INTO users VALUES ('Alice', X'010203'), ('', X'');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, photo FROM users")?;
+
+    assert!(stmt.step()?.is_row());
+
+    // Converting into a box must not reallocate to shed spare capacity.
+    let before = allocations();
+    let (name, photo) = stmt.row::<(Box<str>, Box<[u8]>)>()?;
+    assert_eq!(allocations(), before + 2);
+
+    assert_eq!(&*name, "Alice");
+    assert_eq!(&*photo, [1, 2, 3]);
+
+    let name = String::from(name);
+    let photo = Vec::from(photo);
+    assert_eq!(name.capacity(), name.len());
+    assert_eq!(photo.capacity(), photo.len());
+
+    assert!(stmt.step()?.is_row());
+
+    let (name, photo) = stmt.row::<(Box<str>, Box<[u8]>)>()?;
+    assert!(name.is_empty());
+    assert!(photo.is_empty());
+    Ok(())
+}
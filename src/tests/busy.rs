@@ -1,10 +1,15 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
 use std::thread;
+use std::time::Duration;
 
+use alloc::string::ToString;
 use alloc::vec::Vec;
 
 use anyhow::{Context, Result};
 
-use crate::{Code, Connection, Null, OpenOptions};
+use crate::{Code, Connection, Error, Null, OpenOptions};
 
 use super::data;
 
@@ -28,6 +33,69 @@ fn connection_open_with_flags() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn connection_open_stored_options() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let options = *OpenOptions::new().read_write().create();
+
+    let mut a = options.open(&path)?;
+    data::users(&mut a)?;
+
+    let b = options.open(&path)?;
+    b.execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")?;
+
+    let mut stmt = a.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(2));
+    Ok(())
+}
+
+#[test]
+fn connection_open_busy_timeout() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let options = *OpenOptions::new().read_write().create().busy_timeout(5000);
+
+    let mut a = options.open(&path)?;
+    assert_eq!(a.get_busy_timeout(), 5000);
+    data::users(&mut a)?;
+
+    // The busy timeout waits for the other connection to commit.
+    a.execute("BEGIN IMMEDIATE")?;
+
+    let writer = thread::spawn({
+        let path = path.clone();
+
+        move || -> Result<()> {
+            let b = options.open(path)?;
+            assert_eq!(b.get_busy_timeout(), 5000);
+            b.execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")?;
+            Ok(())
+        }
+    });
+
+    thread::sleep(Duration::from_millis(50));
+    a.execute("COMMIT")?;
+    writer.join().unwrap()?;
+
+    let mut stmt = a.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(2));
+    drop(stmt);
+
+    a.busy_handler(|_| false)?;
+    assert_eq!(a.get_busy_timeout(), 0);
+
+    let c = OpenOptions::new()
+        .read_write()
+        .create()
+        .busy_timeout(-1)
+        .open(&path)?;
+    assert_eq!(c.get_busy_timeout(), 0);
+    Ok(())
+}
+
 #[test]
 fn connection_busy_handler() -> Result<()> {
     let dir = tempfile::tempdir().context("tempdir")?;
@@ -62,3 +130,238 @@ fn connection_busy_handler() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn connection_with_retry() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let mut c = Connection::open(&path)?;
+    data::users(&mut c)?;
+
+    let (locked_tx, locked_rx) = mpsc::channel();
+
+    let holder = thread::spawn({
+        let path = path.to_path_buf();
+
+        move || -> Result<()> {
+            let c = Connection::open(path)?;
+            c.execute("BEGIN EXCLUSIVE")?;
+            locked_tx.send(())?;
+            thread::sleep(Duration::from_millis(200));
+            c.execute("COMMIT")?;
+            Ok(())
+        }
+    });
+
+    locked_rx.recv()?;
+
+    let e = c
+        .execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::BUSY);
+
+    let e = c
+        .with_retry_timeout(Duration::from_millis(10), |c| {
+            c.execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")
+        })
+        .unwrap_err();
+    assert_eq!(e.code(), Code::BUSY);
+
+    let mut attempts = 0;
+
+    c.with_retry(|c| {
+        attempts += 1;
+        c.execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")
+    })?;
+
+    assert!(attempts > 1);
+    holder.join().unwrap()?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(2));
+    Ok(())
+}
+
+#[test]
+fn connection_try_busy_handler() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let mut c = Connection::open(&path)?;
+    data::users(&mut c)?;
+
+    let (locked_tx, locked_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+
+    let holder = thread::spawn({
+        let path = path.to_path_buf();
+
+        move || -> Result<()> {
+            let c = Connection::open(path)?;
+            c.execute("BEGIN EXCLUSIVE")?;
+            locked_tx.send(())?;
+            release_rx.recv()?;
+            c.execute("COMMIT")?;
+            Ok(())
+        }
+    });
+
+    locked_rx.recv()?;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    c.try_busy_handler({
+        let calls = calls.clone();
+
+        move |attempts| {
+            calls.fetch_add(1, Ordering::SeqCst);
+
+            if attempts >= 3 {
+                return Err(Error::new(Code::ABORT, "gave up after 3 attempts"));
+            }
+
+            Ok(true)
+        }
+    })?;
+
+    let mut stmt = c.prepare("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")?;
+    let e = stmt.step().unwrap_err();
+    assert_eq!(e.code(), Code::ABORT);
+    assert_eq!(
+        e.to_string(),
+        "sqlite3 error 4 (ABORT): gave up after 3 attempts"
+    );
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+    let e = c
+        .execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ABORT);
+    assert_eq!(calls.load(Ordering::SeqCst), 8);
+
+    // A handler which doesn't abort results in a regular busy error.
+    c.try_busy_handler(|_| Ok(false))?;
+
+    let e = c
+        .execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::BUSY);
+
+    release_tx.send(())?;
+    holder.join().unwrap()?;
+
+    c.try_busy_handler(|_| Err(Error::new(Code::ABORT, "should not be called")))?;
+    c.execute("INSERT INTO users VALUES (2, 'Bob', NULL, NULL, NULL)")?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(2));
+    Ok(())
+}
+
+#[test]
+fn connection_try_busy_handler_prepare() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let mut c = Connection::open(&path)?;
+    data::users(&mut c)?;
+    drop(c);
+
+    let holder = Connection::open(&path)?;
+    holder.execute("BEGIN EXCLUSIVE")?;
+
+    // A fresh connection has to read the schema when preparing, which blocks
+    // on the lock held above.
+    let mut c = Connection::open(&path)?;
+    c.try_busy_handler(|_| Err(Error::new(Code::ABORT, "gave up while preparing")))?;
+
+    let e = c.prepare("SELECT COUNT(*) FROM users").unwrap_err();
+    assert_eq!(e.code(), Code::ABORT);
+    assert_eq!(e.message(), "gave up while preparing");
+
+    // No error is left behind for later operations.
+    c.try_busy_handler(|_| Ok(false))?;
+    let e = c.prepare("SELECT COUNT(*) FROM users").unwrap_err();
+    assert_eq!(e.code(), Code::BUSY);
+
+    holder.execute("COMMIT")?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "bundled")]
+fn connection_is_interrupted() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        INSERT INTO numbers VALUES (1), (2), (3);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM numbers")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    assert!(!c.is_interrupted());
+
+    let c = thread::spawn(move || {
+        c.interrupt();
+        c
+    })
+    .join()
+    .unwrap();
+
+    assert!(c.is_interrupted());
+
+    let e = stmt.next::<i64>().unwrap_err();
+    assert_eq!(e.code(), Code::INTERRUPT);
+    Ok(())
+}
+
+#[test]
+fn drop_connection_with_busy_handler_and_statement() -> Result<()> {
+    struct Guard(Arc<AtomicUsize>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    let mut holder = Connection::open(&path)?;
+    data::users(&mut holder)?;
+
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let mut c = Connection::open(&path)?;
+
+    c.busy_handler({
+        let guard = Guard(drops.clone());
+        move |_| guard.0.load(Ordering::SeqCst) > 0
+    })?;
+
+    let mut stmt = c.prepare("INSERT INTO users (name) VALUES ('Bob')")?;
+
+    drop(c);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    holder.execute("BEGIN IMMEDIATE")?;
+    let e = stmt.step().unwrap_err();
+    assert_eq!(e.code(), Code::BUSY);
+    holder.execute("COMMIT")?;
+
+    stmt.reset()?;
+    assert!(stmt.step()?.is_done());
+    drop(stmt);
+
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    Ok(())
+}
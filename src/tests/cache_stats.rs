@@ -0,0 +1,51 @@
+use anyhow::{Context, Result};
+
+use crate::Connection;
+
+#[test]
+fn cache_stats_counters() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("database.sqlite3");
+
+    {
+        let c = Connection::open(&path)?;
+
+        c.execute(
+            r#"
+            CREATE TABLE numbers (value INTEGER);
+
+            WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+            INSERT INTO numbers SELECT i FROM n;
+            "#,
+        )?;
+
+        let stats = c.cache_stats(false)?;
+        assert!(stats.used() > 0);
+        assert!(stats.write() > 0);
+    }
+
+    // A fresh connection has a cold cache, so reading the table misses.
+    let c = Connection::open(&path)?;
+
+    let before = c.cache_stats(true)?;
+    assert_eq!(before.write(), 0);
+
+    let stats = c.cache_stats(false)?;
+    assert_eq!((stats.hit(), stats.miss(), stats.write()), (0, 0, 0));
+
+    let mut stmt = c.prepare("SELECT SUM(value) FROM numbers")?;
+    assert_eq!(stmt.next::<i64>()?, Some(500500));
+
+    let first = c.cache_stats(true)?;
+    assert!(first.miss() > 0);
+
+    // Reading the same table again is served from the cache.
+    stmt.reset()?;
+    assert_eq!(stmt.next::<i64>()?, Some(500500));
+
+    let second = c.cache_stats(false)?;
+    assert!(second.hit() > 0);
+    assert_eq!(second.miss(), 0);
+    assert_eq!(second.write(), 0);
+    Ok(())
+}
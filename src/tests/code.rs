@@ -0,0 +1,53 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::ToString;
+
+use anyhow::Result;
+
+use crate::Code;
+
+#[test]
+fn code_round_trip() {
+    for code in [
+        Code::OK,
+        Code::BUSY,
+        Code::CONSTRAINT,
+        Code::CONSTRAINT_UNIQUE,
+        Code::IOERR_READ,
+    ] {
+        assert_eq!(Code::from_raw(code.as_i32()), code);
+    }
+
+    assert_eq!(Code::CONSTRAINT_UNIQUE.as_i32(), 2067);
+    assert_eq!(Code::from_raw(2067).base(), Code::CONSTRAINT);
+    assert_eq!(Code::from_raw(12345).as_i32(), 12345);
+}
+
+#[test]
+fn code_all_names() -> Result<()> {
+    assert_eq!(Code::all().len(), 113);
+    assert_eq!(Code::all().next(), Some(Code::OK));
+
+    let mut names = BTreeMap::new();
+
+    for code in Code::all() {
+        let Some(name) = code.name() else {
+            panic!("code {} has no name", code.as_i32());
+        };
+
+        assert_eq!(code.to_string(), name);
+        assert_eq!(format!("{code:?}"), name);
+        assert_eq!(Code::from_raw(code.as_i32()), code);
+        assert!(names.insert(name, code).is_none(), "duplicate name {name}");
+    }
+
+    assert!(
+        Code::all()
+            .filter(|c| c.base() != *c)
+            .all(|c| names.contains_key(c.base().name().unwrap_or_default()))
+    );
+
+    assert_eq!(Code::from_raw(0xfff).name(), None);
+    assert_eq!(Code::from_raw(0xfff).to_string(), "UNKNOWN(4095)");
+    Ok(())
+}
@@ -0,0 +1,202 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, TxnState};
+
+use super::data;
+
+#[test]
+fn connection_query_only() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    c.set_query_only(true)?;
+    assert!(c.query_only()?);
+
+    let mut stmt = c.prepare("INSERT INTO users (id, name) VALUES (?, ?)")?;
+    let e = stmt.execute((2, "Bob")).unwrap_err();
+    assert_eq!(e.code(), Code::READONLY);
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+
+    c.set_query_only(false)?;
+    assert!(!c.query_only()?);
+
+    let mut stmt = c.prepare("INSERT INTO users (id, name) VALUES (?, ?)")?;
+    stmt.execute((2, "Bob"))?;
+    Ok(())
+}
+
+#[test]
+fn connection_transaction_state() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    assert_eq!(c.transaction_state(None)?, TxnState::NONE);
+
+    c.execute("BEGIN")?;
+
+    let mut stmt = c.prepare("SELECT name FROM users")?;
+    assert_eq!(stmt.next::<String>()?, Some(String::from("Alice")));
+    assert_eq!(c.transaction_state(None)?, TxnState::READ);
+    assert_eq!(c.transaction_state(Some(c"main"))?, TxnState::READ);
+
+    c.execute("INSERT INTO users (id, name) VALUES (2, 'Bob')")?;
+    assert_eq!(c.transaction_state(None)?, TxnState::WRITE);
+    assert_eq!(c.transaction_state(Some(c"main"))?, TxnState::WRITE);
+    assert_eq!(c.transaction_state(Some(c"temp"))?, TxnState::NONE);
+
+    drop(stmt);
+    c.execute("COMMIT")?;
+    assert_eq!(c.transaction_state(None)?, TxnState::NONE);
+    assert!(c.is_autocommit());
+    Ok(())
+}
+
+#[test]
+fn connection_optimize() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT, age INTEGER);
+        CREATE INDEX users_age ON users (age);
+        "#,
+    )?;
+
+    let mut insert = c.prepare("INSERT INTO users (name, age) VALUES (?, ?)")?;
+
+    for age in 0..100 {
+        insert.execute(("user", age))?;
+    }
+
+    drop(insert);
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users WHERE age >= ?")?;
+    stmt.bind(50)?;
+    assert_eq!(stmt.next::<i64>()?, Some(50));
+    drop(stmt);
+
+    c.optimize()?;
+    c.optimize()?;
+    Ok(())
+}
+
+#[test]
+fn connection_database_list() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    assert_eq!(c.database_list()?, [(0, String::from("main"), None)]);
+
+    c.execute("ATTACH DATABASE ':memory:' AS other")?;
+    c.execute("CREATE TEMP TABLE scratch (value INTEGER)")?;
+
+    let list = c.database_list()?;
+    let names = list
+        .iter()
+        .map(|(_, name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["main", "temp", "other"]);
+    assert!(list.iter().all(|(_, _, file)| file.is_none()));
+
+    c.execute("DETACH DATABASE other")?;
+
+    let names = c
+        .database_list()?
+        .into_iter()
+        .map(|(_, name, _)| name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["main", "temp"]);
+    Ok(())
+}
+
+#[test]
+fn connection_ping() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+    c.ping()?;
+
+    c.execute("CREATE TABLE users (name TEXT)")?;
+    c.ping()?;
+    assert_eq!(c.statement_count(), 0);
+    Ok(())
+}
+
+#[test]
+fn connection_integrity_check() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    assert!(c.integrity_check()?.is_empty());
+    assert!(c.quick_check()?.is_empty());
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER);
+        CREATE INDEX users_age ON users (age);
+
+        INSERT INTO users VALUES ('Alice', 42), (NULL, 69);
+        "#,
+    )?;
+
+    assert!(c.integrity_check()?.is_empty());
+    assert!(c.quick_check()?.is_empty());
+
+    // Add a NOT NULL constraint behind SQLite's back which the existing data
+    // violates, and bump the schema version so that it's reloaded.
+    let version = c
+        .prepare("PRAGMA schema_version")?
+        .next::<i64>()?
+        .unwrap_or_default();
+
+    c.execute(
+        r#"
+        PRAGMA writable_schema = ON;
+        UPDATE sqlite_schema SET sql = 'CREATE TABLE users (name TEXT NOT NULL, age INTEGER)' WHERE name = 'users';
+        PRAGMA writable_schema = OFF;
+        "#,
+    )?;
+
+    c.execute(format!("PRAGMA schema_version = {}", version + 1))?;
+
+    let expected = ["NULL value in users.name"];
+    assert_eq!(c.integrity_check()?, expected);
+    assert_eq!(c.quick_check()?, expected);
+    Ok(())
+}
+
+#[test]
+fn pragma_snapshot_restore() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    let snapshot = c.pragma_snapshot()?;
+    assert!(!snapshot.foreign_keys());
+    assert!(!snapshot.recursive_triggers());
+    assert_eq!(snapshot.busy_timeout(), 0);
+    assert_eq!(snapshot.journal_mode(), "memory");
+
+    c.execute(
+        r#"
+        PRAGMA foreign_keys = ON;
+        PRAGMA recursive_triggers = ON;
+        PRAGMA journal_mode = OFF;
+        "#,
+    )?;
+    c.busy_timeout(1000)?;
+
+    let changed = c.pragma_snapshot()?;
+    assert!(changed.foreign_keys());
+    assert!(changed.recursive_triggers());
+    assert_eq!(changed.busy_timeout(), 1000);
+    assert_eq!(changed.journal_mode(), "off");
+
+    c.restore_pragma_snapshot(&snapshot)?;
+    assert_eq!(c.pragma_snapshot()?, snapshot);
+    assert_eq!(c.get_busy_timeout(), 0);
+
+    c.restore_pragma_snapshot(&changed)?;
+    assert_eq!(c.pragma_snapshot()?, changed);
+    Ok(())
+}
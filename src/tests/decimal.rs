@@ -0,0 +1,64 @@
+use alloc::string::String;
+
+use anyhow::Result;
+use rust_decimal::Decimal;
+
+use crate::{Code, Connection};
+
+#[test]
+fn decimal_round_trip() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE prices (amount TEXT);
+        "#,
+    )?;
+
+    let mut insert = c.prepare("INSERT INTO prices (amount) VALUES (?)")?;
+    let mut select = c.prepare("SELECT typeof(amount), amount, amount FROM prices")?;
+
+    macro_rules! test {
+        ($text:literal) => {{
+            let value = Decimal::from_str_exact($text).unwrap();
+            insert.execute(value)?;
+
+            let (ty, text, decoded) = select.next::<(String, String, Decimal)>()?.unwrap();
+            assert_eq!(ty, "text");
+            assert_eq!(text, $text);
+            assert_eq!(decoded, value);
+            assert_eq!(decoded.scale(), value.scale());
+            assert_eq!(select.next::<(String, String, Decimal)>()?, None);
+
+            c.execute("DELETE FROM prices")?;
+        }};
+    }
+
+    test!("0");
+    test!("1.50");
+    test!("-0.0000000000000000000000000001");
+    test!("0.1234567890123456789012345678");
+    test!("79228162514264337593543950335");
+    test!("-79228162514264337593543950335");
+    test!("7.9228162514264337593543950335");
+    test!("123456789.123456789123456789");
+    Ok(())
+}
+
+#[test]
+fn decimal_errors() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT ?")?;
+
+    for text in ["", "abc", "1.2.3", "79228162514264337593543950336"] {
+        stmt.bind(text)?;
+        let e = stmt.next::<Decimal>().unwrap_err();
+        assert_eq!(e.code(), Code::MISMATCH, "{text:?}");
+    }
+
+    stmt.bind(1.5)?;
+    let e = stmt.next::<Decimal>().unwrap_err();
+    assert_eq!(e.code(), Code::MISMATCH);
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use alloc::format;
+
+use anyhow::Result;
+
+use crate::{Bind, Connection, Row, Text};
+
+#[derive(Row)]
+#[sql(crate = crate)]
+struct User<'stmt> {
+    id: i64,
+    #[sql(index = 2)]
+    email: Option<&'stmt str>,
+    #[sql(index = 1)]
+    r#type: &'stmt str,
+}
+
+#[derive(Row)]
+#[sql(crate = crate)]
+struct Pair(i64, i64);
+
+#[test]
+fn row_columns() -> Result<()> {
+    assert_eq!(User::COLUMNS, ["id", "type", "email"]);
+
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (id INTEGER, type TEXT, email TEXT);
+
+        INSERT INTO users VALUES (1, 'admin', NULL);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT * FROM users")?;
+
+    assert!(stmt.column_names().eq(User::COLUMNS.iter().map(Text::new)));
+
+    let user = stmt.next::<User<'_>>()?.expect("missing row");
+    assert_eq!(user.id, 1);
+    assert_eq!(user.r#type, "admin");
+    assert_eq!(user.email, None);
+
+    let mut stmt = c.prepare("SELECT 1, 2")?;
+    let Pair(a, b) = stmt.next::<Pair>()?.expect("missing row");
+    assert_eq!((a, b), (1, 2));
+    Ok(())
+}
+
+#[derive(Row, Bind)]
+#[sql(crate = crate, named, rename_all = "snake_case")]
+#[allow(non_snake_case)]
+struct Account<'stmt> {
+    accountId: i64,
+    DisplayName: &'stmt str,
+    r#type: &'stmt str,
+}
+
+#[derive(Row)]
+#[sql(crate = crate, rename_all = "SCREAMING_SNAKE_CASE")]
+struct Screaming {
+    #[allow(dead_code)]
+    account_id: i64,
+    #[allow(dead_code)]
+    display_name_2: i64,
+}
+
+#[test]
+fn rename_all_snake_case() -> Result<()> {
+    assert_eq!(Account::COLUMNS, ["account_id", "display_name", "type"]);
+    assert_eq!(Screaming::COLUMNS, ["ACCOUNT_ID", "DISPLAY_NAME_2"]);
+
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE accounts (account_id INTEGER, display_name TEXT, type TEXT);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("INSERT INTO accounts VALUES (:account_id, :display_name, :type)")?;
+
+    stmt.execute(Account {
+        accountId: 7,
+        DisplayName: "Alice",
+        r#type: "admin",
+    })?;
+
+    let query = format!("SELECT {} FROM accounts", Account::COLUMNS.join(", "));
+    let mut stmt = c.prepare(query)?;
+
+    assert!(
+        stmt.column_names()
+            .eq(Account::COLUMNS.iter().map(Text::new))
+    );
+
+    let account = stmt.next::<Account<'_>>()?.expect("missing row");
+    assert_eq!(account.accountId, 7);
+    assert_eq!(account.DisplayName, "Alice");
+    assert_eq!(account.r#type, "admin");
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use std::format;
+use std::fs;
+use std::string::ToString;
+use std::vec::Vec;
+
+use anyhow::{Context, Result};
+
+use crate::{Code, Connection, DeserializeOptions};
+
+fn database() -> Result<Vec<u8>> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("data.db");
+
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        INSERT INTO users VALUES ('Alice'), ('Bob');
+        "#,
+    )?;
+
+    c.execute(format!("VACUUM INTO '{}'", path.display()))?;
+    Ok(fs::read(&path)?)
+}
+
+const INSERT_MANY: &str = r#"
+    WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 10000)
+    INSERT INTO users SELECT 'user' FROM n
+"#;
+
+fn count(c: &Connection) -> Result<i64> {
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    stmt.next::<i64>()?.context("missing row")
+}
+
+#[test]
+fn deserialize_read_only() -> Result<()> {
+    let data = database()?;
+
+    let c = Connection::open_in_memory()?;
+    c.deserialize(c"main", &data)?;
+    assert_eq!(count(&c)?, 2);
+
+    let e = c
+        .execute("INSERT INTO users VALUES ('Charlie')")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::READONLY);
+
+    let e = c.execute("DELETE FROM users").unwrap_err();
+    assert_eq!(e.code(), Code::READONLY);
+    assert_eq!(count(&c)?, 2);
+    Ok(())
+}
+
+#[test]
+fn deserialize_read_write() -> Result<()> {
+    let data = database()?;
+
+    let c = Connection::open_in_memory()?;
+
+    DeserializeOptions::new()
+        .read_write()
+        .deserialize(&c, c"main", &data)?;
+
+    c.execute("DELETE FROM users WHERE name = 'Bob'")?;
+    assert_eq!(count(&c)?, 1);
+
+    // Without being resizeable, the database can't grow.
+    let e = c.execute(INSERT_MANY).unwrap_err();
+    assert_eq!(e.code(), Code::FULL);
+
+    DeserializeOptions::new()
+        .read_write()
+        .resizeable()
+        .deserialize(&c, c"main", &data)?;
+
+    assert_eq!(count(&c)?, 2);
+    c.execute(INSERT_MANY)?;
+    assert_eq!(count(&c)?, 10002);
+    Ok(())
+}
+
+#[test]
+fn deserialize_errors() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let e = c.deserialize(c"missing", &database()?).unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert!(
+        e.to_string()
+            .ends_with("failed to deserialize database missing")
+    );
+
+    // Empty data is an empty database.
+    c.deserialize(c"main", &[])?;
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM sqlite_schema")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
+
+#[test]
+fn serialize_to_round_trip() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        INSERT INTO users VALUES ('Alice'), ('Bob');
+        "#,
+    )?;
+
+    c.execute(INSERT_MANY)?;
+
+    let mut data = Vec::new();
+    let n = c.serialize_to(c"main", &mut data)?;
+    assert_eq!(n, data.len() as u64);
+    assert!(data.starts_with(b"SQLite format 3\0"));
+
+    let c2 = Connection::open_in_memory()?;
+    c2.deserialize(c"main", &data)?;
+    assert_eq!(count(&c2)?, 10002);
+
+    // A deserialized database can be serialized again.
+    let mut again = Vec::new();
+    assert_eq!(c2.serialize_to(c"main", &mut again)?, n);
+    assert_eq!(again, data);
+    Ok(())
+}
+
+#[test]
+fn serialize_to_errors() -> Result<()> {
+    struct Failing;
+
+    impl std::io::Write for Failing {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("broken pipe"))
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let c = Connection::open_in_memory()?;
+    c.execute("CREATE TABLE users (name TEXT)")?;
+
+    let e = c.serialize_to(c"missing", Vec::new()).unwrap_err();
+    assert_eq!(e, Code::ERROR);
+
+    let e = c.serialize_to(c"main", Failing).unwrap_err();
+    assert_eq!(e, Code::IOERR);
+    assert!(e.to_string().contains("broken pipe"));
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, DynamicRow, Value};
+
+#[test]
+fn dynamic_row_mixed_types() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE mixed (a, b, c, d, e);
+
+        INSERT INTO mixed VALUES ('Alice', 42, 2.5, X'DEADBEEF', NULL);
+        INSERT INTO mixed VALUES (NULL, 'Bob', 1, 0.5, X'');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT a, b, c, d, e FROM mixed")?;
+    let mut rows = Vec::new();
+
+    while let Some(mut row) = stmt.next::<DynamicRow<'_>>()? {
+        assert_eq!(row.len(), 5);
+
+        let values = row
+            .iter_values()
+            .map(|v| Ok(v?.map(|v| v.to_string())))
+            .collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(values.len(), row.len());
+
+        let mut indexed = Vec::new();
+
+        for index in 0..5 {
+            indexed.push(row.get_value(index)?.map(|v| v.to_string()));
+        }
+
+        assert_eq!(values, indexed);
+        rows.push(indexed);
+    }
+
+    assert_eq!(rows.len(), 2);
+
+    stmt.reset()?;
+
+    let mut row = stmt.next::<DynamicRow<'_>>()?.unwrap();
+    let mut values = row.iter_values();
+    assert_eq!(values.len(), 5);
+    assert_eq!(values.next().transpose()?, Some(Some(Value::text("Alice"))));
+    assert_eq!(values.next().transpose()?, Some(Some(Value::integer(42))));
+    assert_eq!(values.next().transpose()?, Some(Some(Value::float(2.5))));
+    assert_eq!(
+        values.next().transpose()?,
+        Some(Some(Value::blob(b"\xDE\xAD\xBE\xEF")))
+    );
+    assert_eq!(values.next().transpose()?, Some(None));
+    assert_eq!(values.next().transpose()?, None);
+
+    assert_eq!(row.get_value(-1).unwrap_err().code(), Code::RANGE);
+    assert_eq!(row.get_value(5).unwrap_err().code(), Code::RANGE);
+    Ok(())
+}
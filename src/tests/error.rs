@@ -0,0 +1,111 @@
+#[cfg(feature = "bundled")]
+use alloc::format;
+use alloc::string::ToString;
+#[cfg(feature = "bundled")]
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Code, Connection};
+
+#[cfg(feature = "bundled")]
+use crate::Prepare;
+
+#[cfg(feature = "bundled")]
+use super::data;
+
+#[test]
+fn error_display_extended_code() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT UNIQUE);
+
+        INSERT INTO users (name) VALUES ('Alice');
+        "#,
+    )?;
+
+    let e = c
+        .execute("INSERT INTO users (name) VALUES ('Alice')")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+    assert_eq!(
+        e.to_string(),
+        "sqlite3 error 2067 (CONSTRAINT_UNIQUE / CONSTRAINT): UNIQUE constraint failed: users.name"
+    );
+    Ok(())
+}
+
+#[test]
+fn connection_error_codes() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT UNIQUE);
+
+        INSERT INTO users VALUES ('Alice');
+        "#,
+    )?;
+
+    assert_eq!(c.error_code(), Code::OK);
+    assert_eq!(c.extended_error_code(), Code::OK);
+
+    let e = c.execute("INSERT INTO users VALUES ('Alice')").unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+    assert_eq!(c.error_code(), Code::CONSTRAINT_UNIQUE);
+    assert_eq!(c.extended_error_code(), Code::CONSTRAINT_UNIQUE);
+
+    c.extended_result_codes(false)?;
+
+    let e = c.execute("INSERT INTO users VALUES ('Alice')").unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT);
+    assert_eq!(c.error_code(), Code::CONSTRAINT);
+    assert_eq!(c.extended_error_code(), Code::CONSTRAINT_UNIQUE);
+    assert_eq!(c.extended_error_code().base(), c.error_code());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "bundled")]
+fn error_offset() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let sql = "SELECT name FROM users WHERE age >;";
+    let e = c.prepare(sql).unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(e.offset(), Some(sql.len() - 1));
+
+    let sql = r#"
+        UPDATE users SET age = age + 1;
+        SELECT name FORM users;
+    "#;
+
+    let e = c.execute(sql).unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    let offset = e.offset().expect("missing offset");
+    assert!(sql[offset..].starts_with("users;"), "{offset}: {e}");
+
+    let sql = "SELECT 'åäö 😀', nme FROM users";
+    let utf16 = sql.encode_utf16().collect::<Vec<u16>>();
+    let expected = sql[..sql.find("nme").unwrap()].encode_utf16().count();
+
+    let e = c.prepare_utf16(&utf16, Prepare::EMPTY).unwrap_err();
+    assert_eq!(e.offset(), Some(expected));
+
+    let sql = format!("UPDATE users SET age = age + 1; {sql}");
+    let utf16 = sql.encode_utf16().collect::<Vec<u16>>();
+    let expected = sql[..sql.find("nme").unwrap()].encode_utf16().count();
+
+    let e = c.execute_utf16(&utf16).unwrap_err();
+    assert_eq!(e.offset(), Some(expected));
+
+    // Runtime errors have no offset.
+    let e = c
+        .execute("INSERT INTO users (id) VALUES (1 / 0 + abs(-9223372036854775808))")
+        .unwrap_err();
+    assert_eq!(e.offset(), None);
+    Ok(())
+}
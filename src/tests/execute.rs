@@ -0,0 +1,384 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, Prepare};
+
+use super::data;
+
+#[test]
+fn connection_execute_interior_null() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let e = c
+        .execute_bytes(b"CREATE TABLE a (id INTEGER);\0CREATE TABLE b (id INTEGER);")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c.execute("CREATE TABLE c (id INTEGER);\0").unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c.execute_bytes(b"\0").unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    c.execute_bytes(b"CREATE TABLE d (id INTEGER); INSERT INTO d VALUES (1);")?;
+
+    let mut stmt = c.prepare("SELECT id FROM d")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    Ok(())
+}
+
+#[test]
+fn connection_execute_each() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    c.execute_each(
+        "INSERT INTO users (id, name, age) VALUES (?, ?, ?)",
+        [(2, "Bob", 30.0), (3, "Charlie", 40.5), (4, "Dave", 50.25)],
+    )?;
+
+    let mut stmt = c.prepare("SELECT id, name, age FROM users WHERE id > 1 ORDER BY id")?;
+    let rows = stmt
+        .iter::<(i64, String, f64)>()
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    assert_eq!(
+        rows,
+        [
+            (2, String::from("Bob"), 30.0),
+            (3, String::from("Charlie"), 40.5),
+            (4, String::from("Dave"), 50.25),
+        ]
+    );
+
+    // Nests inside of an existing transaction.
+    c.execute("BEGIN")?;
+    c.execute_each("DELETE FROM users WHERE id = ?", [2, 3])?;
+    c.execute("ROLLBACK")?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(4));
+    Ok(())
+}
+
+#[test]
+fn connection_execute_each_raise_rollback() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        CREATE TRIGGER no_mallory BEFORE INSERT ON users WHEN NEW.name = 'Mallory'
+        BEGIN
+            SELECT RAISE(ROLLBACK, 'nope');
+        END;
+        "#,
+    )?;
+
+    // The trigger rolls back the whole transaction, which discards the
+    // savepoint, but the error which caused it is still reported.
+    let e = c
+        .execute_each("INSERT INTO users (name) VALUES (?)", ["Alice", "Mallory"])
+        .unwrap_err();
+    assert_eq!(e.message(), "nope");
+    assert!(c.is_autocommit());
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
+
+#[test]
+fn connection_execute_exactly() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let migration = r#"
+        CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT);
+        CREATE INDEX users_name ON users (name);
+        INSERT INTO users (name) VALUES ('Alice');
+    "#;
+
+    let truncated = &migration[..migration.find("CREATE INDEX").unwrap()];
+
+    let e = c.execute_exactly(truncated, 3).unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert!(c.prepare("SELECT * FROM users").is_err());
+
+    c.execute_exactly(migration, 3)?;
+
+    let mut stmt = c.prepare("SELECT name FROM users")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+
+    // Ending the transaction discards the savepoint, but the count mismatch is
+    // still reported.
+    let e = c
+        .execute_exactly("CREATE TABLE other (id INTEGER); COMMIT;", 3)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(e.message(), "expected 3 statements but executed 2");
+    Ok(())
+}
+
+#[test]
+fn connection_execute_counting() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    c.execute(
+        r#"
+        CREATE TABLE audit (name TEXT);
+
+        INSERT INTO users (id, name, age) VALUES (2, 'Bob', 69.42), (3, 'Charlie', 20);
+        "#,
+    )?;
+
+    let n = c.execute_counting(
+        r#"
+        UPDATE users SET age = age + 1;
+        INSERT INTO audit SELECT name FROM users WHERE age > 30;
+        UPDATE users SET email = 'unknown' WHERE email IS NULL AND id > 1;
+        "#,
+    )?;
+
+    assert_eq!(n, 3 + 2 + 2);
+    assert_eq!(c.changes(), 2);
+
+    assert_eq!(c.execute_counting("SELECT * FROM users")?, 0);
+    Ok(())
+}
+
+#[test]
+fn connection_execute_with_progress() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut seen = Vec::new();
+
+    c.execute_with_progress(
+        r#"
+        CREATE TABLE users (name TEXT);
+        ;
+        INSERT INTO users VALUES ('Alice');
+        /* comment */
+        INSERT INTO users VALUES ('Bob');
+        "#,
+        |index| seen.push(index),
+    )?;
+
+    assert_eq!(seen, [0, 1, 2]);
+
+    // Statements which fail to prepare are not reported.
+    seen.clear();
+
+    let e = c
+        .execute_with_progress(
+            r#"
+            INSERT INTO users VALUES ('Charlie');
+            INSERT INTO missing VALUES ('Dave');
+            INSERT INTO users VALUES ('Eve');
+            "#,
+            |index| seen.push(index),
+        )
+        .unwrap_err();
+
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(seen, [0]);
+    Ok(())
+}
+
+#[test]
+fn connection_execute_with_rows() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut rows = Vec::new();
+
+    c.execute_with_rows(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER);
+        INSERT INTO users VALUES ('Alice', 42), ('Bob', 72);
+        SELECT name, age FROM users ORDER BY name;
+        UPDATE users SET age = age + 1;
+        -- Only a comment.
+        ;
+        SELECT COUNT(*) FROM users;
+        DROP TABLE users;
+        "#,
+        |stmt| {
+            let row = match stmt.column_count() {
+                2 => {
+                    let (name, age) = stmt.row::<(String, i64)>()?;
+                    format!("{name}: {age}")
+                }
+                _ => format!("count: {}", stmt.column::<i64>(0)?),
+            };
+
+            rows.push(row);
+            Ok(())
+        },
+    )?;
+
+    assert_eq!(rows, ["Alice: 42", "Bob: 72", "count: 2"]);
+
+    let mut count = 0;
+
+    let e = c
+        .execute_with_rows("SELECT 1 UNION SELECT 2; SELECT 3;", |_| {
+            count += 1;
+            Err(crate::Error::new(Code::ABORT, "stop"))
+        })
+        .unwrap_err();
+
+    assert_eq!(e.code(), Code::ABORT);
+    assert_eq!(count, 1);
+    Ok(())
+}
+
+#[test]
+fn connection_execute_expecting() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE documents (id INTEGER PRIMARY KEY, owner TEXT, version INTEGER);
+
+        INSERT INTO documents VALUES (1, 'Alice', 1), (2, 'Alice', 1), (3, 'Bob', 1);
+        "#,
+    )?;
+
+    let update = "UPDATE documents SET version = version + 1 WHERE id = ? AND version = ?";
+
+    c.execute_expecting(update, (1, 1), 1)?;
+
+    // The version has moved on, so the stale update doesn't match.
+    let e = c.execute_expecting(update, (1, 1), 1).unwrap_err();
+    assert_eq!(e.code(), Code::ABORT);
+
+    // Changing more rows than expected rolls them back.
+    let e = c
+        .execute_expecting(
+            "UPDATE documents SET version = version + 1 WHERE owner = ?",
+            "Alice",
+            1,
+        )
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ABORT);
+
+    // Works inside of an already open transaction.
+    c.execute("BEGIN")?;
+    c.execute_expecting(
+        "UPDATE documents SET version = version + 1 WHERE owner = ?",
+        "Bob",
+        1,
+    )?;
+    assert!(c.execute_expecting(update, (3, 1), 1).is_err());
+    c.execute("COMMIT")?;
+
+    let mut stmt = c.prepare("SELECT id, version FROM documents ORDER BY id")?;
+    let versions = stmt.iter::<(i64, i64)>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(versions, [(1, 2), (2, 1), (3, 2)]);
+
+    // A trigger rolling back the whole transaction discards the savepoint,
+    // but the error which caused it is still reported.
+    c.execute(
+        r#"
+        CREATE TRIGGER frozen BEFORE UPDATE ON documents WHEN OLD.id = 3
+        BEGIN
+            SELECT RAISE(ROLLBACK, 'document is frozen');
+        END;
+        "#,
+    )?;
+
+    let e = c.execute_expecting(update, (3, 2), 1).unwrap_err();
+    assert_eq!(e.message(), "document is frozen");
+    assert!(c.is_autocommit());
+    Ok(())
+}
+
+#[test]
+fn connection_insert() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT UNIQUE);
+        CREATE TABLE log (message TEXT);
+
+        CREATE TRIGGER users_log AFTER INSERT ON users BEGIN
+            INSERT INTO log VALUES ('inserted ' || NEW.name);
+        END;
+        "#,
+    )?;
+
+    let result = c.insert("INSERT INTO users (id, name) VALUES (?, ?)", (10, "Alice"))?;
+    assert_eq!(result.rowid(), 10);
+    assert_eq!(result.changes(), 1);
+
+    let result = c.insert(
+        "INSERT INTO users (name) VALUES (?), (?), (?)",
+        ("Bob", "Charlie", "Dave"),
+    )?;
+    assert_eq!(result.rowid(), 13);
+    assert_eq!(result.changes(), 3);
+
+    // Rows inserted by triggers are not counted.
+    assert_eq!(c.total_changes(), 8);
+
+    let result = c.insert("INSERT OR IGNORE INTO users (name) VALUES (?)", "Alice")?;
+    assert_eq!(result.rowid(), 13);
+    assert_eq!(result.changes(), 0);
+
+    let e = c
+        .insert("INSERT INTO users (name) VALUES (?)", "Alice")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+    Ok(())
+}
+
+#[test]
+fn connection_utf16() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    macro_rules! utf16 {
+        ($sql:expr) => {
+            $sql.encode_utf16().collect::<Vec<u16>>()
+        };
+    }
+
+    let mut stmt = c.prepare_utf16(&utf16!("SELECT 1"), Prepare::EMPTY)?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    assert_eq!(stmt.next::<i64>()?, None);
+
+    let mut stmt = c.prepare_utf16(
+        &utf16!("SELECT ?; /* trailing comment */ -- ünïcödé"),
+        Prepare::PERSISTENT,
+    )?;
+    stmt.bind("hello")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("hello"));
+
+    let e = c
+        .prepare_utf16(&utf16!("SELECT 1; SELECT 2"), Prepare::EMPTY)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c.prepare_utf16(&[], Prepare::EMPTY).unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    c.execute_utf16(&utf16!(
+        "CREATE TABLE users (name TEXT); INSERT INTO users VALUES ('Ålice');"
+    ))?;
+
+    let mut stmt = c.prepare("SELECT name FROM users")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("Ålice"));
+
+    let e = c.execute_utf16(&utf16!("SELECT 1;\0SELECT 2")).unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c
+        .execute_utf16(&utf16!("SELECT * FROM missing"))
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
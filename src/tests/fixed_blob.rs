@@ -0,0 +1,47 @@
+use crate::{FixedBlob, FixedText};
+
+#[test]
+fn fixed_blob_capacity_edges() {
+    let mut blob = FixedBlob::<4>::new();
+    assert_eq!(blob.as_array(), None);
+
+    blob.try_extend_from_slice(b"").unwrap();
+    assert!(blob.is_empty());
+
+    blob.try_extend_from_slice(b"ab").unwrap();
+    assert!(blob.try_extend_from_slice(b"cde").is_err());
+    assert_eq!(blob.as_slice(), b"ab");
+
+    blob.try_extend_from_slice(b"cd").unwrap();
+    assert_eq!(blob.remaining_capacity(), 0);
+    assert_eq!(blob.as_array(), Some(b"abcd"));
+
+    assert!(blob.try_extend_from_slice(b"e").is_err());
+    blob.try_extend_from_slice(b"").unwrap();
+    assert_eq!(blob.as_slice(), b"abcd");
+
+    blob.clear();
+    assert_eq!(blob.as_array(), None);
+    blob.try_extend_from_slice(b"wxyz").unwrap();
+    assert_eq!(blob.into_bytes(), Some(*b"wxyz"));
+
+    let mut empty = FixedBlob::<0>::new();
+    assert_eq!(empty.as_array(), Some(&[]));
+    assert_eq!(empty.as_array_mut(), Some(&mut []));
+    assert!(empty.try_extend_from_slice(b"a").is_err());
+}
+
+#[test]
+fn fixed_text_capacity_edges() {
+    let mut text = FixedText::<4>::new();
+
+    text.try_push_str("ab").unwrap();
+    assert!(text.try_push_str("\u{e5}\u{e4}").is_err());
+    text.try_push_str("\u{e5}").unwrap();
+    assert_eq!(text.as_text(), "ab\u{e5}");
+    assert!(text.try_push_str("c").is_err());
+
+    text.clear();
+    text.try_push_str("abcd").unwrap();
+    assert_eq!(text.as_text(), "abcd");
+}
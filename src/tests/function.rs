@@ -0,0 +1,275 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Arguments, Arity, Code, Connection, Error, FunctionFlags, WindowAggregate};
+
+#[derive(Default)]
+struct Sum(i64);
+
+impl WindowAggregate for Sum {
+    type Output = i64;
+
+    fn step(&mut self, args: &Arguments<'_>) -> crate::Result<()> {
+        match args.get(0).and_then(|v| v.as_integer()) {
+            Some(value) if value < 0 => Err(Error::new(Code::RANGE, "negative value")),
+            Some(value) => {
+                self.0 += value;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn inverse(&mut self, args: &Arguments<'_>) -> crate::Result<()> {
+        self.0 -= args.get(0).and_then(|v| v.as_integer()).unwrap_or(0);
+        Ok(())
+    }
+
+    fn value(&self) -> crate::Result<i64> {
+        Ok(self.0)
+    }
+}
+
+#[test]
+fn window_function_sum() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+    c.create_window_function(
+        c"windowed_sum",
+        Arity::Exact(1),
+        FunctionFlags::EMPTY,
+        Sum::default,
+    )?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        INSERT INTO numbers VALUES (1), (2), (NULL), (4), (5);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare(
+        r#"
+        SELECT windowed_sum(value) OVER (ORDER BY rowid ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)
+        FROM numbers
+        "#,
+    )?;
+
+    let sums = stmt.iter::<i64>().collect::<crate::Result<Vec<_>>>()?;
+    assert_eq!(sums, [1, 3, 3, 6, 9]);
+    Ok(())
+}
+
+#[test]
+fn window_function_empty() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+    c.create_window_function(
+        c"windowed_sum",
+        Arity::Exact(1),
+        FunctionFlags::EMPTY,
+        Sum::default,
+    )?;
+    c.execute("CREATE TABLE numbers (value INTEGER)")?;
+
+    let mut stmt = c.prepare("SELECT windowed_sum(value) FROM numbers")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
+
+#[test]
+fn window_function_error() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+    c.create_window_function(
+        c"windowed_sum",
+        Arity::Exact(1),
+        FunctionFlags::EMPTY,
+        Sum::default,
+    )?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        INSERT INTO numbers VALUES (1), (-2);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT windowed_sum(value) FROM numbers")?;
+    let e = stmt.next::<i64>().unwrap_err();
+    assert_eq!(e.code(), Code::RANGE);
+    assert_eq!(e.to_string(), "sqlite3 error 25 (RANGE): negative value");
+    Ok(())
+}
+
+#[test]
+fn window_function_direct_only() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+    c.create_window_function(
+        c"windowed_sum",
+        Arity::Exact(1),
+        FunctionFlags::DIRECTONLY,
+        Sum::default,
+    )?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        INSERT INTO numbers VALUES (1), (2);
+
+        CREATE VIEW sums AS SELECT windowed_sum(value) AS value FROM numbers;
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT windowed_sum(value) FROM numbers")?;
+    assert_eq!(stmt.next::<i64>()?, Some(3));
+
+    let e = c.prepare("SELECT value FROM sums").unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
+
+#[test]
+fn scalar_function_partial_index() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let is_even = |args: &Arguments<'_>| {
+        let value = args.get(0).and_then(|v| v.as_integer());
+        Ok(value.map(|v| v % 2 == 0))
+    };
+
+    c.create_scalar_function(
+        c"is_even",
+        Arity::Exact(1),
+        FunctionFlags::DETERMINISTIC,
+        is_even,
+    )?;
+    c.create_scalar_function(
+        c"is_even_nd",
+        Arity::Exact(1),
+        FunctionFlags::EMPTY,
+        is_even,
+    )?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        INSERT INTO numbers VALUES (1), (2), (3), (4);
+
+        CREATE INDEX even_numbers ON numbers (value) WHERE is_even(value);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM numbers WHERE is_even(value) ORDER BY value")?;
+    let values = stmt.iter::<i64>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(values, [2, 4]);
+
+    // Non-deterministic functions cannot be used in partial indexes.
+    let e = c
+        .execute("CREATE INDEX even_numbers_nd ON numbers (value) WHERE is_even_nd(value)")
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
+
+#[test]
+fn scalar_function_error() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.create_scalar_function(c"fail", Arity::Exact(0), FunctionFlags::EMPTY, |_| {
+        Err::<i64, _>(Error::new(Code::RANGE, "out of range"))
+    })?;
+
+    let mut stmt = c.prepare("SELECT fail()")?;
+    let e = stmt.step().unwrap_err();
+    assert_eq!(e.code(), Code::RANGE);
+    assert_eq!(e.message(), "out of range");
+    Ok(())
+}
+
+#[test]
+fn scalar_function_direct_only() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.create_scalar_function(
+        c"one",
+        Arity::Exact(0),
+        FunctionFlags::DIRECTONLY | FunctionFlags::INNOCUOUS,
+        |_| Ok(1),
+    )?;
+
+    let mut stmt = c.prepare("SELECT one()")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+
+    c.execute("CREATE VIEW ones AS SELECT one() AS value")?;
+    let e = c.prepare("SELECT value FROM ones").unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
+
+#[test]
+fn scalar_function_variadic() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.create_scalar_function(
+        c"join_with",
+        Arity::Variadic,
+        FunctionFlags::DETERMINISTIC,
+        |args| {
+            let Some(separator) = args.get(0) else {
+                return Err(Error::new(Code::ERROR, "missing separator"));
+            };
+
+            let separator = separator
+                .as_text()
+                .map(|s| s.to_string())
+                .unwrap_or_default();
+            let mut out = String::new();
+
+            for value in (1..args.len()).filter_map(|n| args.get(n)?.as_text()) {
+                if !out.is_empty() {
+                    out.push_str(&separator);
+                }
+
+                out.push_str(&value.to_string());
+            }
+
+            Ok(out)
+        },
+    )?;
+
+    let mut stmt = c.prepare("SELECT join_with('-', 'a')")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("a"));
+
+    let mut stmt = c.prepare("SELECT join_with(', ', 'a', 'b', 'c')")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("a, b, c"));
+
+    let e = c
+        .prepare("SELECT join_with()")?
+        .next::<String>()
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(c.error_message(), "missing separator");
+    Ok(())
+}
+
+#[test]
+fn scalar_function_exact_arity() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.create_scalar_function(c"two", Arity::Exact(2), FunctionFlags::EMPTY, |_| Ok(2))?;
+
+    let e = c.prepare("SELECT two(1)").unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    assert_eq!(
+        c.error_message(),
+        "wrong number of arguments to function two()"
+    );
+
+    let mut stmt = c.prepare("SELECT two(1, 2)")?;
+    assert_eq!(stmt.next::<i64>()?, Some(2));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Connection, JsonArray};
+
+#[test]
+fn json_array_in_clause() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+        INSERT INTO numbers SELECT i FROM n;
+        "#,
+    )?;
+
+    let ids = (0..100).map(|n| n * 7).collect::<Vec<i64>>();
+
+    let mut stmt = c.prepare(
+        "SELECT value FROM numbers WHERE value IN (SELECT value FROM json_each(?)) ORDER BY value",
+    )?;
+
+    stmt.bind(JsonArray::new(&ids))?;
+
+    let found = stmt.iter::<i64>().collect::<crate::Result<Vec<_>>>()?;
+    // Zero is not in the table.
+    assert_eq!(found, &ids[1..]);
+
+    stmt.bind(JsonArray::new(&[] as &[i64]))?;
+    assert_eq!(stmt.next::<i64>()?, None);
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use alloc::string::String;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, Lenient};
+
+#[test]
+fn lenient_column() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER, score REAL);
+
+        INSERT INTO users VALUES ('Alice', 42, 1.5), ('Bob', 300, 2.5), ('Charlie', -1, NULL);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, age, score FROM users")?;
+
+    let (name, age, score) = stmt
+        .next::<(String, Lenient<u8>, Lenient<f64>)>()?
+        .expect("missing row");
+    assert_eq!(name, "Alice");
+    assert_eq!(age.into_result()?, 42);
+    assert_eq!(score.into_result()?, 1.5);
+
+    let (name, age, score) = stmt
+        .next::<(String, Lenient<u8>, Lenient<f64>)>()?
+        .expect("missing row");
+    assert_eq!(name, "Bob");
+    assert!(!age.is_ok());
+    assert_eq!(age.into_result().unwrap_err().code(), Code::MISMATCH);
+    assert_eq!(score.ok(), Some(2.5));
+
+    let (name, age, score) = stmt
+        .next::<(String, Lenient<u8>, Lenient<f64>)>()?
+        .expect("missing row");
+    assert_eq!(name, "Charlie");
+    assert_eq!(age.into_result().unwrap_err().code(), Code::MISMATCH);
+    assert_eq!(score.into_result().unwrap_err().code(), Code::MISMATCH);
+
+    assert!(
+        stmt.next::<(String, Lenient<u8>, Lenient<f64>)>()?
+            .is_none()
+    );
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use anyhow::Result;
+
+use crate::Connection;
+
+#[test]
+fn math_functions() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT pow(2, 10), sqrt(16.0)")?;
+    assert_eq!(stmt.next::<(f64, f64)>()?, Some((1024.0, 4.0)));
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+
+use crate::Connection;
+
+// NB: Memory usage is process-wide and other tests allocate and free memory
+// concurrently, so only properties which hold regardless of that are tested
+// here. Deltas are tested in the doctests, which run in their own process.
+#[test]
+fn memory_highwater_covers_data() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE files (data BLOB);
+
+        INSERT INTO files VALUES (randomblob(4000000));
+        "#,
+    )?;
+
+    // The high-water mark only increases, so it has to be at least what was
+    // in use when it was read.
+    let used = crate::memory_used();
+    let highwater = crate::memory_highwater(false);
+    assert!(highwater >= used, "{highwater} >= {used}");
+    assert!(highwater > 4000000, "{highwater} > 4000000");
+    Ok(())
+}
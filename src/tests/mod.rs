@@ -1,3 +1,53 @@
+#[cfg(feature = "std")]
+mod allocator;
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "std")]
+mod authorizer;
 mod basics;
+mod bind;
+mod bind_value;
+#[cfg(feature = "std")]
+mod borrowed;
+#[cfg(feature = "std")]
+mod boxed;
+#[cfg(feature = "std")]
 mod busy;
+#[cfg(feature = "std")]
+mod cache_stats;
+mod code;
+mod connection;
 mod data;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "derive")]
+mod derive;
+#[cfg(feature = "std")]
+mod deserialize;
+mod dynamic_row;
+mod error;
+mod execute;
+mod fixed_blob;
+mod function;
+mod json_array;
+mod lenient;
+#[cfg(all(feature = "math", feature = "bundled"))]
+mod math;
+mod memory;
+mod no_std;
+mod open_options;
+mod prepare;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "std")]
+mod sink;
+#[cfg(feature = "smallvec")]
+mod smallvec;
+#[cfg(feature = "derive")]
+mod sql;
+mod statement;
+mod text_buf;
+mod value;
+mod virtual_table;
@@ -0,0 +1,68 @@
+//! Tests which exercise the crate without relying on the `std` feature.
+//!
+//! These are intended to be run with `--no-default-features --features
+//! threadsafe,alloc` to ensure that in-memory databases are usable in `no_std`
+//! environments.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Connection, OpenOptions};
+
+#[test]
+fn open_in_memory() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER);
+
+        INSERT INTO users VALUES ('Alice', 72);
+        INSERT INTO users VALUES ('Bob', 40);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, age FROM users ORDER BY age")?;
+
+    let mut rows = Vec::new();
+
+    while let Some(row) = stmt.next::<(String, i64)>()? {
+        rows.push(row);
+    }
+
+    assert_eq!(
+        rows,
+        [(String::from("Bob"), 40), (String::from("Alice"), 72)]
+    );
+    Ok(())
+}
+
+#[test]
+fn open_c_str() -> Result<()> {
+    let c = Connection::open_c_str(c":memory:")?;
+
+    c.execute("CREATE TABLE numbers (value INTEGER)")?;
+
+    let mut stmt = c.prepare("INSERT INTO numbers VALUES (?)")?;
+
+    for n in 0..10i64 {
+        stmt.execute(n)?;
+    }
+
+    let mut stmt = c.prepare("SELECT SUM(value) FROM numbers")?;
+    assert_eq!(stmt.next::<i64>()?, Some(45));
+    Ok(())
+}
+
+#[test]
+fn open_options_in_memory() -> Result<()> {
+    let c = OpenOptions::new().read_write().create().open_in_memory()?;
+
+    c.execute("CREATE TABLE test (value TEXT)")?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM test")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
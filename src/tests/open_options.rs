@@ -0,0 +1,184 @@
+use core::cell::Cell;
+
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use anyhow::Context;
+use anyhow::Result;
+
+#[cfg(feature = "std")]
+use crate::Code;
+use crate::{Connection, OpenOptions};
+
+#[test]
+#[cfg(feature = "std")]
+fn open_create_dirs() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let path = dir.path().join("data").join("nested").join("app.db");
+
+    let e = OpenOptions::new()
+        .read_write()
+        .create()
+        .open(&path)
+        .unwrap_err();
+
+    assert_eq!(e, Code::CANTOPEN);
+    assert!(!path.parent().unwrap().exists());
+
+    // Without create, missing directories are left alone.
+    let e = OpenOptions::new()
+        .read_write()
+        .create_dirs()
+        .open(&path)
+        .unwrap_err();
+
+    assert_eq!(e, Code::CANTOPEN);
+    assert!(!path.parent().unwrap().exists());
+
+    let c = OpenOptions::new()
+        .read_write()
+        .create()
+        .create_dirs()
+        .open(&path)?;
+
+    c.execute("CREATE TABLE users (name TEXT)")?;
+    drop(c);
+
+    assert!(path.is_file());
+
+    // Opening again when the directories already exist works.
+    let c = OpenOptions::new()
+        .read_write()
+        .create()
+        .create_dirs()
+        .open(&path)?;
+
+    let mut stmt = c.prepare("SELECT COUNT(*) FROM users")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn open_create_dirs_fails() -> Result<()> {
+    let dir = tempfile::tempdir().context("tempdir")?;
+    let file = dir.path().join("file");
+    std::fs::write(&file, b"").context("write")?;
+
+    let e = OpenOptions::new()
+        .read_write()
+        .create()
+        .create_dirs()
+        .open(file.join("app.db"))
+        .unwrap_err();
+
+    assert_eq!(e, Code::CANTOPEN);
+    Ok(())
+}
+
+#[test]
+fn connection_on_open_runs_once() -> Result<()> {
+    let calls = Cell::new(0);
+
+    let options = OpenOptions::new().read_write().create().on_open(|c| {
+        calls.set(calls.get() + 1);
+        c.execute("PRAGMA foreign_keys = ON")
+    });
+
+    let a = options.open_in_memory()?;
+    assert_eq!(calls.get(), 1);
+
+    let b = options.open_c_str(c":memory:")?;
+    assert_eq!(calls.get(), 2);
+
+    for c in [&a, &b] {
+        let mut stmt = c.prepare("PRAGMA foreign_keys")?;
+        assert_eq!(stmt.next::<bool>()?, Some(true));
+    }
+
+    assert_eq!(calls.get(), 2);
+    Ok(())
+}
+
+#[test]
+fn connection_open_in_memory_named() -> Result<()> {
+    let a = Connection::open_in_memory_named(c"tests_shared?#%")?;
+    let b = Connection::open_in_memory_named(c"tests_shared?#%")?;
+    let other = Connection::open_in_memory_named(c"tests_shared")?;
+
+    a.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        INSERT INTO users VALUES ('Alice');
+        "#,
+    )?;
+
+    let mut stmt = b.prepare("SELECT name FROM users")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("Alice"));
+
+    assert!(other.prepare("SELECT name FROM users").is_err());
+    Ok(())
+}
+
+#[test]
+fn connection_open_temporary() -> Result<()> {
+    let a = Connection::open_temporary()?;
+    let b = Connection::open_temporary()?;
+
+    a.execute(
+        r#"
+        CREATE TABLE data (value BLOB);
+
+        WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+        INSERT INTO data SELECT randomblob(1024) FROM n;
+        "#,
+    )?;
+
+    let mut stmt = a.prepare("SELECT COUNT(*), SUM(LENGTH(value)) FROM data")?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((1000, 1024 * 1000)));
+
+    // Every temporary database is private.
+    let mut stmt = b.prepare("SELECT COUNT(*) FROM sqlite_schema")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    Ok(())
+}
+
+#[test]
+fn open_options_flags() -> Result<()> {
+    let empty = OpenOptions::empty();
+    assert_eq!(empty.flags(), 0);
+    assert!(!empty.has_extended_result_codes());
+
+    let mut options = OpenOptions::new();
+    assert!(options.has_extended_result_codes());
+    assert!(!options.is_read_write());
+    assert!(!options.is_create());
+
+    options
+        .read_write()
+        .create()
+        .memory()
+        .full_mutex()
+        .no_follow();
+
+    assert!(options.is_read_write());
+    assert!(options.is_create());
+    assert!(options.is_memory());
+    assert!(options.has_full_mutex());
+    assert!(options.has_no_follow());
+    assert!(options.has_extended_result_codes());
+
+    assert!(!options.is_read_only());
+    assert!(!options.is_uri());
+    assert!(!options.has_no_mutex());
+    assert!(!options.has_shared_cache());
+    assert!(!options.has_private_cache());
+
+    let copy = options;
+    assert_eq!(copy.flags(), options.flags());
+
+    let c = options.open_c_str(c"open_options_flags")?;
+    c.execute("CREATE TABLE test (value INTEGER)")?;
+    Ok(())
+}
@@ -0,0 +1,190 @@
+use alloc::string::String;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, Prepare};
+
+use super::data;
+
+#[test]
+fn connection_prepare_trailing() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let accepted = [
+        "SELECT name FROM users",
+        "SELECT name FROM users;",
+        "SELECT name FROM users;\n",
+        "SELECT name FROM users \t\r\n  ",
+        "SELECT name FROM users; ; ;",
+        "SELECT name FROM users; -- trailing comment",
+        "SELECT name FROM users -- trailing comment\n",
+        "SELECT name FROM users; /* block */ ",
+        "SELECT name FROM users; /* unterminated",
+        "SELECT name FROM users; -- a\n/* b */ -- c\n",
+    ];
+
+    for sql in accepted {
+        let mut stmt = c.prepare(sql)?;
+        assert_eq!(
+            stmt.next::<String>()?,
+            Some(String::from("Alice")),
+            "{sql:?}"
+        );
+    }
+
+    let rejected = [
+        "SELECT name FROM users; SELECT age FROM users",
+        "SELECT name FROM users; /* comment */ SELECT age FROM users;",
+        "SELECT name FROM users; -- comment\nSELECT age FROM users",
+        "SELECT name FROM users; garbage",
+        "",
+        "   ",
+        "-- only a comment",
+    ];
+
+    for sql in rejected {
+        let e = c.prepare(sql).unwrap_err();
+        assert_eq!(e.code(), Code::MISUSE, "{sql:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn connection_prepare_c_str() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare_c_str(c"SELECT 1", Prepare::EMPTY)?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    assert_eq!(stmt.next::<i64>()?, None);
+
+    let mut stmt = c.prepare_c_str(c"SELECT ?; -- trailing comment", Prepare::PERSISTENT)?;
+    stmt.bind("hello")?;
+    assert_eq!(stmt.next::<String>()?.as_deref(), Some("hello"));
+
+    let e = c
+        .prepare_c_str(c"SELECT 1; SELECT 2", Prepare::EMPTY)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c.prepare_c_str(c"", Prepare::EMPTY).unwrap_err();
+    assert_eq!(e.code(), Code::MISUSE);
+
+    let e = c
+        .prepare_c_str(c"SELECT * FROM missing", Prepare::EMPTY)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
+
+#[test]
+#[cfg(target_has_atomic = "ptr")]
+fn connection_single_cache() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        INSERT INTO users VALUES ('Alice'), ('Bob');
+        "#,
+    )?;
+
+    const SELECT: &str = "SELECT COUNT(*) FROM users WHERE name = ?";
+
+    // Without the cache each prepare produces a new statement.
+    let mut stmt = c.prepare(SELECT)?;
+    stmt.bind("Alice")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    drop(stmt);
+
+    c.single_cache(true);
+
+    let mut stmt = c.prepare(SELECT)?;
+    let ptr = stmt.as_ptr();
+    stmt.bind("Alice")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+    drop(stmt);
+
+    // Preparing identical SQL reuses the statement with bindings cleared.
+    let mut stmt = c.prepare(SELECT)?;
+    assert_eq!(stmt.as_ptr(), ptr);
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    stmt.bind("Bob")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+
+    // An outstanding statement is not shared.
+    let other = c.prepare(SELECT)?;
+    assert_ne!(other.as_ptr(), ptr);
+    drop(other);
+    drop(stmt);
+
+    let stmt = c.prepare(SELECT)?;
+    assert_eq!(stmt.as_ptr(), ptr);
+    drop(stmt);
+
+    // Different SQL replaces the cached statement.
+    let stmt = c.prepare("SELECT name FROM users")?;
+    let ptr = stmt.as_ptr();
+    drop(stmt);
+
+    let stmt = c.prepare("SELECT name FROM users")?;
+    assert_eq!(stmt.as_ptr(), ptr);
+    drop(stmt);
+
+    c.single_cache(false);
+
+    let mut stmt = c.prepare(SELECT)?;
+    stmt.bind("Bob")?;
+    assert_eq!(stmt.next::<i64>()?, Some(1));
+
+    // Statements may outlive the connection and the cache.
+    c.single_cache(true);
+    let cached = c.prepare(SELECT)?;
+    drop(c);
+    drop(stmt);
+    drop(cached);
+    Ok(())
+}
+
+#[test]
+fn connection_statement_count() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    assert_eq!(c.statement_count(), 0);
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT);
+
+        INSERT INTO users VALUES ('Alice'), ('Bob');
+        "#,
+    )?;
+
+    // Statements used internally by execute are finalized.
+    assert_eq!(c.statement_count(), 0);
+
+    let mut select = c.prepare("SELECT name FROM users")?;
+    let insert = c.prepare("INSERT INTO users VALUES (?)")?;
+    assert_eq!(c.statement_count(), 2);
+
+    assert!(select.step()?.is_row());
+    let e = c.execute("DROP TABLE users").unwrap_err();
+    assert_eq!(e.code(), Code::LOCKED);
+
+    drop(select);
+    assert_eq!(c.statement_count(), 1);
+
+    drop(insert);
+    assert_eq!(c.statement_count(), 0);
+
+    c.execute("DROP TABLE users")?;
+
+    c.single_cache(true);
+    drop(c.prepare("SELECT 1")?);
+    assert_eq!(c.statement_count(), 1);
+
+    c.single_cache(false);
+    assert_eq!(c.statement_count(), 0);
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+use serde_json::json;
+
+use crate::{BIND_INDEX, Connection, Null};
+
+#[test]
+fn bind_json_scalars() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT typeof(?), ?1")?;
+
+    stmt.bind(json!(null))?;
+    assert_eq!(
+        stmt.next::<(String, Null)>()?,
+        Some((String::from("null"), Null))
+    );
+
+    stmt.bind(json!(true))?;
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("integer"), 1))
+    );
+
+    stmt.bind(json!(false))?;
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("integer"), 0))
+    );
+
+    stmt.bind(json!(-42))?;
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("integer"), -42))
+    );
+
+    stmt.bind(json!(i64::MAX))?;
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("integer"), i64::MAX))
+    );
+
+    stmt.bind(json!(u64::MAX))?;
+    assert_eq!(
+        stmt.next::<(String, f64)>()?,
+        Some((String::from("real"), u64::MAX as f64))
+    );
+
+    stmt.bind(json!(1.5))?;
+    assert_eq!(
+        stmt.next::<(String, f64)>()?,
+        Some((String::from("real"), 1.5))
+    );
+
+    stmt.bind(json!("hello"))?;
+    assert_eq!(
+        stmt.next::<(String, String)>()?,
+        Some((String::from("text"), String::from("hello")))
+    );
+    Ok(())
+}
+
+#[test]
+fn bind_json_nested() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT typeof(?), ?1, json_valid(?1)")?;
+
+    stmt.bind(json!({"name": "Alice", "tags": [1, 2]}))?;
+    assert_eq!(
+        stmt.next::<(String, String, bool)>()?,
+        Some((
+            String::from("text"),
+            String::from(r#"{"name":"Alice","tags":[1,2]}"#),
+            true
+        ))
+    );
+
+    stmt.bind(json!([1, "two", null]))?;
+    assert_eq!(
+        stmt.next::<(String, String, bool)>()?,
+        Some((
+            String::from("text"),
+            String::from(r#"[1,"two",null]"#),
+            true
+        ))
+    );
+
+    let mut stmt = c.prepare("SELECT value FROM json_each(?) ORDER BY key")?;
+    stmt.bind_value(BIND_INDEX, json!([3, 1, 2]))?;
+
+    let values = stmt.iter::<i64>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(values, [3, 1, 2]);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use alloc::string::String;
+
+use anyhow::Result;
+
+use crate::{BlobEncoding, Connection, Value};
+
+fn json(value: impl serde::Serialize) -> Result<String> {
+    Ok(serde_json::to_string(&value)?)
+}
+
+#[test]
+fn serialize_value_kinds() -> Result<()> {
+    assert_eq!(json(Value::integer(-42))?, "-42");
+    assert_eq!(json(Value::float(2.5))?, "2.5");
+    assert_eq!(json(Value::text("a \"b\""))?, r#""a \"b\"""#);
+    assert_eq!(json(None::<Value<'_>>)?, "null");
+
+    assert_eq!(json(Value::blob(b""))?, r#""""#);
+    assert_eq!(json(Value::blob(b"f"))?, r#""Zg==""#);
+    assert_eq!(json(Value::blob(b"fo"))?, r#""Zm8=""#);
+    assert_eq!(json(Value::blob(b"foo"))?, r#""Zm9v""#);
+    assert_eq!(json(Value::blob(b"foobar"))?, r#""Zm9vYmFy""#);
+    assert_eq!(json(Value::blob(&[0xFF, 0xEF]))?, r#""/+8=""#);
+
+    let blob = Value::blob(&[0x00, 0xAB, 0xFF]);
+    assert_eq!(json(blob.serialize_with(BlobEncoding::Hex))?, r#""00abff""#);
+    assert_eq!(
+        json(blob.serialize_with(BlobEncoding::Bytes))?,
+        "[0,171,255]"
+    );
+    Ok(())
+}
+
+#[test]
+fn serialize_invalid_text() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT CAST(X'F0908000' AS TEXT)")?;
+    assert!(stmt.step()?.is_row());
+    assert!(serde_json::to_string(&stmt.serialize_row()?).is_err());
+    Ok(())
+}
+
+#[test]
+fn serialize_row() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare(
+        "SELECT 1 AS id, 'Alice' AS name, 4.5 AS score, X'0102' AS photo, NULL AS email",
+    )?;
+    assert!(stmt.step()?.is_row());
+
+    assert_eq!(
+        json(stmt.serialize_row()?)?,
+        r#"{"id":1,"name":"Alice","score":4.5,"photo":"AQI=","email":null}"#
+    );
+
+    assert_eq!(
+        json(stmt.serialize_row()?.blob_encoding(BlobEncoding::Bytes))?,
+        r#"{"id":1,"name":"Alice","score":4.5,"photo":[1,2],"email":null}"#
+    );
+    Ok(())
+}
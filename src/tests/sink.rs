@@ -0,0 +1,161 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Code, Connection};
+
+use super::allocator::allocations;
+
+#[test]
+fn sink_iter_no_allocations() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE data (name TEXT, payload BLOB);
+
+        WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+        INSERT INTO data SELECT 'name-' || i, randomblob(i % 64) FROM n;
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, payload FROM data")?;
+
+    // Warm up buffers so that they are large enough to hold any row.
+    let mut sink = (String::with_capacity(64), Vec::with_capacity(64));
+
+    let before = allocations();
+
+    let mut it = stmt.sink_iter(&mut sink);
+    let mut count = 0;
+    let mut total = 0;
+
+    while let Some((name, payload)) = it.next()? {
+        assert!(name.starts_with("name-"));
+        total += payload.len();
+        count += 1;
+    }
+
+    let after = allocations();
+
+    assert_eq!(count, 1000);
+    assert!(total > 0);
+    assert_eq!(before, after);
+    Ok(())
+}
+
+#[test]
+fn read_into_sink_no_allocations() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE data (name TEXT);
+
+        WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < 1000)
+        INSERT INTO data SELECT 'name-' || i FROM n;
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name FROM data")?;
+    let mut name = String::with_capacity(64);
+
+    let before = allocations();
+    let mut count = 0;
+
+    while stmt.step()?.is_row() {
+        stmt.read_into_sink(0, &mut name)?;
+        assert!(name.starts_with("name-"));
+        count += 1;
+    }
+
+    let after = allocations();
+
+    assert_eq!(count, 1000);
+    assert_eq!(before, after);
+    Ok(())
+}
+
+#[test]
+fn sink_keeps_capacity() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE data (name TEXT, payload BLOB);
+
+        INSERT INTO data VALUES ('aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa', zeroblob(128));
+        INSERT INTO data VALUES ('aaaaaaaaaaaaaaaa', zeroblob(64));
+        INSERT INTO data VALUES ('aaaa', zeroblob(16));
+        INSERT INTO data VALUES ('', zeroblob(0));
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, payload FROM data ORDER BY length(name) DESC")?;
+    let mut name = String::new();
+    let mut payload = Vec::new();
+
+    assert!(stmt.step()?.is_row());
+    stmt.read_into_sink(0, &mut (&mut name, &mut payload))?;
+    assert_eq!(name.len(), 32);
+    assert_eq!(payload.len(), 128);
+
+    let name_ptr = name.as_ptr();
+    let name_capacity = name.capacity();
+    let payload_ptr = payload.as_ptr();
+    let payload_capacity = payload.capacity();
+
+    while stmt.step()?.is_row() {
+        stmt.read_into_sink(0, &mut (&mut name, &mut payload))?;
+        assert_eq!(name.as_ptr(), name_ptr);
+        assert_eq!(name.capacity(), name_capacity);
+        assert_eq!(payload.as_ptr(), payload_ptr);
+        assert_eq!(payload.capacity(), payload_capacity);
+    }
+
+    assert!(name.is_empty());
+    assert!(payload.is_empty());
+    Ok(())
+}
+
+#[test]
+fn copy_column_to() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE documents (body BLOB);
+
+        INSERT INTO documents VALUES (randomblob(100000)), ('text'), (X''), (42);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT body FROM documents")?;
+
+    assert!(stmt.step()?.is_row());
+    let expected = stmt.column::<Vec<u8>>(0)?;
+    let mut out = Vec::new();
+    assert_eq!(stmt.copy_column_to(0, &mut out)?, expected.len());
+    assert_eq!(out, expected);
+
+    assert!(stmt.step()?.is_row());
+    out.clear();
+    assert_eq!(stmt.copy_column_to(0, &mut out)?, 4);
+    assert_eq!(out, b"text");
+
+    assert!(stmt.step()?.is_row());
+    out.clear();
+    assert_eq!(stmt.copy_column_to(0, &mut out)?, 0);
+    assert!(out.is_empty());
+
+    assert!(stmt.step()?.is_row());
+    let e = stmt.copy_column_to(0, &mut out).unwrap_err();
+    assert_eq!(e.code(), Code::MISMATCH);
+
+    stmt.reset()?;
+    assert!(stmt.step()?.is_row());
+    let e = stmt.copy_column_to(0, &mut [0u8; 16][..]).unwrap_err();
+    assert_eq!(e.code(), Code::IOERR);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use smallvec::SmallVec;
+
+use crate::{Code, Connection};
+
+#[test]
+fn smallvec_blobs() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE blobs (value BLOB);
+
+        INSERT INTO blobs VALUES (X''), (X'01020304'), (X'0102030405');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM blobs")?;
+
+    let empty = stmt.next::<SmallVec<[u8; 4]>>()?.unwrap();
+    assert!(empty.is_empty());
+    assert!(!empty.spilled());
+
+    let at_capacity = stmt.next::<SmallVec<[u8; 4]>>()?.unwrap();
+    assert_eq!(at_capacity.as_slice(), &[1, 2, 3, 4]);
+    assert!(!at_capacity.spilled());
+
+    let beyond_capacity = stmt.next::<SmallVec<[u8; 4]>>()?.unwrap();
+    assert_eq!(beyond_capacity.as_slice(), &[1, 2, 3, 4, 5]);
+    assert!(beyond_capacity.spilled());
+
+    let mut stmt = c.prepare("SELECT 'text'")?;
+    let e = stmt.next::<SmallVec<[u8; 4]>>().unwrap_err();
+    assert_eq!(e, Code::MISMATCH);
+    Ok(())
+}
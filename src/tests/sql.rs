@@ -0,0 +1,84 @@
+use alloc::string::String;
+
+use anyhow::Result;
+
+use crate::{Code, Connection, sql};
+
+#[test]
+fn sql_parameter_count() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE "a""b" (id INTEGER, [x?] TEXT, `y?` TEXT);
+        "#,
+    )?;
+
+    macro_rules! test {
+        ($sql:literal, $expected:expr) => {{
+            let sql = sql!(crate = crate, $sql);
+            assert_eq!(sql.parameter_count(), $expected, "{}", $sql);
+            let stmt = c.prepare(sql)?;
+            assert_eq!(stmt.bind_parameter_count(), $expected as i32, "{}", $sql);
+        }};
+    }
+
+    test!("SELECT 1", 0);
+    test!("SELECT ?, ?, ?", 3);
+    test!("SELECT ?3, ?", 4);
+    test!("SELECT ?, ?1", 1);
+    test!("SELECT :a, @b, $c, :a", 3);
+    test!("SELECT ?2, :a, ?", 4);
+    test!("SELECT '?', 'it''s ?', ?", 1);
+    test!("SELECT X'3F', ?", 1);
+    test!(r#"SELECT "a""b".id FROM "a""b" WHERE id = ?"#, 1);
+    test!("SELECT [x?], `y?` FROM \"a\"\"b\" WHERE id = :id", 1);
+    test!("SELECT ? -- comment ?\n, ?", 2);
+    test!("SELECT ? /* comment ? */, ?", 2);
+    test!("SELECT ? /* unterminated ?", 1);
+    test!("SELECT 1 AS a$b, :c", 1);
+    test!("SELECT :ünï, ?", 2);
+    Ok(())
+}
+
+#[test]
+fn sql_execute() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(sql!(
+        crate = crate,
+        "CREATE TABLE users (name TEXT, age INTEGER)"
+    ))?;
+
+    c.execute_sql(
+        sql!(crate = crate, "INSERT INTO users (name, age) VALUES (?, ?)"),
+        ("Alice", 42),
+    )?;
+    c.execute_sql(
+        sql!(
+            crate = crate,
+            "INSERT INTO users (name, age) VALUES (?, 72)"
+        ),
+        "Bob",
+    )?;
+    c.execute_sql(sql!(crate = crate, "UPDATE users SET age = age + 1"), ())?;
+
+    let e = c
+        .execute_sql(sql!(crate = crate, "INSERT INTO missing VALUES (?)"), 1)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+
+    let mut stmt = c.prepare(sql!(
+        crate = crate,
+        "SELECT name, age FROM users ORDER BY age"
+    ))?;
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("Alice"), 43))
+    );
+    assert_eq!(
+        stmt.next::<(String, i64)>()?,
+        Some((String::from("Bob"), 73))
+    );
+    Ok(())
+}
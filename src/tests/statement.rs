@@ -0,0 +1,462 @@
+use core::ffi::c_int;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+#[cfg(all(feature = "bundled", feature = "normalize"))]
+use crate::Text;
+use crate::{Affinity, Code, Connection, Null, Value, ValueType};
+
+use super::data;
+
+#[test]
+fn statement_get_by_name() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    for query in [
+        "SELECT name, age FROM users",
+        "SELECT age, name FROM users",
+        "SELECT id, age AS years, name FROM users",
+    ] {
+        let mut stmt = c.prepare(query)?;
+        assert!(stmt.step()?.is_row());
+
+        assert_eq!(stmt.get_by_name::<&str>("name")?, "Alice");
+
+        let age = match stmt.column_index("years") {
+            Some(_) => stmt.get_by_name::<f64>("years")?,
+            None => stmt.get_by_name::<f64>("age")?,
+        };
+
+        assert_eq!(age, 42.69);
+
+        let e = stmt.get_by_name::<i64>("missing").unwrap_err();
+        assert_eq!(e.code(), Code::RANGE);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn statement_verify_schema() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let expected = [
+        ("id", Affinity::INTEGER),
+        ("name", Affinity::TEXT),
+        ("age", Affinity::REAL),
+        ("photo", Affinity::BLOB),
+        ("email", Affinity::TEXT),
+    ];
+
+    let stmt = c.prepare("SELECT * FROM users")?;
+    stmt.verify_schema(&expected)?;
+
+    c.execute("ALTER TABLE users RENAME COLUMN email TO mail")?;
+
+    let stmt = c.prepare("SELECT * FROM users")?;
+    let e = stmt.verify_schema(&expected).unwrap_err();
+    assert_eq!(e.code(), Code::SCHEMA);
+    Ok(())
+}
+
+#[test]
+fn statement_next_after_done() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let mut stmt = c.prepare("SELECT name FROM users WHERE id = ?")?;
+
+    // A statement without rows is done immediately, and stays done no matter
+    // how many times it's stepped.
+    stmt.bind(42)?;
+
+    for _ in 0..4 {
+        assert_eq!(stmt.next::<String>()?, None);
+    }
+
+    // Stepping a statement which has completed implicitly resets it.
+    stmt.bind(1)?;
+
+    for _ in 0..4 {
+        assert_eq!(stmt.next::<String>()?, Some(String::from("Alice")));
+        assert_eq!(stmt.next::<String>()?, None);
+    }
+
+    // Explicitly resetting a done statement has the same effect.
+    assert!(stmt.step()?.is_row());
+    assert!(stmt.step()?.is_done());
+    stmt.reset()?;
+    assert_eq!(stmt.next::<String>()?, Some(String::from("Alice")));
+    Ok(())
+}
+
+#[test]
+fn statement_columns_as_map() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT 'Alice' AS name, 42 AS age, NULL AS photo")?;
+    assert!(stmt.step()?.is_row());
+
+    let map = stmt.columns_as_map()?;
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["name"], Some(Value::text("Alice")));
+    assert_eq!(map["age"], Some(Value::integer(42)));
+    assert_eq!(map["photo"], None);
+    assert_eq!(
+        map.keys().copied().collect::<Vec<_>>(),
+        ["age", "name", "photo"]
+    );
+
+    let mut stmt = c.prepare("SELECT 1 AS a, 2 AS b, 3 AS a")?;
+    assert!(stmt.step()?.is_row());
+
+    let map = stmt.columns_as_map()?;
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["a"], Some(Value::integer(3)));
+
+    let e = stmt.columns_as_unique_map().unwrap_err();
+    assert_eq!(e.code(), Code::ERROR);
+    Ok(())
+}
+
+#[test]
+fn statement_read_nullable_any() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE test (value);
+
+        INSERT INTO test (value) VALUES (1), (NULL), (2.5), ('three'), (X'04'), (NULL);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM test")?;
+
+    let mut values = Vec::new();
+
+    while let Some(value) = stmt.next::<Option<Value<'_>>>()? {
+        values.push(value.map(|v| v.column_type()));
+    }
+
+    assert_eq!(
+        values,
+        [
+            Some(ValueType::INTEGER),
+            None,
+            Some(ValueType::FLOAT),
+            Some(ValueType::TEXT),
+            Some(ValueType::BLOB),
+            None,
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn statement_max_column_size() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE files (name TEXT, data BLOB);
+
+        INSERT INTO files VALUES ('small', X'0102'), ('oversized', zeroblob(1024));
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT name, data FROM files")?;
+    stmt.set_max_column_size(8);
+
+    assert!(stmt.step()?.is_row());
+    assert_eq!(
+        stmt.row::<(String, Vec<u8>)>()?,
+        ("small".into(), vec![1, 2])
+    );
+
+    assert!(stmt.step()?.is_row());
+    let e = stmt.column::<String>(0).unwrap_err();
+    assert_eq!(e.code(), Code::TOOBIG);
+    let e = stmt.column::<&[u8]>(1).unwrap_err();
+    assert_eq!(e.code(), Code::TOOBIG);
+    let e = stmt.column::<Option<Value<'_>>>(1).unwrap_err();
+    assert_eq!(e.code(), Code::TOOBIG);
+
+    let mut data = Vec::new();
+    let e = stmt.read_into_sink(1, &mut data).unwrap_err();
+    assert_eq!(e.code(), Code::TOOBIG);
+    assert!(data.is_empty());
+
+    stmt.set_max_column_size(1024);
+    assert_eq!(stmt.column::<&[u8]>(1)?.len(), 1024);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "bundled", feature = "normalize"))]
+fn statement_normalized_sql() -> Result<()> {
+    let mut c = Connection::open_in_memory()?;
+    data::users(&mut c)?;
+
+    let a = c.prepare("SELECT name FROM users WHERE age > 30 AND name = 'Alice'")?;
+    let b = c.prepare("SELECT name   FROM users WHERE age > 72.5 AND name = 'Bob'")?;
+    let other = c.prepare("SELECT email FROM users WHERE age > 30 AND name = 'Alice'")?;
+
+    let normalized = a.normalized_sql().expect("normalized sql");
+    assert_eq!(
+        normalized,
+        Text::new("SELECT name FROM users WHERE age>?AND name=?;")
+    );
+    assert_eq!(b.normalized_sql(), Some(normalized));
+    assert_ne!(other.normalized_sql(), Some(normalized));
+    Ok(())
+}
+
+#[test]
+fn statement_has_results() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, age INTEGER);
+        "#,
+    )?;
+
+    for sql in [
+        "INSERT INTO users VALUES ('Alice', 42)",
+        "UPDATE users SET age = age + 1",
+        "DELETE FROM users WHERE age > 100",
+        "CREATE TABLE posts (title TEXT)",
+    ] {
+        let mut stmt = c.prepare(sql)?;
+        assert!(!stmt.has_results(), "{sql}");
+        assert!(stmt.step()?.is_done());
+        assert!(!stmt.has_results(), "{sql}");
+    }
+
+    let mut stmt = c.prepare("SELECT * FROM users WHERE age > 100")?;
+    assert!(stmt.has_results());
+    assert_eq!(stmt.column_count(), 2);
+
+    // The column count changes when the statement is re-prepared after a
+    // schema change.
+    c.execute("ALTER TABLE users ADD COLUMN occupation TEXT")?;
+    assert!(stmt.step()?.is_done());
+    assert_eq!(stmt.column_count(), 3);
+
+    let stmt = c.prepare("INSERT INTO users VALUES ('Bob', 72, NULL) RETURNING name")?;
+    assert!(stmt.has_results());
+    Ok(())
+}
+
+#[test]
+fn statement_get_tuple() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE edges (source INTEGER, target INTEGER, label TEXT);
+
+        INSERT INTO edges VALUES (1, 2, 'a'), (2, 3, 'b'), (3, 1, 'c');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT source, target, label FROM edges")?;
+    let mut edges = BTreeMap::new();
+
+    while stmt.step()?.is_row() {
+        let key = stmt.get_tuple::<(i64, i64)>(0)?;
+        let (target, label) = stmt.get_tuple::<(i64, String)>(1)?;
+        assert_eq!(key.1, target);
+        edges.insert(key, label);
+    }
+
+    let expected = BTreeMap::from([
+        ((1, 2), String::from("a")),
+        ((2, 3), String::from("b")),
+        ((3, 1), String::from("c")),
+    ]);
+
+    assert_eq!(edges, expected);
+
+    stmt.reset()?;
+    assert!(stmt.step()?.is_row());
+
+    // Columns are type checked before anything is read.
+    let e = stmt.get_tuple::<(i64, i64)>(1).unwrap_err();
+    assert_eq!(e.code(), Code::MISMATCH);
+
+    let e = stmt.get_tuple::<(i64, i64)>(2).unwrap_err();
+    assert_eq!(e.code(), Code::MISMATCH);
+
+    // Overflowing the column index is an error rather than a panic.
+    let e = stmt
+        .get_tuple::<(Option<i64>, i64)>(c_int::MAX)
+        .unwrap_err();
+    assert_eq!(e.code(), Code::RANGE);
+    Ok(())
+}
+
+#[test]
+fn statement_finish() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT NOT NULL UNIQUE);
+
+        INSERT INTO users VALUES ('Alice');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("INSERT INTO users VALUES ('Alice')")?;
+    assert!(stmt.step().is_err());
+
+    let e = stmt.finish().unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+
+    // Never stepped.
+    let stmt = c.prepare("INSERT INTO users VALUES (NULL)")?;
+    stmt.finish()?;
+
+    let mut stmt = c.prepare("INSERT INTO users VALUES ('Bob')")?;
+    assert!(stmt.step()?.is_done());
+    stmt.finish()?;
+
+    // Statements which outlive the connection can still report errors.
+    let mut stmt = c.prepare("INSERT INTO users VALUES (NULL)")?;
+    drop(c);
+    assert!(stmt.step().is_err());
+    let e = stmt.finish().unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_NOTNULL);
+    Ok(())
+}
+
+#[test]
+fn statement_step_after_error() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT UNIQUE NOT NULL);
+
+        INSERT INTO users VALUES ('Alice');
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("INSERT INTO users VALUES (?)")?;
+    stmt.bind("Alice")?;
+
+    // Stepping again without resetting re-runs the statement and reports the
+    // same error rather than something unrelated.
+    for _ in 0..3 {
+        let e = stmt.step().unwrap_err();
+        assert_eq!(e.code(), Code::CONSTRAINT_UNIQUE);
+        assert_eq!(c.error_message(), "UNIQUE constraint failed: users.name");
+    }
+
+    stmt.reset()?;
+    stmt.bind(Null)?;
+
+    let e = stmt.step().unwrap_err();
+    assert_eq!(e.code(), Code::CONSTRAINT_NOTNULL);
+
+    stmt.reset()?;
+    stmt.bind("Bob")?;
+    assert!(stmt.step()?.is_done());
+
+    let mut select = c.prepare("SELECT name FROM users ORDER BY name")?;
+    assert_eq!(
+        select.iter::<String>().collect::<Vec<_>>(),
+        [Ok(String::from("Alice")), Ok(String::from("Bob"))]
+    );
+    Ok(())
+}
+
+#[test]
+fn statement_rows_stepped() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 10)
+        INSERT INTO numbers SELECT n FROM seq;
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM numbers WHERE value > ?")?;
+    assert_eq!(stmt.rows_stepped(), 0);
+
+    stmt.bind(3)?;
+    let values = stmt.iter::<i64>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(values.len(), 7);
+    assert_eq!(stmt.rows_stepped(), 7);
+
+    // Stepping a completed statement resets it automatically.
+    assert!(stmt.step()?.is_row());
+    assert_eq!(stmt.rows_stepped(), 1);
+
+    // Binding resets the statement.
+    stmt.bind(8)?;
+    assert_eq!(stmt.rows_stepped(), 0);
+    while stmt.step()?.is_row() {}
+    assert_eq!(stmt.rows_stepped(), 2);
+
+    stmt.reset_and_clear()?;
+    assert_eq!(stmt.rows_stepped(), 0);
+    Ok(())
+}
+
+#[test]
+fn statement_fetch_all_into() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE numbers (value INTEGER);
+
+        WITH RECURSIVE seq(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < 100)
+        INSERT INTO numbers SELECT n FROM seq;
+        "#,
+    )?;
+
+    let mut stmt = c.prepare("SELECT value FROM numbers WHERE value > ?")?;
+    let mut out = Vec::<i64>::new();
+
+    stmt.bind(0)?;
+    assert_eq!(stmt.fetch_all_into(&mut out)?, 100);
+    assert_eq!(out.len(), 100);
+
+    let capacity = out.capacity();
+    let ptr = out.as_ptr();
+    out.clear();
+
+    stmt.bind(50)?;
+    assert_eq!(stmt.fetch_all_into(&mut out)?, 50);
+    assert_eq!(out, (51..=100).collect::<Vec<_>>());
+    assert_eq!(out.capacity(), capacity);
+    assert_eq!(out.as_ptr(), ptr);
+
+    // Rows are appended after existing ones.
+    stmt.bind(98)?;
+    assert_eq!(stmt.fetch_all_into(&mut out)?, 2);
+    assert_eq!(out.len(), 52);
+    assert_eq!(out[50..], [99, 100]);
+
+    // Nothing is appended if reading a row fails.
+    let mut stmt = c.prepare("SELECT value FROM numbers UNION ALL SELECT 'text'")?;
+    let e = stmt.fetch_all_into(&mut out).unwrap_err();
+    assert_eq!(e, Code::MISMATCH);
+    assert_eq!(out.len(), 52);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Connection, Text, TextBuf};
+
+#[test]
+fn text_buf_invalid_utf8() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    c.execute("CREATE TABLE example (data TEXT)")?;
+
+    let values = [
+        TextBuf::from_vec(b"invalid: \xF0\x90\x80".to_vec()),
+        TextBuf::from_vec(b"\xFF\xFE".to_vec()),
+        TextBuf::from("valid"),
+        TextBuf::new(),
+    ];
+
+    let mut insert = c.prepare("INSERT INTO example (data) VALUES (?)")?;
+
+    for value in &values {
+        insert.execute(value)?;
+    }
+
+    let mut stmt = c.prepare("SELECT data FROM example")?;
+    let read = stmt.iter::<TextBuf>().collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(read, values);
+
+    let mut stmt = c.prepare("SELECT typeof(data) FROM example")?;
+    assert!(stmt.iter::<String>().all(|ty| ty.as_deref() == Ok("text")));
+
+    let text: &Text = &read[0];
+    assert_eq!(text.to_owned(), read[0]);
+    assert!(read[0].to_str().is_err());
+    assert_eq!(read[2], "valid");
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{Connection, Value, ValueType};
+
+#[test]
+fn value_display() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt =
+        c.prepare("SELECT -42, 0.25, 'Alice', CAST(X'416C696365FF' AS TEXT), X'00FF10', X''")?;
+    assert!(stmt.step()?.is_row());
+
+    let mut values = Vec::new();
+
+    for index in 0..stmt.column_count() {
+        values.push(stmt.column::<Value<'_>>(index)?.to_string());
+    }
+
+    assert_eq!(
+        values,
+        ["-42", "0.25", "Alice", "Alice\u{FFFD}", "00ff10", ""]
+    );
+    Ok(())
+}
+
+#[test]
+fn value_type_as_str() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT x, typeof(x) FROM (SELECT 1 AS x UNION ALL SELECT 1.5 UNION ALL SELECT 'a' UNION ALL SELECT X'01' UNION ALL SELECT NULL)")?;
+
+    let expected = [
+        ValueType::INTEGER,
+        ValueType::FLOAT,
+        ValueType::TEXT,
+        ValueType::BLOB,
+        ValueType::NULL,
+    ];
+
+    for ty in expected {
+        assert!(stmt.step()?.is_row());
+        assert_eq!(stmt.column_type(0), ty);
+        assert_eq!(ty.as_str().to_ascii_lowercase(), stmt.column::<&str>(1)?);
+        assert_eq!(ty.as_str().parse::<ValueType>().ok(), Some(ty));
+    }
+
+    assert!(stmt.step()?.is_done());
+    Ok(())
+}
+
+#[test]
+fn value_blob_display() -> Result<()> {
+    let c = Connection::open_in_memory()?;
+
+    let mut stmt = c.prepare("SELECT X'', X'00FF10', X'4869', X'486921', 'text'")?;
+    assert!(stmt.step()?.is_row());
+
+    let values = stmt.row::<(Value<'_>, Value<'_>, Value<'_>, Value<'_>, Value<'_>)>()?;
+    let (empty, bytes, two, three, text) = values;
+
+    let hex = |v: &Value<'_>| v.blob_to_hex().map(|d| d.to_string());
+    let base64 = |v: &Value<'_>| v.blob_to_base64().map(|d| d.to_string());
+
+    assert_eq!(hex(&empty).as_deref(), Some(""));
+    assert_eq!(base64(&empty).as_deref(), Some(""));
+
+    assert_eq!(hex(&bytes).as_deref(), Some("00ff10"));
+    assert_eq!(base64(&bytes).as_deref(), Some("AP8Q"));
+
+    assert_eq!(hex(&two).as_deref(), Some("4869"));
+    assert_eq!(base64(&two).as_deref(), Some("SGk="));
+
+    assert_eq!(hex(&three).as_deref(), Some("486921"));
+    assert_eq!(base64(&three).as_deref(), Some("SGkh"));
+
+    assert_eq!(hex(&text), None);
+    assert_eq!(base64(&text), None);
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use core::ffi::{CStr, c_int};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{
+    Arguments, Code, Connection, ConstraintOp, Context, Error, IndexInfo, ReturnValue,
+    VirtualCursor, VirtualTable,
+};
+
+const START: c_int = 1;
+const STOP: c_int = 2;
+
+/// A table generating the integers in `start..stop`.
+struct Range {
+    opened: Arc<AtomicUsize>,
+    dropped: Arc<AtomicUsize>,
+}
+
+impl Drop for Range {
+    fn drop(&mut self) {
+        self.dropped.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl VirtualTable for Range {
+    type Cursor = RangeCursor;
+
+    fn connect(&self) -> crate::Result<&CStr> {
+        Ok(c"CREATE TABLE x(value INTEGER, start HIDDEN, stop HIDDEN)")
+    }
+
+    fn best_index(&self, info: &mut IndexInfo<'_>) -> crate::Result<()> {
+        let mut start = None;
+        let mut stop = None;
+
+        for (index, c) in info.constraints().iter().enumerate() {
+            if c.op() != ConstraintOp::EQ {
+                continue;
+            }
+
+            let slot = match c.column() {
+                START => &mut start,
+                STOP => &mut stop,
+                _ => continue,
+            };
+
+            if !c.is_usable() {
+                return Err(Error::new(Code::CONSTRAINT, "unusable"));
+            }
+
+            *slot = Some(index);
+        }
+
+        let (Some(start), Some(stop)) = (start, stop) else {
+            return Err(Error::custom("range requires start and stop arguments"));
+        };
+
+        let usage = info.constraint_usage_mut();
+        usage[start].set_argv_index(1).set_omit(true);
+        usage[stop].set_argv_index(2).set_omit(true);
+
+        info.set_estimated_cost(10.0);
+        info.set_estimated_rows(10);
+        Ok(())
+    }
+
+    fn open(&self) -> crate::Result<RangeCursor> {
+        self.opened.fetch_add(1, Ordering::SeqCst);
+
+        Ok(RangeCursor {
+            start: 0,
+            current: 0,
+            stop: 0,
+        })
+    }
+}
+
+struct RangeCursor {
+    start: i64,
+    current: i64,
+    stop: i64,
+}
+
+impl VirtualCursor for RangeCursor {
+    fn filter(&mut self, _: c_int, args: &Arguments<'_>) -> crate::Result<()> {
+        let arg = |index: usize| args.get(index).and_then(|v| v.as_integer());
+
+        self.start = arg(0).unwrap_or_default();
+        self.stop = arg(1).unwrap_or_default();
+
+        if self.start < 0 {
+            return Err(Error::new(Code::RANGE, "start must be positive"));
+        }
+
+        self.current = self.start;
+        Ok(())
+    }
+
+    fn next(&mut self) -> crate::Result<()> {
+        self.current += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.current >= self.stop
+    }
+
+    fn column(&self, ctx: &mut Context, index: c_int) -> crate::Result<()> {
+        match index {
+            0 => self.current.return_value(ctx),
+            START => self.start.return_value(ctx),
+            _ => self.stop.return_value(ctx),
+        }
+    }
+
+    fn rowid(&self) -> crate::Result<i64> {
+        Ok(self.current)
+    }
+}
+
+fn setup() -> Result<(Connection, Arc<AtomicUsize>, Arc<AtomicUsize>)> {
+    let opened = Arc::new(AtomicUsize::new(0));
+    let dropped = Arc::new(AtomicUsize::new(0));
+
+    let c = Connection::open_in_memory()?;
+
+    c.create_module(
+        c"range",
+        Range {
+            opened: opened.clone(),
+            dropped: dropped.clone(),
+        },
+    )?;
+
+    Ok((c, opened, dropped))
+}
+
+#[test]
+fn virtual_table_range() -> Result<()> {
+    let (c, opened, _) = setup()?;
+
+    let mut stmt = c.prepare("SELECT value FROM range(0, 5)")?;
+    let values = stmt.iter::<i64>().collect::<crate::Result<Vec<_>>>()?;
+    assert_eq!(values, [0, 1, 2, 3, 4]);
+
+    let mut stmt = c.prepare("SELECT value FROM range WHERE start = ? AND stop = ?")?;
+    stmt.bind((3, 6))?;
+    let values = stmt.iter::<i64>().collect::<crate::Result<Vec<_>>>()?;
+    assert_eq!(values, [3, 4, 5]);
+
+    let mut stmt = c.prepare("SELECT start, stop, rowid, value FROM range(2, 4)")?;
+    assert_eq!(stmt.next::<(i64, i64, i64, i64)>()?, Some((2, 4, 2, 2)));
+    assert_eq!(stmt.next::<(i64, i64, i64, i64)>()?, Some((2, 4, 3, 3)));
+    assert_eq!(stmt.next::<(i64, i64, i64, i64)>()?, None);
+
+    let mut stmt =
+        c.prepare("SELECT COUNT(*), SUM(value) FROM range(0, 10) WHERE value % 2 = 0")?;
+    assert_eq!(stmt.next::<(i64, i64)>()?, Some((5, 20)));
+
+    assert_eq!(opened.load(Ordering::SeqCst), 4);
+    Ok(())
+}
+
+#[test]
+fn virtual_table_join() -> Result<()> {
+    let (c, opened, _) = setup()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (name TEXT, count INTEGER);
+
+        INSERT INTO users VALUES ('Alice', 2), ('Bob', 3);
+        "#,
+    )?;
+
+    let mut stmt = c.prepare(
+        "SELECT users.name, range.value FROM users, range(0, users.count) ORDER BY users.name, range.value",
+    )?;
+
+    let values = stmt
+        .iter::<(String, i64)>()
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let expected = [
+        (String::from("Alice"), 0),
+        (String::from("Alice"), 1),
+        (String::from("Bob"), 0),
+        (String::from("Bob"), 1),
+        (String::from("Bob"), 2),
+    ];
+
+    assert_eq!(values, expected);
+    assert!(opened.load(Ordering::SeqCst) >= 1);
+    Ok(())
+}
+
+#[test]
+fn virtual_table_errors() -> Result<()> {
+    let (c, _, _) = setup()?;
+
+    let e = c.prepare("SELECT value FROM range(1)").unwrap_err();
+    assert_eq!(e, Code::ERROR);
+    assert_eq!(c.error_message(), "range requires start and stop arguments");
+
+    let mut stmt = c.prepare("SELECT value FROM range(-1, 2)")?;
+    let e = stmt.step().unwrap_err();
+    assert_eq!(e, Code::RANGE);
+    assert_eq!(c.error_message(), "start must be positive");
+
+    // The table is read-only and can't be created explicitly.
+    let e = c.execute("INSERT INTO range VALUES (1, 2, 3)").unwrap_err();
+    assert_eq!(e, Code::ERROR);
+
+    let e = c
+        .execute("CREATE VIRTUAL TABLE numbers USING range")
+        .unwrap_err();
+    assert_eq!(e, Code::ERROR);
+    Ok(())
+}
+
+#[test]
+fn virtual_table_dropped() -> Result<()> {
+    let (c, _, dropped) = setup()?;
+
+    let mut stmt = c.prepare("SELECT value FROM range(0, 3)")?;
+    assert_eq!(stmt.next::<i64>()?, Some(0));
+    drop(stmt);
+
+    assert_eq!(dropped.load(Ordering::SeqCst), 0);
+    drop(c);
+    assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    Ok(())
+}
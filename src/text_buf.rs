@@ -0,0 +1,313 @@
+use core::borrow::Borrow;
+use core::ffi::c_int;
+use core::fmt;
+use core::ops::Deref;
+
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ty;
+use crate::{BIND_INDEX, Bind, BindValue, FromColumn, FromUnsizedColumn, Result, Statement, Text};
+
+/// An owned SQLite text value.
+///
+/// This is the owned counterpart to [`Text`] in the same way that [`String`]
+/// is to [`str`]. Like [`Text`] it does not require the underlying bytes to be
+/// valid UTF-8, which allows text values which are not well-formed to be
+/// stored without loss. See [`Text`] for more details.
+///
+/// It dereferences to [`Text`] which provides the rest of its functionality.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, Text, TextBuf};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE example (data TEXT);
+/// "#)?;
+///
+/// let mut insert = c.prepare("INSERT INTO example (data) VALUES (?)")?;
+/// insert.execute(Text::new(b"invalid: \xF0\x90\x80"))?;
+/// insert.execute(Text::new(b"valid: \xe2\x9d\xa4\xef\xb8\x8f"))?;
+///
+/// let mut stmt = c.prepare("SELECT data FROM example")?;
+/// let values = stmt.iter::<TextBuf>().collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(values[0].as_bytes(), b"invalid: \xF0\x90\x80");
+/// assert!(values[0].to_str().is_err());
+/// assert_eq!(values[1].to_str()?, "valid: ❤️");
+/// # Ok::<_, Box<dyn core::error::Error>>(())
+/// ```
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextBuf {
+    bytes: Vec<u8>,
+}
+
+impl TextBuf {
+    /// Construct a new empty `TextBuf`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::TextBuf;
+    ///
+    /// let t = TextBuf::new();
+    /// assert!(t.as_bytes().is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    /// Construct a `TextBuf` from a vector of bytes which might not be valid
+    /// UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::TextBuf;
+    ///
+    /// let t = TextBuf::from_vec(b"before\xF0\x90\x80after".to_vec());
+    /// assert_eq!(t.to_string(), "before�after");
+    /// ```
+    #[inline]
+    pub const fn from_vec(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Borrow the value as a [`Text`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Text, TextBuf};
+    ///
+    /// let t = TextBuf::from("example");
+    /// assert_eq!(t.as_text(), Text::new("example"));
+    /// ```
+    #[inline]
+    pub fn as_text(&self) -> &Text {
+        Text::from_bytes(&self.bytes)
+    }
+
+    /// Convert the value into its underlying bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::TextBuf;
+    ///
+    /// let t = TextBuf::from("example");
+    /// assert_eq!(t.into_bytes(), b"example");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl Deref for TextBuf {
+    type Target = Text;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_text()
+    }
+}
+
+impl Borrow<Text> for TextBuf {
+    #[inline]
+    fn borrow(&self) -> &Text {
+        self.as_text()
+    }
+}
+
+impl AsRef<Text> for TextBuf {
+    #[inline]
+    fn as_ref(&self) -> &Text {
+        self.as_text()
+    }
+}
+
+impl AsRef<[u8]> for TextBuf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// Convert a borrowed [`Text`] into an owned [`TextBuf`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Text, TextBuf};
+///
+/// let t: TextBuf = Text::new(b"\xF0\x90\x80").to_owned();
+/// assert_eq!(t.as_bytes(), b"\xF0\x90\x80");
+/// ```
+impl ToOwned for Text {
+    type Owned = TextBuf;
+
+    #[inline]
+    fn to_owned(&self) -> TextBuf {
+        TextBuf::from_vec(self.as_bytes().to_vec())
+    }
+}
+
+impl From<&Text> for TextBuf {
+    #[inline]
+    fn from(text: &Text) -> Self {
+        text.to_owned()
+    }
+}
+
+impl From<&str> for TextBuf {
+    #[inline]
+    fn from(string: &str) -> Self {
+        Self::from_vec(string.as_bytes().to_vec())
+    }
+}
+
+impl From<String> for TextBuf {
+    #[inline]
+    fn from(string: String) -> Self {
+        Self::from_vec(string.into_bytes())
+    }
+}
+
+impl From<Vec<u8>> for TextBuf {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_vec(bytes)
+    }
+}
+
+impl PartialEq<Text> for TextBuf {
+    #[inline]
+    fn eq(&self, other: &Text) -> bool {
+        self.as_text() == other
+    }
+}
+
+impl PartialEq<str> for TextBuf {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_text() == other
+    }
+}
+
+impl PartialEq<&str> for TextBuf {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_text() == *other
+    }
+}
+
+/// The display implementation converts the text into a UTF-8 string lossily,
+/// like [`Text`].
+impl fmt::Display for TextBuf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_text().fmt(f)
+    }
+}
+
+impl fmt::Debug for TextBuf {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_text().fmt(f)
+    }
+}
+
+/// [`FromColumn`] implementation which returns a newly allocated [`TextBuf`].
+///
+/// Unlike [`String`], this does not require the text to be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, TextBuf};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name);
+///
+///     INSERT INTO users (name) VALUES ('Alice'), (42);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT name FROM users")?;
+///
+/// assert_eq!(stmt.next::<TextBuf>()?, Some(TextBuf::from("Alice")));
+///
+/// let e = stmt.next::<TextBuf>().unwrap_err();
+/// assert_eq!(e.code(), Code::MISMATCH);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl FromColumn<'_> for TextBuf {
+    type Type = ty::Text;
+
+    #[inline]
+    fn from_column(stmt: &Statement, index: ty::Text) -> Result<Self> {
+        Ok(Text::from_unsized_column(stmt, index)?.to_owned())
+    }
+}
+
+/// [`BindValue`] implementation for [`TextBuf`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, TextBuf, BIND_INDEX};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+/// stmt.bind_value(BIND_INDEX, TextBuf::from("Alice"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(42)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl BindValue for TextBuf {
+    #[inline]
+    fn bind_value(&self, stmt: &mut Statement, index: c_int) -> Result<()> {
+        self.as_text().bind_value(stmt, index)
+    }
+}
+
+/// [`Bind`] implementation for [`TextBuf`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, TextBuf};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT, age INTEGER);
+///
+///     INSERT INTO users (name, age) VALUES ('Alice', 42), ('Bob', 30);
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT age FROM users WHERE name = ?")?;
+/// stmt.bind(TextBuf::from("Bob"))?;
+/// assert_eq!(stmt.iter::<i64>().collect::<Vec<_>>(), [Ok(30)]);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+impl Bind for TextBuf {
+    #[inline]
+    fn bind(&self, stmt: &mut Statement) -> Result<()> {
+        self.bind_value(stmt, BIND_INDEX)
+    }
+}
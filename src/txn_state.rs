@@ -0,0 +1,88 @@
+use core::ffi::c_int;
+use core::fmt;
+
+use crate::ffi;
+
+/// The transaction state of a database.
+///
+/// See [`Connection::transaction_state`].
+///
+/// [`Connection::transaction_state`]: crate::Connection::transaction_state
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Connection, TxnState};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE users (name TEXT);
+/// "#)?;
+///
+/// assert_eq!(c.transaction_state(None)?, TxnState::NONE);
+///
+/// c.execute("BEGIN")?;
+/// c.execute("INSERT INTO users VALUES ('Alice')")?;
+/// assert_eq!(c.transaction_state(None)?, TxnState::WRITE);
+/// c.execute("COMMIT")?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub struct TxnState {
+    raw: c_int,
+}
+
+impl TxnState {
+    /// Construct from a raw transaction state.
+    #[inline]
+    pub(crate) const fn new(raw: c_int) -> Self {
+        Self { raw }
+    }
+
+    /// No transaction is currently pending.
+    pub const NONE: Self = Self::new(ffi::SQLITE_TXN_NONE);
+
+    /// A read transaction has been started, but no writes have been performed.
+    ///
+    /// The transaction will be upgraded to a write transaction if any writes
+    /// are performed, which might fail if another connection holds a write
+    /// lock.
+    pub const READ: Self = Self::new(ffi::SQLITE_TXN_READ);
+
+    /// A write transaction has been started, meaning that at least one write
+    /// has been performed or the transaction was started with `BEGIN
+    /// IMMEDIATE` or `BEGIN EXCLUSIVE`.
+    pub const WRITE: Self = Self::new(ffi::SQLITE_TXN_WRITE);
+}
+
+/// Display implementation for [`TxnState`].
+///
+/// # Examples
+///
+/// ```
+/// use sqll::TxnState;
+///
+/// assert_eq!(TxnState::NONE.to_string(), "NONE");
+/// assert_eq!(TxnState::READ.to_string(), "READ");
+/// assert_eq!(TxnState::WRITE.to_string(), "WRITE");
+/// ```
+impl fmt::Display for TxnState {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.raw {
+            ffi::SQLITE_TXN_NONE => write!(f, "NONE"),
+            ffi::SQLITE_TXN_READ => write!(f, "READ"),
+            ffi::SQLITE_TXN_WRITE => write!(f, "WRITE"),
+            raw => write!(f, "UNKNOWN({raw})"),
+        }
+    }
+}
+
+impl fmt::Debug for TxnState {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
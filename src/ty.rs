@@ -51,4 +51,4 @@ mod ty;
 pub use self::not_null::NotNull;
 pub(crate) use self::ty::AnyKind;
 #[doc(inline)]
-pub use self::ty::{Any, Blob, Float, Integer, Nullable, Text, Type};
+pub use self::ty::{Any, Blob, Fallible, Float, Integer, Nullable, Text, Type};
@@ -6,7 +6,7 @@ use crate::{Code, Error, Null, Result, Statement, ValueType};
 use super::NotNull;
 
 mod sealed {
-    use super::{Any, Blob, Float, Integer, NotNull, Null, Nullable, Text};
+    use super::{Any, Blob, Fallible, Float, Integer, NotNull, Null, Nullable, Text, Type};
 
     pub trait Sealed
     where
@@ -21,6 +21,7 @@ mod sealed {
     impl Sealed for Blob {}
     impl Sealed for Text {}
     impl<T> Sealed for Nullable<T> where T: NotNull {}
+    impl<T> Sealed for Fallible<T> where T: Type {}
 }
 
 /// A trait which defines the underlying static column type that is supported by
@@ -107,6 +108,41 @@ where
 /// assert_eq!(select.next::<MyValue<'_>>()?, None);
 /// # Ok::<_, sqll::Error>(())
 /// ```
+///
+/// Since `Any` is [`NotNull`], [`Nullable<Any>`] can be used to read any value
+/// including `NULL`, which is then decoded into an `Option<Value>`:
+///
+/// ```
+/// use sqll::{Connection, FromColumn, Result, Statement, Value};
+/// use sqll::ty;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct MaybeValue<'stmt>(Option<Value<'stmt>>);
+///
+/// impl<'stmt> FromColumn<'stmt> for MaybeValue<'stmt> {
+///     type Type = ty::Nullable<ty::Any>;
+///
+///     #[inline]
+///     fn from_column(stmt: &'stmt Statement, index: ty::Nullable<ty::Any>) -> Result<Self> {
+///         Ok(MaybeValue(<_>::from_column(stmt, index)?))
+///     }
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE test (value);
+///
+///     INSERT INTO test (value) VALUES (42), (NULL), ('Hello');
+/// "#)?;
+///
+/// let mut select = c.prepare("SELECT value FROM test")?;
+/// assert_eq!(select.next::<MaybeValue<'_>>()?, Some(MaybeValue(Some(Value::integer(42)))));
+/// assert_eq!(select.next::<MaybeValue<'_>>()?, Some(MaybeValue(None)));
+/// assert_eq!(select.next::<MaybeValue<'_>>()?, Some(MaybeValue(Some(Value::text("Hello")))));
+/// assert_eq!(select.next::<MaybeValue<'_>>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
 pub struct Any {
     kind: AnyKind,
 }
@@ -517,6 +553,7 @@ unsafe impl Type for Text {
                 ));
             };
 
+            stmt.check_column_size(len)?;
             Ok(Self { index, len })
         }
     }
@@ -639,6 +676,7 @@ unsafe impl Type for Blob {
                 ));
             };
 
+            stmt.check_column_size(len)?;
             Ok(Self { index, len })
         }
     }
@@ -759,6 +797,85 @@ where
     }
 }
 
+/// A type that captures the error of checking its inner type instead of
+/// failing.
+///
+/// This is used to implement [`FromColumn`] for types which should report a
+/// failure to read a single column without failing the entire row, like
+/// [`Lenient<T>`].
+///
+/// [`FromColumn`]: crate::FromColumn
+/// [`Lenient<T>`]: crate::Lenient
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Code, Connection, FromColumn, Result, Statement};
+/// use sqll::ty;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Checked(Option<i64>);
+///
+/// impl FromColumn<'_> for Checked {
+///     type Type = ty::Fallible<ty::Integer>;
+///
+///     #[inline]
+///     fn from_column(stmt: &Statement, index: ty::Fallible<ty::Integer>) -> Result<Self> {
+///         match index.into_result() {
+///             Ok(index) => Ok(Checked(Some(i64::from_column(stmt, index)?))),
+///             Err(..) => Ok(Checked(None)),
+///         }
+///     }
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE test (value);
+///
+///     INSERT INTO test (value) VALUES (42), ('not a number');
+/// "#)?;
+///
+/// let mut stmt = c.prepare("SELECT value FROM test")?;
+///
+/// assert_eq!(stmt.next::<Checked>()?, Some(Checked(Some(42))));
+/// assert_eq!(stmt.next::<Checked>()?, Some(Checked(None)));
+/// assert_eq!(stmt.next::<Checked>()?, None);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub struct Fallible<T>
+where
+    T: Type,
+{
+    inner: Result<T>,
+}
+
+impl<T> Fallible<T>
+where
+    T: Type,
+{
+    /// Get the result of checking the inner type.
+    #[inline]
+    pub fn into_result(self) -> Result<T> {
+        self.inner
+    }
+}
+
+/// [`Type`] implementation for a column whose check is allowed to fail.
+///
+/// See [`Fallible`].
+unsafe impl<T> Type for Fallible<T>
+where
+    T: Type,
+{
+    #[inline]
+    fn check(stmt: &mut Statement, index: c_int) -> Result<Self> {
+        Ok(Fallible {
+            inner: T::check(stmt, index),
+        })
+    }
+}
+
 // NB: We have to perform strict type checking to avoid auto-conversion, if we
 // permit it, the pointers that have previously been fetched for a given column
 // may become invalidated.
@@ -8,10 +8,7 @@ macro_rules! __sqlite3_try {
         let code = $expr;
 
         if code != $crate::ffi::SQLITE_OK {
-            return Err($crate::Error::new(
-                $crate::Code::new(code),
-                $db.error_message(),
-            ));
+            return Err($db.error(code));
         }
     }};
 }
@@ -61,3 +58,54 @@ pub(crate) unsafe fn c_to_error_text(ptr: *const i8) -> &'static Text {
     static DEFAULT_MESSAGE: &Text = Text::from_bytes(b"not an error");
     unsafe { c_to_text(ptr).unwrap_or(DEFAULT_MESSAGE) }
 }
+
+/// Test if the remainder of a prepared SQL string is insignificant, in that it
+/// only contains whitespace, comments, or empty statements.
+pub(crate) fn is_insignificant_sql(mut rest: &[u8]) -> bool {
+    loop {
+        rest = match rest {
+            [] => return true,
+            [b' ' | b'\t' | b'\n' | b'\x0c' | b'\r' | b';', tail @ ..] => tail,
+            [b'-', b'-', tail @ ..] => match tail.iter().position(|&b| b == b'\n') {
+                Some(n) => &tail[n + 1..],
+                None => return true,
+            },
+            [b'/', b'*', tail @ ..] => match tail.windows(2).position(|w| w == b"*/") {
+                Some(n) => &tail[n + 2..],
+                None => return true,
+            },
+            _ => return false,
+        };
+    }
+}
+
+/// Test if the remainder of a prepared UTF-16 SQL string is insignificant.
+///
+/// See [`is_insignificant_sql`].
+pub(crate) fn is_insignificant_sql16(mut rest: &[u16]) -> bool {
+    const NEWLINE: u16 = b'\n' as u16;
+    const END_COMMENT: [u16; 2] = [b'*' as u16, b'/' as u16];
+
+    loop {
+        let Some((&c, tail)) = rest.split_first() else {
+            return true;
+        };
+
+        rest = match (u8::try_from(c), tail) {
+            (Ok(b' ' | b'\t' | b'\n' | b'\x0c' | b'\r' | b';'), tail) => tail,
+            (Ok(b'-'), [next, tail @ ..]) if *next == u16::from(b'-') => {
+                match tail.iter().position(|&c| c == NEWLINE) {
+                    Some(n) => &tail[n + 1..],
+                    None => return true,
+                }
+            }
+            (Ok(b'/'), [next, tail @ ..]) if *next == u16::from(b'*') => {
+                match tail.windows(2).position(|w| w == END_COMMENT) {
+                    Some(n) => &tail[n + 2..],
+                    None => return true,
+                }
+            }
+            _ => return false,
+        };
+    }
+}
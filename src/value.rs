@@ -1,6 +1,9 @@
+use core::cmp::Ordering;
 use core::fmt;
 
-use crate::{Text, ValueType};
+use crate::{BlobBase64, BlobHex, Text, ValueType};
+#[cfg(feature = "serde")]
+use crate::{BlobEncoding, SerializeValue};
 
 /// A dynamic value.
 ///
@@ -47,7 +50,7 @@ use crate::{Text, ValueType};
 /// assert_eq!(select.iter::<Null>().collect::<Vec<_>>(), [Ok(Null)]);
 /// # Ok::<_, sqll::Error>(())
 /// ```
-#[derive(Clone, PartialEq)]
+#[derive(Clone)]
 pub struct Value<'stmt> {
     kind: Kind<'stmt>,
 }
@@ -221,6 +224,63 @@ impl<'stmt> Value<'stmt> {
         None
     }
 
+    /// Display the binary data as a lowercase hexadecimal string if the value
+    /// is `Binary`.
+    ///
+    /// This is useful when rendering blobs for things like logging or CSV
+    /// output. The returned value implements [`Display`], so it can be written
+    /// directly or converted into a string using `to_string()`.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Value;
+    ///
+    /// let value = Value::blob(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// assert_eq!(value.blob_to_hex().map(|hex| hex.to_string()).as_deref(), Some("deadbeef"));
+    ///
+    /// let value = Value::text("deadbeef");
+    /// assert!(value.blob_to_hex().is_none());
+    /// ```
+    #[inline]
+    pub const fn blob_to_hex(&self) -> Option<BlobHex<'stmt>> {
+        if let Kind::Blob(value) = self.kind {
+            return Some(BlobHex::new(value));
+        }
+
+        None
+    }
+
+    /// Display the binary data as a padded base64 string using the standard
+    /// alphabet if the value is `Binary`.
+    ///
+    /// The returned value implements [`Display`], so it can be written
+    /// directly or converted into a string using `to_string()`.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::Value;
+    ///
+    /// let value = Value::blob(b"Hello");
+    /// assert_eq!(value.blob_to_base64().map(|b| b.to_string()).as_deref(), Some("SGVsbG8="));
+    ///
+    /// let value = Value::integer(42);
+    /// assert!(value.blob_to_base64().is_none());
+    /// ```
+    #[inline]
+    pub const fn blob_to_base64(&self) -> Option<BlobBase64<'stmt>> {
+        if let Kind::Blob(value) = self.kind {
+            return Some(BlobBase64::new(value));
+        }
+
+        None
+    }
+
     /// Return the [`ValueType`] of the value.
     ///
     /// # Examples
@@ -260,6 +320,29 @@ impl<'stmt> Value<'stmt> {
             Kind::Text(_) => ValueType::TEXT,
         }
     }
+
+    /// Serialize the value using the given [`BlobEncoding`] for blobs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{BlobEncoding, Value};
+    ///
+    /// let value = Value::blob(&[0xDE, 0xAD, 0xBE, 0xEF]);
+    /// let json = serde_json::to_string(&value.serialize_with(BlobEncoding::Hex))?;
+    /// assert_eq!(json, r#""deadbeef""#);
+    ///
+    /// let value = Value::integer(42);
+    /// let json = serde_json::to_string(&value.serialize_with(BlobEncoding::Hex))?;
+    /// assert_eq!(json, "42");
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, cfg(feature = "serde"))]
+    #[inline]
+    pub fn serialize_with(&self, encoding: BlobEncoding) -> SerializeValue<'_, 'stmt> {
+        SerializeValue::new(self, encoding)
+    }
 }
 
 /// Debug implementation for [`Value`].
@@ -300,3 +383,239 @@ impl fmt::Debug for Value<'_> {
         }
     }
 }
+
+/// Display implementation for [`Value`], suitable for logging.
+///
+/// Integers and floats are formatted as numbers, text is written as-is with
+/// invalid UTF-8 sequences replaced by `U+FFFD`, and blobs are written as
+/// lowercase hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::{Text, Value};
+///
+/// assert_eq!(Value::integer(42).to_string(), "42");
+/// assert_eq!(Value::float(3.5).to_string(), "3.5");
+/// assert_eq!(Value::text("hello").to_string(), "hello");
+/// assert_eq!(Value::text(Text::new(b"hello\xFFworld")).to_string(), "hello\u{FFFD}world");
+/// assert_eq!(Value::blob(&[0xDE, 0xAD, 0xBE, 0xEF]).to_string(), "deadbeef");
+/// ```
+impl fmt::Display for Value<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            Kind::Integer(value) => write!(f, "{value}"),
+            Kind::Float(value) => write!(f, "{value}"),
+            Kind::Text(value) => write!(f, "{value}"),
+            Kind::Blob(value) => {
+                for byte in value {
+                    write!(f, "{byte:02x}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Equality for [`Value`] following the rules used by sqlite.
+///
+/// This is consistent with the [`Ord`] implementation, so values of different
+/// numeric types might compare equal.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Value;
+///
+/// assert_eq!(Value::integer(1), Value::float(1.0));
+/// assert_eq!(Value::float(0.0), Value::float(-0.0));
+/// assert_ne!(Value::integer(1), Value::float(1.5));
+/// assert_ne!(Value::integer(1), Value::text("1"));
+/// assert_ne!(Value::text("a"), Value::blob(b"a"));
+/// ```
+impl PartialEq for Value<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Value<'_> {}
+
+impl PartialOrd for Value<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Ordering for [`Value`] following the sort order used by sqlite.
+///
+/// Values are first ordered by their storage class where numbers (`INTEGER`
+/// and `FLOAT`) sort before `TEXT`, which sorts before `BLOB`. Since `NULL`
+/// cannot be represented by a [`Value`] it is represented with `None` in an
+/// [`Option<Value>`], which conveniently orders `NULL` before any other value
+/// the same way sqlite does.
+///
+/// Within the same storage class:
+/// * Numbers are compared by their numerical value, regardless of whether
+///   they are an `INTEGER` or a `FLOAT`. The comparison between integers and
+///   floats is exact, so `2^53 + 1` compares greater than the float `2^53` even
+///   though converting the integer into a float would make them equal. Zero
+///   and negative zero compare equal.
+/// * Text is compared using the `BINARY` collation, which compares the bytes of
+///   the text.
+/// * Blobs are compared by their bytes.
+///
+/// Note that sqlite never stores a floating point NaN, instead converting it
+/// into `NULL`. To maintain a total order a NaN is considered equal to other
+/// NaNs and greater than all other numbers.
+///
+/// # Examples
+///
+/// ```
+/// use sqll::Value;
+///
+/// let mut values = vec![
+///     Some(Value::blob(b"\x01")),
+///     Some(Value::text("b")),
+///     Some(Value::integer(2)),
+///     None,
+///     Some(Value::text("a")),
+///     Some(Value::float(1.5)),
+///     Some(Value::integer(1)),
+/// ];
+///
+/// values.sort();
+///
+/// assert_eq!(values, [
+///     None,
+///     Some(Value::integer(1)),
+///     Some(Value::float(1.5)),
+///     Some(Value::integer(2)),
+///     Some(Value::text("a")),
+///     Some(Value::text("b")),
+///     Some(Value::blob(b"\x01")),
+/// ]);
+/// ```
+///
+/// Sorting in memory agrees with sqlite:
+///
+/// ```
+/// use sqll::{Connection, Result, Value};
+///
+/// let c = Connection::open_in_memory()?;
+///
+/// c.execute(r#"
+///     CREATE TABLE test (id INTEGER, value);
+/// "#)?;
+///
+/// let values = [
+///     Some(Value::blob(b"\x00")),
+///     Some(Value::text("10")),
+///     Some(Value::integer(10)),
+///     Some(Value::float(9.5)),
+///     None,
+///     Some(Value::text("9")),
+///     Some(Value::integer(-1)),
+///     Some(Value::float(-1.5)),
+/// ];
+///
+/// let mut stmt = c.prepare("INSERT INTO test VALUES (?, ?)")?;
+///
+/// for (id, value) in values.iter().enumerate() {
+///     stmt.execute((id as i64, value))?;
+/// }
+///
+/// let mut stmt = c.prepare("SELECT id FROM test ORDER BY value")?;
+/// let expected = stmt.iter::<i64>().collect::<Result<Vec<_>>>()?;
+///
+/// let mut sorted = values.iter().enumerate().collect::<Vec<_>>();
+/// sorted.sort_by(|a, b| a.1.cmp(b.1));
+/// let sorted = sorted.into_iter().map(|(id, _)| id as i64).collect::<Vec<_>>();
+///
+/// assert_eq!(sorted, expected);
+/// # Ok::<_, sqll::Error>(())
+/// ```
+///
+/// Integers and floats are compared exactly:
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use sqll::Value;
+///
+/// let big = 1i64 << 53;
+///
+/// assert_eq!(Value::integer(big + 1).cmp(&Value::float(big as f64)), Ordering::Greater);
+/// assert_eq!(Value::integer(i64::MAX).cmp(&Value::float(i64::MAX as f64)), Ordering::Less);
+/// assert_eq!(Value::integer(-1).cmp(&Value::float(-0.5)), Ordering::Less);
+/// assert_eq!(Value::integer(0).cmp(&Value::float(-0.5)), Ordering::Greater);
+/// assert_eq!(Value::integer(i64::MAX).cmp(&Value::float(f64::NAN)), Ordering::Less);
+/// assert_eq!(Value::float(f64::INFINITY).cmp(&Value::float(f64::NAN)), Ordering::Less);
+/// ```
+impl Ord for Value<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.kind, other.kind) {
+            (Kind::Integer(a), Kind::Integer(b)) => a.cmp(&b),
+            (Kind::Float(a), Kind::Float(b)) => float_cmp(a, b),
+            (Kind::Integer(a), Kind::Float(b)) => int_float_cmp(a, b),
+            (Kind::Float(a), Kind::Integer(b)) => int_float_cmp(b, a).reverse(),
+            (Kind::Text(a), Kind::Text(b)) => a.as_bytes().cmp(b.as_bytes()),
+            (Kind::Blob(a), Kind::Blob(b)) => a.cmp(b),
+            (a, b) => a.class().cmp(&b.class()),
+        }
+    }
+}
+
+impl Kind<'_> {
+    /// The storage class used for ordering values of different types.
+    #[inline]
+    fn class(&self) -> u8 {
+        match self {
+            Kind::Integer(..) | Kind::Float(..) => 0,
+            Kind::Text(..) => 1,
+            Kind::Blob(..) => 2,
+        }
+    }
+}
+
+/// Compare two floats, where NaN is considered greater than all other numbers.
+#[inline]
+fn float_cmp(a: f64, b: f64) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Compare an integer with a float exactly.
+fn int_float_cmp(a: i64, b: f64) -> Ordering {
+    // NB: 2^63 is exactly representable as a float.
+    const MAX: f64 = 9223372036854775808.0;
+
+    if b.is_nan() {
+        return Ordering::Less;
+    }
+
+    if b < -MAX {
+        return Ordering::Greater;
+    }
+
+    if b >= MAX {
+        return Ordering::Less;
+    }
+
+    // The float is in range so truncating it is exact, and the truncated value
+    // is exactly representable as a float.
+    let truncated = b as i64;
+
+    match a.cmp(&truncated) {
+        Ordering::Equal => float_cmp(truncated as f64, b),
+        ordering => ordering,
+    }
+}
@@ -1,6 +1,8 @@
 use core::ffi::c_int;
 use core::fmt;
+use core::str::FromStr;
 
+use crate::ParseValueTypeError;
 use crate::ffi;
 
 /// The type of a value.
@@ -14,6 +16,11 @@ use crate::ffi;
 /// [`Statement::column_type`]: crate::Statement::column_type
 /// [`Value::column_type`]: crate::Value::column_type
 ///
+/// To predict which value type data will be stored as in a column based on its
+/// declared type, see [`Affinity`].
+///
+/// [`Affinity`]: crate::Affinity
+///
 /// # Examples
 ///
 /// ```
@@ -192,6 +199,49 @@ impl ValueType {
     /// # Ok::<_, sqll::Error>(())
     /// ```
     pub const NULL: Self = Self::new(ffi::SQLITE_NULL);
+
+    /// Get the name of the sqlite storage class corresponding to this type.
+    ///
+    /// This is the upper case name of the storage class as used in the sqlite
+    /// documentation and as returned in lower case by the `typeof()` SQL
+    /// function. Note that this differs from the [`Display`] implementation for
+    /// [`ValueType::FLOAT`], which is stored in the `REAL` storage class.
+    ///
+    /// Types not known to this crate are reported as `UNKNOWN`.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sqll::{Connection, ValueType};
+    ///
+    /// assert_eq!(ValueType::INTEGER.as_str(), "INTEGER");
+    /// assert_eq!(ValueType::FLOAT.as_str(), "REAL");
+    /// assert_eq!(ValueType::TEXT.as_str(), "TEXT");
+    /// assert_eq!(ValueType::BLOB.as_str(), "BLOB");
+    /// assert_eq!(ValueType::NULL.as_str(), "NULL");
+    ///
+    /// let c = Connection::open_in_memory()?;
+    ///
+    /// let mut stmt = c.prepare("SELECT 1.5, typeof(1.5)")?;
+    /// assert!(stmt.step()?.is_row());
+    ///
+    /// let ty = stmt.column_type(0);
+    /// assert!(ty.as_str().eq_ignore_ascii_case(stmt.column::<&str>(1)?));
+    /// # Ok::<_, sqll::Error>(())
+    /// ```
+    #[inline]
+    pub const fn as_str(self) -> &'static str {
+        match self.raw {
+            ffi::SQLITE_BLOB => "BLOB",
+            ffi::SQLITE_TEXT => "TEXT",
+            ffi::SQLITE_FLOAT => "REAL",
+            ffi::SQLITE_INTEGER => "INTEGER",
+            ffi::SQLITE_NULL => "NULL",
+            _ => "UNKNOWN",
+        }
+    }
 }
 
 /// Display implementation for [`ValueType`].
@@ -221,6 +271,58 @@ impl fmt::Display for ValueType {
     }
 }
 
+/// Parse a [`ValueType`] from its [`Display`] representation.
+///
+/// Parsing is case-insensitive, and the storage class name `REAL` as returned
+/// by [`ValueType::as_str`] is accepted as an alias for [`ValueType::FLOAT`].
+/// To determine the type affinity of an arbitrary
+/// declared column type such as `VARCHAR(255)`, use [`Affinity::of`] instead.
+///
+/// [`Display`]: fmt::Display
+/// [`Affinity::of`]: crate::Affinity::of
+///
+/// # Examples
+///
+/// ```
+/// use sqll::ValueType;
+///
+/// assert_eq!("INTEGER".parse::<ValueType>()?, ValueType::INTEGER);
+/// assert_eq!("float".parse::<ValueType>()?, ValueType::FLOAT);
+/// assert_eq!("Text".parse::<ValueType>()?, ValueType::TEXT);
+/// assert_eq!("BLOB".parse::<ValueType>()?, ValueType::BLOB);
+/// assert_eq!("NULL".parse::<ValueType>()?, ValueType::NULL);
+/// assert_eq!("real".parse::<ValueType>()?, ValueType::FLOAT);
+/// assert!("VARCHAR".parse::<ValueType>().is_err());
+///
+/// for ty in [ValueType::INTEGER, ValueType::FLOAT, ValueType::TEXT, ValueType::BLOB, ValueType::NULL] {
+///     assert_eq!(ty.to_string().parse::<ValueType>()?, ty);
+///     assert_eq!(ty.as_str().parse::<ValueType>()?, ty);
+/// }
+/// # Ok::<_, sqll::ParseValueTypeError>(())
+/// ```
+impl FromStr for ValueType {
+    type Err = ParseValueTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const TYPES: [(&str, ValueType); 6] = [
+            ("INTEGER", ValueType::INTEGER),
+            ("FLOAT", ValueType::FLOAT),
+            ("REAL", ValueType::FLOAT),
+            ("TEXT", ValueType::TEXT),
+            ("BLOB", ValueType::BLOB),
+            ("NULL", ValueType::NULL),
+        ];
+
+        for (name, ty) in TYPES {
+            if s.eq_ignore_ascii_case(name) {
+                return Ok(ty);
+            }
+        }
+
+        Err(ParseValueTypeError)
+    }
+}
+
 impl fmt::Debug for ValueType {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
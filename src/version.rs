@@ -24,6 +24,32 @@ pub fn lib_version() -> &'static str {
     }
 }
 
+/// Return the source identifier of the SQLite library in use.
+///
+/// This identifies the exact check-in of the SQLite source code that the
+/// library was built from and consists of a date and time followed by a
+/// SHA3-256 hash, like `2025-11-04 14:05:52 b7a6...`. This is useful for
+/// diagnostics, like distinguishing between a bundled and a system library
+/// which share the same [`lib_version`].
+///
+/// # Examples
+///
+/// ```
+/// let source_id = sqll::lib_source_id();
+/// let (date, _) = source_id.split_once(' ').unwrap();
+/// assert_eq!(date.len(), 10);
+/// assert!(date.starts_with("20"));
+/// assert!(sqll::lib_version().starts_with("3."));
+/// ```
+#[inline]
+pub fn lib_source_id() -> &'static str {
+    unsafe {
+        let c_str = ffi::sqlite3_sourceid();
+        let bytes = CStr::from_ptr(c_str).to_bytes();
+        str::from_utf8_unchecked(bytes)
+    }
+}
+
 /// Return the version number of the SQLite library in use.
 ///
 /// The version `3.51.2` as returned by [`lib_version`] would correspond to the
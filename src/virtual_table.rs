@@ -0,0 +1,672 @@
+use core::ffi::{CStr, c_char, c_int, c_void};
+use core::fmt;
+use core::ptr;
+use core::slice;
+
+use alloc::boxed::Box;
+
+use crate::ffi;
+use crate::{Arguments, Context, Error, Result};
+
+/// A read-only virtual table.
+///
+/// Virtual tables are registered with [`Connection::create_module`] and are
+/// eponymous, which means that they can be queried directly by the name of the
+/// module without being created with `CREATE VIRTUAL TABLE`. Columns declared
+/// as `HIDDEN` can be used as arguments, allowing the table to be used like a
+/// table-valued function.
+///
+/// The table is shared by every query using it, while a new [`Cursor`] is
+/// opened for each scan of the table.
+///
+/// See the [virtual table documentation] for details on how each method is
+/// used.
+///
+/// [`Connection::create_module`]: crate::Connection::create_module
+/// [`Cursor`]: Self::Cursor
+/// [virtual table documentation]: https://sqlite.org/vtab.html
+///
+/// # Examples
+///
+/// ```
+/// use core::ffi::{CStr, c_int};
+///
+/// use sqll::{Arguments, ConstraintOp, Connection, Context, IndexInfo, Result, ReturnValue};
+/// use sqll::{VirtualCursor, VirtualTable};
+///
+/// struct Squares;
+///
+/// impl VirtualTable for Squares {
+///     type Cursor = SquaresCursor;
+///
+///     fn connect(&self) -> Result<&CStr> {
+///         Ok(c"CREATE TABLE x(value INTEGER, square INTEGER, count HIDDEN)")
+///     }
+///
+///     fn best_index(&self, info: &mut IndexInfo<'_>) -> Result<()> {
+///         let found = info.constraints().iter().position(|c| {
+///             c.is_usable() && c.column() == 2 && c.op() == ConstraintOp::EQ
+///         });
+///
+///         if let Some(index) = found {
+///             info.constraint_usage_mut()[index].set_argv_index(1).set_omit(true);
+///             info.set_index_num(1);
+///         }
+///
+///         Ok(())
+///     }
+///
+///     fn open(&self) -> Result<SquaresCursor> {
+///         Ok(SquaresCursor { value: 0, count: 0 })
+///     }
+/// }
+///
+/// struct SquaresCursor {
+///     value: i64,
+///     count: i64,
+/// }
+///
+/// impl VirtualCursor for SquaresCursor {
+///     fn filter(&mut self, index_num: c_int, args: &Arguments<'_>) -> Result<()> {
+///         self.value = 1;
+///         self.count = match index_num {
+///             1 => args.get(0).and_then(|v| v.as_integer()).unwrap_or(0),
+///             _ => 10,
+///         };
+///
+///         Ok(())
+///     }
+///
+///     fn next(&mut self) -> Result<()> {
+///         self.value += 1;
+///         Ok(())
+///     }
+///
+///     fn eof(&self) -> bool {
+///         self.value > self.count
+///     }
+///
+///     fn column(&self, ctx: &mut Context, index: c_int) -> Result<()> {
+///         match index {
+///             0 => self.value.return_value(ctx),
+///             1 => (self.value * self.value).return_value(ctx),
+///             _ => self.count.return_value(ctx),
+///         }
+///     }
+///
+///     fn rowid(&self) -> Result<i64> {
+///         Ok(self.value)
+///     }
+/// }
+///
+/// let c = Connection::open_in_memory()?;
+/// c.create_module(c"squares", Squares)?;
+///
+/// let mut stmt = c.prepare("SELECT square FROM squares(3)")?;
+/// let values = stmt.iter::<i64>().collect::<Result<Vec<_>>>()?;
+/// assert_eq!(values, [1, 4, 9]);
+///
+/// let mut stmt = c.prepare("SELECT COUNT(*) FROM squares")?;
+/// assert_eq!(stmt.next::<i64>()?, Some(10));
+/// # Ok::<_, sqll::Error>(())
+/// ```
+pub trait VirtualTable: Send + 'static {
+    /// The cursor used to scan the table.
+    type Cursor: VirtualCursor;
+
+    /// Connect to the table, returning the `CREATE TABLE` statement which
+    /// declares its schema.
+    ///
+    /// The name of the table in the statement is ignored.
+    fn connect(&self) -> Result<&CStr>;
+
+    /// Determine the best way to scan the table given the constraints in
+    /// `info`.
+    ///
+    /// Constraints which are used are passed as arguments to
+    /// [`VirtualCursor::filter`] in the order specified by
+    /// [`ConstraintUsage::set_argv_index`], together with the index number set
+    /// using [`IndexInfo::set_index_num`].
+    ///
+    /// Returning an error with [`Code::CONSTRAINT`] indicates that the
+    /// combination of constraints is unusable, in which case sqlite will try
+    /// another plan.
+    ///
+    /// [`Code::CONSTRAINT`]: crate::Code::CONSTRAINT
+    fn best_index(&self, info: &mut IndexInfo<'_>) -> Result<()>;
+
+    /// Open a new cursor to scan the table.
+    fn open(&self) -> Result<Self::Cursor>;
+}
+
+/// A cursor scanning a [`VirtualTable`].
+///
+/// See [`VirtualTable`] for an example.
+pub trait VirtualCursor: Send + 'static {
+    /// Start a new scan of the table.
+    ///
+    /// The `index_num` and `args` are determined by
+    /// [`VirtualTable::best_index`]. After this is called the cursor should be
+    /// positioned on the first row, or report [`eof`] if there are no rows.
+    ///
+    /// [`eof`]: Self::eof
+    fn filter(&mut self, index_num: c_int, args: &Arguments<'_>) -> Result<()>;
+
+    /// Advance the cursor to the next row.
+    fn next(&mut self) -> Result<()>;
+
+    /// Test if the cursor has moved past the last row.
+    fn eof(&self) -> bool;
+
+    /// Set the value of the column at the given index of the current row
+    /// through `ctx`, typically using [`ReturnValue::return_value`].
+    ///
+    /// [`ReturnValue::return_value`]: crate::ReturnValue::return_value
+    fn column(&self, ctx: &mut Context, index: c_int) -> Result<()>;
+
+    /// Get the rowid of the current row.
+    fn rowid(&self) -> Result<i64>;
+}
+
+/// Information about a query used to pick the best way to scan a
+/// [`VirtualTable`].
+///
+/// See [`VirtualTable::best_index`].
+pub struct IndexInfo<'a> {
+    raw: &'a mut ffi::sqlite3_index_info,
+}
+
+impl IndexInfo<'_> {
+    /// The constraints in the `WHERE` clause of the query which apply to the
+    /// table.
+    #[inline]
+    pub fn constraints(&self) -> &[IndexConstraint] {
+        unsafe { raw_slice(self.raw.aConstraint.cast(), self.raw.nConstraint) }
+    }
+
+    /// Mutable access to how each constraint is used, which has the same
+    /// length and order as [`constraints`].
+    ///
+    /// [`constraints`]: Self::constraints
+    #[inline]
+    pub fn constraint_usage_mut(&mut self) -> &mut [ConstraintUsage] {
+        let len = usize::try_from(self.raw.nConstraint).unwrap_or_default();
+
+        if len == 0 || self.raw.aConstraintUsage.is_null() {
+            return &mut [];
+        }
+
+        unsafe { slice::from_raw_parts_mut(self.raw.aConstraintUsage.cast(), len) }
+    }
+
+    /// Set the index number which is passed to [`VirtualCursor::filter`].
+    #[inline]
+    pub fn set_index_num(&mut self, index_num: c_int) {
+        self.raw.idxNum = index_num;
+    }
+
+    /// Set the estimated cost of scanning the table with the current plan.
+    #[inline]
+    pub fn set_estimated_cost(&mut self, cost: f64) {
+        self.raw.estimatedCost = cost;
+    }
+
+    /// Set the estimated number of rows produced with the current plan.
+    #[inline]
+    pub fn set_estimated_rows(&mut self, rows: i64) {
+        self.raw.estimatedRows = rows;
+    }
+}
+
+/// A constraint in the `WHERE` clause of a query.
+///
+/// See [`IndexInfo::constraints`].
+#[repr(transparent)]
+pub struct IndexConstraint {
+    raw: ffi::sqlite3_index_info_sqlite3_index_constraint,
+}
+
+impl IndexConstraint {
+    /// The index of the column the constraint applies to, or `-1` for the
+    /// rowid.
+    #[inline]
+    pub fn column(&self) -> c_int {
+        self.raw.iColumn
+    }
+
+    /// The operator of the constraint.
+    #[inline]
+    pub fn op(&self) -> ConstraintOp {
+        ConstraintOp::new(c_int::from(self.raw.op))
+    }
+
+    /// Test if the constraint can be used by the current plan.
+    ///
+    /// Constraints which are not usable must not be given an argument.
+    #[inline]
+    pub fn is_usable(&self) -> bool {
+        self.raw.usable != 0
+    }
+}
+
+impl fmt::Debug for IndexConstraint {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexConstraint")
+            .field("column", &self.column())
+            .field("op", &self.op())
+            .field("usable", &self.is_usable())
+            .finish()
+    }
+}
+
+/// How a constraint is used by a plan.
+///
+/// See [`IndexInfo::constraint_usage_mut`].
+#[repr(transparent)]
+pub struct ConstraintUsage {
+    raw: ffi::sqlite3_index_info_sqlite3_index_constraint_usage,
+}
+
+impl ConstraintUsage {
+    /// Pass the right-hand value of the constraint as an argument to
+    /// [`VirtualCursor::filter`].
+    ///
+    /// The argument index is 1-based, so the first argument is `1`. Setting it
+    /// to `0` means that the value is not passed.
+    #[inline]
+    pub fn set_argv_index(&mut self, argv_index: c_int) -> &mut Self {
+        self.raw.argvIndex = argv_index;
+        self
+    }
+
+    /// Tell sqlite that the cursor fully handles the constraint, so it does
+    /// not have to be checked again for each row.
+    #[inline]
+    pub fn set_omit(&mut self, omit: bool) -> &mut Self {
+        self.raw.omit = u8::from(omit);
+        self
+    }
+}
+
+/// The operator of an [`IndexConstraint`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct ConstraintOp {
+    raw: c_int,
+}
+
+impl ConstraintOp {
+    /// The `=` operator.
+    pub const EQ: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_EQ);
+    /// The `>` operator.
+    pub const GT: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_GT);
+    /// The `<=` operator.
+    pub const LE: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_LE);
+    /// The `<` operator.
+    pub const LT: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_LT);
+    /// The `>=` operator.
+    pub const GE: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_GE);
+    /// The `MATCH` operator.
+    pub const MATCH: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_MATCH);
+    /// The `LIKE` operator.
+    pub const LIKE: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_LIKE);
+    /// The `GLOB` operator.
+    pub const GLOB: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_GLOB);
+    /// The `REGEXP` operator.
+    pub const REGEXP: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_REGEXP);
+    /// The `!=` operator.
+    pub const NE: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_NE);
+    /// The `IS NOT` operator.
+    pub const IS_NOT: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_ISNOT);
+    /// The `IS NOT NULL` operator.
+    pub const IS_NOT_NULL: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_ISNOTNULL);
+    /// The `IS NULL` operator.
+    pub const IS_NULL: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_ISNULL);
+    /// The `IS` operator.
+    pub const IS: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_IS);
+    /// A `LIMIT` clause.
+    #[cfg(feature = "bundled")]
+    #[cfg_attr(docsrs, cfg(feature = "bundled"))]
+    pub const LIMIT: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_LIMIT);
+    /// An `OFFSET` clause.
+    #[cfg(feature = "bundled")]
+    #[cfg_attr(docsrs, cfg(feature = "bundled"))]
+    pub const OFFSET: Self = Self::new(ffi::SQLITE_INDEX_CONSTRAINT_OFFSET);
+
+    #[inline]
+    const fn new(raw: c_int) -> Self {
+        Self { raw }
+    }
+}
+
+impl fmt::Debug for ConstraintOp {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.raw {
+            ffi::SQLITE_INDEX_CONSTRAINT_EQ => write!(f, "EQ"),
+            ffi::SQLITE_INDEX_CONSTRAINT_GT => write!(f, "GT"),
+            ffi::SQLITE_INDEX_CONSTRAINT_LE => write!(f, "LE"),
+            ffi::SQLITE_INDEX_CONSTRAINT_LT => write!(f, "LT"),
+            ffi::SQLITE_INDEX_CONSTRAINT_GE => write!(f, "GE"),
+            ffi::SQLITE_INDEX_CONSTRAINT_MATCH => write!(f, "MATCH"),
+            ffi::SQLITE_INDEX_CONSTRAINT_LIKE => write!(f, "LIKE"),
+            ffi::SQLITE_INDEX_CONSTRAINT_GLOB => write!(f, "GLOB"),
+            ffi::SQLITE_INDEX_CONSTRAINT_REGEXP => write!(f, "REGEXP"),
+            ffi::SQLITE_INDEX_CONSTRAINT_NE => write!(f, "NE"),
+            ffi::SQLITE_INDEX_CONSTRAINT_ISNOT => write!(f, "IS_NOT"),
+            ffi::SQLITE_INDEX_CONSTRAINT_ISNOTNULL => write!(f, "IS_NOT_NULL"),
+            ffi::SQLITE_INDEX_CONSTRAINT_ISNULL => write!(f, "IS_NULL"),
+            ffi::SQLITE_INDEX_CONSTRAINT_IS => write!(f, "IS"),
+            #[cfg(feature = "bundled")]
+            ffi::SQLITE_INDEX_CONSTRAINT_LIMIT => write!(f, "LIMIT"),
+            #[cfg(feature = "bundled")]
+            ffi::SQLITE_INDEX_CONSTRAINT_OFFSET => write!(f, "OFFSET"),
+            raw => write!(f, "UNKNOWN({raw})"),
+        }
+    }
+}
+
+/// Construct a slice from a raw pointer and a length reported by sqlite.
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: c_int) -> &'a [T] {
+    match usize::try_from(len) {
+        Ok(len) if len > 0 && !ptr.is_null() => unsafe { slice::from_raw_parts(ptr, len) },
+        _ => &[],
+    }
+}
+
+/// The module registered with sqlite, which owns the table.
+struct Module<T> {
+    raw: ffi::sqlite3_module,
+    table: T,
+}
+
+/// The sqlite virtual table, which borrows the table from its module.
+#[repr(C)]
+struct Table<T> {
+    base: ffi::sqlite3_vtab,
+    table: *const T,
+}
+
+/// The sqlite virtual table cursor.
+#[repr(C)]
+struct Cursor<C> {
+    base: ffi::sqlite3_vtab_cursor,
+    cursor: C,
+}
+
+/// Copy the message of an error into memory allocated by sqlite, which is how
+/// sqlite expects virtual table errors to be reported.
+fn error_message(error: &Error) -> *mut c_char {
+    let message = error.message();
+
+    unsafe {
+        let ptr = ffi::sqlite3_malloc64(message.len() as u64 + 1).cast::<u8>();
+
+        if !ptr.is_null() {
+            ptr::copy_nonoverlapping(message.as_ptr(), ptr, message.len());
+            ptr.add(message.len()).write(0);
+        }
+
+        ptr.cast()
+    }
+}
+
+/// Report an error on a virtual table, returning its code.
+///
+/// # Safety
+///
+/// The virtual table must be valid.
+unsafe fn table_error(vtab: *mut ffi::sqlite3_vtab, error: &Error) -> c_int {
+    unsafe {
+        ffi::sqlite3_free((*vtab).zErrMsg.cast());
+        (*vtab).zErrMsg = error_message(error);
+    }
+
+    error.code().as_i32()
+}
+
+/// Report the result of a cursor operation.
+///
+/// # Safety
+///
+/// The cursor must be valid.
+unsafe fn cursor_result(cursor: *mut ffi::sqlite3_vtab_cursor, result: Result<()>) -> c_int {
+    match result {
+        Ok(()) => ffi::SQLITE_OK,
+        Err(error) => unsafe { table_error((*cursor).pVtab, &error) },
+    }
+}
+
+pub(crate) unsafe fn create_module<T>(db: *mut ffi::sqlite3, name: *const c_char, table: T) -> c_int
+where
+    T: VirtualTable,
+{
+    unsafe extern "C" fn connect<T>(
+        db: *mut ffi::sqlite3,
+        aux: *mut c_void,
+        _: c_int,
+        _: *const *const c_char,
+        vtab: *mut *mut ffi::sqlite3_vtab,
+        err: *mut *mut c_char,
+    ) -> c_int
+    where
+        T: VirtualTable,
+    {
+        unsafe {
+            let module = &*aux.cast::<Module<T>>();
+
+            let sql = match module.table.connect() {
+                Ok(sql) => sql,
+                Err(error) => {
+                    *err = error_message(&error);
+                    return error.code().as_i32();
+                }
+            };
+
+            let code = ffi::sqlite3_declare_vtab(db, sql.as_ptr());
+
+            if code != ffi::SQLITE_OK {
+                return code;
+            }
+
+            let table = Box::new(Table {
+                base: ffi::sqlite3_vtab {
+                    pModule: ptr::null(),
+                    nRef: 0,
+                    zErrMsg: ptr::null_mut(),
+                },
+                table: &module.table,
+            });
+
+            *vtab = Box::into_raw(table).cast();
+            ffi::SQLITE_OK
+        }
+    }
+
+    unsafe extern "C" fn best_index<T>(
+        vtab: *mut ffi::sqlite3_vtab,
+        info: *mut ffi::sqlite3_index_info,
+    ) -> c_int
+    where
+        T: VirtualTable,
+    {
+        unsafe {
+            let table = &*(*vtab.cast::<Table<T>>()).table;
+
+            match table.best_index(&mut IndexInfo { raw: &mut *info }) {
+                Ok(()) => ffi::SQLITE_OK,
+                Err(error) => table_error(vtab, &error),
+            }
+        }
+    }
+
+    unsafe extern "C" fn disconnect<T>(vtab: *mut ffi::sqlite3_vtab) -> c_int {
+        unsafe {
+            drop(Box::from_raw(vtab.cast::<Table<T>>()));
+        }
+
+        ffi::SQLITE_OK
+    }
+
+    unsafe extern "C" fn open<T>(
+        vtab: *mut ffi::sqlite3_vtab,
+        cursor: *mut *mut ffi::sqlite3_vtab_cursor,
+    ) -> c_int
+    where
+        T: VirtualTable,
+    {
+        unsafe {
+            let table = &*(*vtab.cast::<Table<T>>()).table;
+
+            match table.open() {
+                Ok(inner) => {
+                    let raw = Box::new(Cursor {
+                        base: ffi::sqlite3_vtab_cursor {
+                            pVtab: ptr::null_mut(),
+                        },
+                        cursor: inner,
+                    });
+
+                    *cursor = Box::into_raw(raw).cast();
+                    ffi::SQLITE_OK
+                }
+                Err(error) => table_error(vtab, &error),
+            }
+        }
+    }
+
+    unsafe extern "C" fn close<C>(cursor: *mut ffi::sqlite3_vtab_cursor) -> c_int {
+        unsafe {
+            drop(Box::from_raw(cursor.cast::<Cursor<C>>()));
+        }
+
+        ffi::SQLITE_OK
+    }
+
+    unsafe extern "C" fn filter<C>(
+        cursor: *mut ffi::sqlite3_vtab_cursor,
+        index_num: c_int,
+        _: *const c_char,
+        argc: c_int,
+        argv: *mut *mut ffi::sqlite3_value,
+    ) -> c_int
+    where
+        C: VirtualCursor,
+    {
+        unsafe {
+            let inner = &mut (*cursor.cast::<Cursor<C>>()).cursor;
+            let result = inner.filter(index_num, &Arguments::from_raw(argc, argv));
+            cursor_result(cursor, result)
+        }
+    }
+
+    unsafe extern "C" fn next<C>(cursor: *mut ffi::sqlite3_vtab_cursor) -> c_int
+    where
+        C: VirtualCursor,
+    {
+        unsafe {
+            let inner = &mut (*cursor.cast::<Cursor<C>>()).cursor;
+            let result = inner.next();
+            cursor_result(cursor, result)
+        }
+    }
+
+    unsafe extern "C" fn eof<C>(cursor: *mut ffi::sqlite3_vtab_cursor) -> c_int
+    where
+        C: VirtualCursor,
+    {
+        unsafe {
+            let inner = &(*cursor.cast::<Cursor<C>>()).cursor;
+            c_int::from(inner.eof())
+        }
+    }
+
+    unsafe extern "C" fn column<C>(
+        cursor: *mut ffi::sqlite3_vtab_cursor,
+        ctx: *mut ffi::sqlite3_context,
+        index: c_int,
+    ) -> c_int
+    where
+        C: VirtualCursor,
+    {
+        unsafe {
+            let inner = &(*cursor.cast::<Cursor<C>>()).cursor;
+            let result = inner.column(&mut Context::from_raw(ctx), index);
+            cursor_result(cursor, result)
+        }
+    }
+
+    unsafe extern "C" fn rowid<C>(
+        cursor: *mut ffi::sqlite3_vtab_cursor,
+        out: *mut ffi::sqlite3_int64,
+    ) -> c_int
+    where
+        C: VirtualCursor,
+    {
+        unsafe {
+            let inner = &(*cursor.cast::<Cursor<C>>()).cursor;
+
+            match inner.rowid() {
+                Ok(rowid) => {
+                    *out = rowid;
+                    ffi::SQLITE_OK
+                }
+                Err(error) => table_error((*cursor).pVtab, &error),
+            }
+        }
+    }
+
+    unsafe extern "C" fn destroy<T>(data: *mut c_void) {
+        unsafe {
+            drop(Box::from_raw(data.cast::<Module<T>>()));
+        }
+    }
+
+    // NB: Leaving out xCreate makes the module eponymous-only, and leaving out
+    // xUpdate makes it read-only.
+    let module = Box::new(Module {
+        raw: ffi::sqlite3_module {
+            iVersion: 0,
+            xCreate: None,
+            xConnect: Some(connect::<T>),
+            xBestIndex: Some(best_index::<T>),
+            xDisconnect: Some(disconnect::<T>),
+            xDestroy: None,
+            xOpen: Some(open::<T>),
+            xClose: Some(close::<T::Cursor>),
+            xFilter: Some(filter::<T::Cursor>),
+            xNext: Some(next::<T::Cursor>),
+            xEof: Some(eof::<T::Cursor>),
+            xColumn: Some(column::<T::Cursor>),
+            xRowid: Some(rowid::<T::Cursor>),
+            xUpdate: None,
+            xBegin: None,
+            xSync: None,
+            xCommit: None,
+            xRollback: None,
+            xFindFunction: None,
+            xRename: None,
+            xSavepoint: None,
+            xRelease: None,
+            xRollbackTo: None,
+            xShadowName: None,
+        },
+        table,
+    });
+
+    let module = Box::into_raw(module);
+
+    // SAFETY: The module is boxed, so the pointer to the raw module stays
+    // valid until sqlite calls destroy, which also happens if registration
+    // fails.
+    unsafe {
+        ffi::sqlite3_create_module_v2(
+            db,
+            name,
+            &raw const (*module).raw,
+            module.cast(),
+            Some(destroy::<T>),
+        )
+    }
+}
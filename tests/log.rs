@@ -0,0 +1,44 @@
+//! The log callback is process-wide, and older versions of sqlite only permit
+//! configuring it before any connection has been opened. So this lives in its
+//! own test binary.
+
+#![cfg(feature = "alloc")]
+
+use std::sync::Mutex;
+
+use anyhow::Result;
+
+use sqll::{Code, Connection};
+
+#[test]
+fn config_log_autoindex() -> Result<()> {
+    static MESSAGES: Mutex<Vec<(Code, String)>> = Mutex::new(Vec::new());
+
+    unsafe {
+        sqll::config_log(|code, message| {
+            MESSAGES.lock().unwrap().push((code, message.to_string()));
+        })?;
+    }
+
+    let c = Connection::open_in_memory()?;
+
+    c.execute(
+        r#"
+        CREATE TABLE users (id INTEGER, name TEXT);
+        CREATE TABLE posts (user_id INTEGER, title TEXT);
+        "#,
+    )?;
+
+    let _stmt = c.prepare("SELECT name, title FROM users, posts WHERE users.id = posts.user_id")?;
+
+    let messages = MESSAGES.lock().unwrap();
+
+    assert!(
+        messages.iter().any(|(code, message)| {
+            *code == Code::WARNING_AUTOINDEX && message.contains("automatic index on")
+        }),
+        "{messages:?}"
+    );
+
+    Ok(())
+}
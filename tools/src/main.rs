@@ -62,6 +62,71 @@ const CONSTANTS: &[&str] = &[
     "OPEN_PRIVATECACHE",
     "OPEN_NOFOLLOW",
     "OPEN_EXRESCODE",
+    "CONFIG_LOG",
+    "UTF8",
+    "DETERMINISTIC",
+    "DIRECTONLY",
+    "INNOCUOUS",
+    "TXN_NONE",
+    "TXN_READ",
+    "TXN_WRITE",
+    "DBSTATUS_CACHE_USED",
+    "DBSTATUS_CACHE_HIT",
+    "DBSTATUS_CACHE_MISS",
+    "DBSTATUS_CACHE_WRITE",
+    "DESERIALIZE_FREEONCLOSE",
+    "DESERIALIZE_RESIZEABLE",
+    "DESERIALIZE_READONLY",
+    "INDEX_CONSTRAINT_EQ",
+    "INDEX_CONSTRAINT_GT",
+    "INDEX_CONSTRAINT_LE",
+    "INDEX_CONSTRAINT_LT",
+    "INDEX_CONSTRAINT_GE",
+    "INDEX_CONSTRAINT_MATCH",
+    "INDEX_CONSTRAINT_LIKE",
+    "INDEX_CONSTRAINT_GLOB",
+    "INDEX_CONSTRAINT_REGEXP",
+    "INDEX_CONSTRAINT_NE",
+    "INDEX_CONSTRAINT_ISNOT",
+    "INDEX_CONSTRAINT_ISNOTNULL",
+    "INDEX_CONSTRAINT_ISNULL",
+    "INDEX_CONSTRAINT_IS",
+    "INDEX_CONSTRAINT_FUNCTION",
+    "DENY",
+    "IGNORE",
+    "CREATE_INDEX",
+    "CREATE_TABLE",
+    "CREATE_TEMP_INDEX",
+    "CREATE_TEMP_TABLE",
+    "CREATE_TEMP_TRIGGER",
+    "CREATE_TEMP_VIEW",
+    "CREATE_TRIGGER",
+    "CREATE_VIEW",
+    "DELETE",
+    "DROP_INDEX",
+    "DROP_TABLE",
+    "DROP_TEMP_INDEX",
+    "DROP_TEMP_TABLE",
+    "DROP_TEMP_TRIGGER",
+    "DROP_TEMP_VIEW",
+    "DROP_TRIGGER",
+    "DROP_VIEW",
+    "INSERT",
+    "PRAGMA",
+    "READ",
+    "SELECT",
+    "TRANSACTION",
+    "UPDATE",
+    "ATTACH",
+    "DETACH",
+    "ALTER_TABLE",
+    "REINDEX",
+    "ANALYZE",
+    "CREATE_VTABLE",
+    "DROP_VTABLE",
+    "FUNCTION",
+    "SAVEPOINT",
+    "RECURSIVE",
 ];
 
 // NB: Excluding these files causes the source file to include a massive comment
@@ -257,15 +322,25 @@ async fn entry(opts: &Opts) -> Result<()> {
         builder = builder
             .allowlist_item(format!("SQLITE_({constants})"))
             .allowlist_item("SQLITE_PREPARE_.*")
-            .allowlist_item("sqlite3_(libversion_number|libversion|threadsafe)")
-            .allowlist_item("sqlite3_(reset|step|open_v2|close_v2|prepare_v3|finalize)")
+            .allowlist_item("sqlite3_(libversion_number|libversion|sourceid|threadsafe|config)")
+            .allowlist_item("sqlite3_(reset|step|open_v2|close_v2|prepare_v3|prepare16_v3|finalize)")
+            .allowlist_item("sqlite3_(sql|normalized_sql)")
             .allowlist_item("sqlite3_db_(readonly|handle)")
-            .allowlist_item("sqlite3_(errstr|errmsg|extended_result_codes)")
-            .allowlist_item("sqlite3_(clear_bindings|busy_handler|busy_timeout|changes|total_changes|last_insert_rowid)")
-            .allowlist_item("sqlite3_bind_parameter_(index|name)")
-            .allowlist_item("sqlite3_column_(name|type|count|bytes|text|double|int64|null|blob)")
+            .allowlist_item("sqlite3_(txn_state|get_autocommit|next_stmt)")
+            .allowlist_item("sqlite3_(serialize|deserialize|db_status)")
+            .allowlist_item("sqlite3_interrupt")
+            .allowlist_item("sqlite3_(errstr|errmsg|errcode|extended_errcode|extended_result_codes)")
+            .allowlist_item("sqlite3_(clear_bindings|busy_handler|busy_timeout|changes|total_changes|total_changes64|last_insert_rowid)")
+            .allowlist_item("sqlite3_set_authorizer")
+            .allowlist_item("sqlite3_bind_parameter_(count|index|name)")
+            .allowlist_item("sqlite3_column_(name|decltype|type|count|bytes|text|double|int64|null|blob)")
             .allowlist_item("sqlite3_bind_(bytes|text|double|int64|null|blob)")
-            .allowlist_item("sqlite3_(malloc|free)");
+            .allowlist_item("sqlite3_(malloc|malloc64|free|msize)")
+            .allowlist_item("sqlite3_(memory_used|memory_highwater|soft_heap_limit64|hard_heap_limit64)")
+            .allowlist_item("sqlite3_(create_function_v2|create_window_function|aggregate_context|user_data)")
+            .allowlist_item("sqlite3_(create_module_v2|declare_vtab)")
+            .allowlist_item("sqlite3_value_(type|bytes|text|double|int64|blob)")
+            .allowlist_item("sqlite3_result_(text|double|int64|null|blob|error|error_code|error_nomem)");
     }
 
     builder